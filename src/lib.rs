@@ -15,3 +15,6 @@
 //! is adapted from the u16 intrinsic type.
 
 pub mod u12;
+
+#[cfg(feature = "serde")]
+pub use u12::serde;
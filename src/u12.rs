@@ -7,7 +7,12 @@
 // distributed except according to those terms.
 //
 
+use std::borrow::Borrow;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
 use std::fmt::Debug;
+use std::iter::FromIterator;
 use std::marker;
 use std::num::{IntErrorKind, ParseIntError};
 use std::ops::AddAssign;
@@ -21,10 +26,67 @@ use std::ops::SubAssign;
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
 use std::str::FromStr;
 
+#[cfg(feature = "arbitrary-int")]
+mod arbitrary_int;
+#[cfg(feature = "bitvec")]
+mod bitvec;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "embedded-hal")]
+mod embedded_hal;
+#[cfg(feature = "num")]
+mod num;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
 #[cfg(feature = "serde")]
-mod serde;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub mod serde;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "ux")]
+mod ux;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+mod bitfield;
+
+pub mod base64;
+pub mod color;
+pub mod crc12;
+pub mod delta;
+pub mod dither;
+pub mod dump;
+pub mod ecc;
+pub mod fat12;
+pub mod fixed;
+pub mod float;
+pub mod frames;
+pub mod histogram;
+pub mod image;
+pub mod io;
+pub mod lfsr;
+pub mod lut;
+pub mod memory;
+pub mod packing;
+pub mod permutation;
+pub mod seven_segment;
+pub mod simd;
+pub mod slice_ops;
+pub mod stats;
+pub mod vlan;
+pub mod waveform;
+
+#[cfg(feature = "embedded-hal")]
+pub use self::embedded_hal::SetDutyCycleU12;
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::ArchivedU12;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct U12(u16);
 
 // MARK: - Literal Macro
@@ -152,6 +214,80 @@ impl U12 {
         }
     }
 
+    /// Returns `true` if `self` is divisible by two.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert!(u12![0].is_even());
+    /// assert!(!u12![4095].is_even());
+    /// # }
+    /// ```
+    pub const fn is_even(self) -> bool {
+        self.0 & 1 == 0
+    }
+
+    /// Returns `true` if `self` is not divisible by two.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert!(!u12![0].is_odd());
+    /// assert!(u12![4095].is_odd());
+    /// # }
+    /// ```
+    pub const fn is_odd(self) -> bool {
+        !self.is_even()
+    }
+
+    /// Returns the parity of `self`, i.e. the XOR of all 12 bits. This is `true` when the
+    /// binary representation contains an odd number of ones, and is the bit to append to
+    /// the value for even parity.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0b000000000000].parity(), false);
+    /// assert_eq!(u12![0b000000010000].parity(), true);
+    /// assert_eq!(u12![0b100000010000].parity(), false);
+    /// assert_eq!(u12![0b111111111111].parity(), false);
+    /// # }
+    /// ```
+    pub const fn parity(self) -> bool {
+        self.count_ones() & 1 == 1
+    }
+
+    /// Returns the number of bit positions at which `self` and `other` differ, i.e. the
+    /// number of ones in the binary representation of `self ^ other`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0b101010101010].hamming_distance(u12![0b101010101010]), 0);
+    /// assert_eq!(u12![0b101010101010].hamming_distance(u12![0b101010101011]), 1);
+    /// assert_eq!(u12![0b000000000000].hamming_distance(u12![0b111111111111]), 12);
+    /// # }
+    /// ```
+    pub const fn hamming_distance(self, other: Self) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
     /// Checked integer addition.
     /// Computes `self + other`, returning `None` if overflow occurred.
     ///
@@ -229,6 +365,150 @@ impl U12 {
         }
     }
 
+    /// Unchecked integer addition.
+    /// Computes `self + other`, assuming overflow cannot occur.
+    ///
+    /// # Safety
+    /// This results in undefined behavior when `self + other > U12::max_value()`,
+    /// i.e. when `checked_add` would return `None`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(unsafe { u12![1].unchecked_add(u12![2]) }, u12![3]);
+    /// # }
+    /// ```
+    pub const unsafe fn unchecked_add(self, other: Self) -> Self {
+        debug_assert!(
+            self.0 + other.0 <= 0xFFF,
+            "unchecked_add requires that the addition does not overflow"
+        );
+        U12(self.0.unchecked_add(other.0))
+    }
+
+    /// Strict integer addition.
+    /// Computes `self + other`, panicking if overflow occurred. Unlike the `Add` operator,
+    /// this is guaranteed to panic regardless of the build profile or enabled features.
+    ///
+    /// # Panics
+    /// This function will panic if `self + other > U12::max_value()`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![1].strict_add(u12![2]), u12![3]);
+    /// # }
+    /// ```
+    ///
+    /// The following panics because of overflow:
+    ///
+    /// ```should_panic
+    /// use twelve_bit::u12::*;
+    ///
+    /// let _ = U12::max_value().strict_add(1u8.into());
+    /// ```
+    pub const fn strict_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Some(result) => result,
+            None => panic!("arithmetic overflow"),
+        }
+    }
+
+    /// Checked addition with a signed integer.
+    /// Computes `self + rhs`, returning `None` if the result is negative or overflows.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x200].checked_add_signed(-2), Some(u12![0x1FE]));
+    /// assert_eq!(u12![1].checked_add_signed(-2), None);
+    /// assert_eq!(U12::max_value().checked_add_signed(1), None);
+    /// # }
+    /// ```
+    pub const fn checked_add_signed(self, rhs: i16) -> Option<Self> {
+        match self.0 as i32 + rhs as i32 {
+            result @ 0..=4095 => Some(U12(result as u16)),
+            _ => None,
+        }
+    }
+
+    /// Saturating addition with a signed integer.
+    /// Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![1].saturating_add_signed(2), u12![3]);
+    /// assert_eq!(u12![1].saturating_add_signed(-2), U12::min_value());
+    /// assert_eq!(u12![4000].saturating_add_signed(200), U12::max_value());
+    /// # }
+    /// ```
+    pub const fn saturating_add_signed(self, rhs: i16) -> Self {
+        match self.0 as i32 + rhs as i32 {
+            result @ 0..=4095 => U12(result as u16),
+            result if result < 0 => Self::min_value(),
+            _ => Self::max_value(),
+        }
+    }
+
+    /// Wrapping (modular) addition with a signed integer.
+    /// Computes `self + rhs`, wrapping around at the boundary of the type. This is how a
+    /// 12-bit program counter applies a relative jump.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![1].wrapping_add_signed(-2), U12::max_value());
+    /// assert_eq!(U12::max_value().wrapping_add_signed(3), u12![2]);
+    /// # }
+    /// ```
+    pub const fn wrapping_add_signed(self, rhs: i16) -> Self {
+        U12(((self.0 as i32 + rhs as i32) & 0xFFF) as u16)
+    }
+
+    /// Overflowing addition with a signed integer.
+    /// Computes `self + rhs`, returning a tuple of the addition along with a boolean
+    /// indicating whether the result fell outside the range of the type.
+    /// If it did, then the wrapped value is returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![1].overflowing_add_signed(2), (u12![3], false));
+    /// assert_eq!(u12![1].overflowing_add_signed(-2), (U12::max_value(), true));
+    /// # }
+    /// ```
+    pub const fn overflowing_add_signed(self, rhs: i16) -> (Self, bool) {
+        match self.checked_add_signed(rhs) {
+            Some(result) => (result, false),
+            None => (self.wrapping_add_signed(rhs), true),
+        }
+    }
+
     /// Checked integer subtraction.
     /// Computes `self - other`, returning `None` if underflow occurred.
     ///
@@ -302,6 +582,129 @@ impl U12 {
         }
     }
 
+    /// Unchecked integer subtraction.
+    /// Computes `self - other`, assuming underflow cannot occur.
+    ///
+    /// # Safety
+    /// This results in undefined behavior when `self < other`, i.e. when
+    /// `checked_sub` would return `None`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(unsafe { u12![3].unchecked_sub(u12![2]) }, u12![1]);
+    /// # }
+    /// ```
+    pub const unsafe fn unchecked_sub(self, other: Self) -> Self {
+        debug_assert!(
+            self.0 >= other.0,
+            "unchecked_sub requires that the subtraction does not underflow"
+        );
+        U12(self.0.unchecked_sub(other.0))
+    }
+
+    /// Strict integer subtraction.
+    /// Computes `self - other`, panicking if underflow occurred. Unlike the `Sub` operator,
+    /// this is guaranteed to panic regardless of the build profile or enabled features.
+    ///
+    /// # Panics
+    /// This function will panic if `self < other`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![3].strict_sub(u12![2]), u12![1]);
+    /// # }
+    /// ```
+    ///
+    /// The following panics because of underflow:
+    ///
+    /// ```should_panic
+    /// use twelve_bit::u12::*;
+    ///
+    /// let _ = U12::min_value().strict_sub(1u8.into());
+    /// ```
+    pub const fn strict_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Some(result) => result,
+            None => panic!("arithmetic underflow"),
+        }
+    }
+
+    /// Checked signed difference.
+    /// Computes `self - other` as a signed integer. The difference of two 12-bit values
+    /// always fits in an `i16`, so this never returns `None`; the `Option` matches the
+    /// signature of the standard library's `checked_signed_diff`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let (from, to) = (u12![0x204], u12![0x1F0]);
+    /// let offset = to.checked_signed_diff(from).unwrap();
+    /// assert_eq!(offset, -20);
+    /// assert_eq!(from.checked_add_signed(offset), Some(to));
+    /// # }
+    /// ```
+    pub const fn checked_signed_diff(self, other: Self) -> Option<i16> {
+        Some(self.0 as i16 - other.0 as i16)
+    }
+
+    /// Checked successor.
+    /// Computes `self + 1`, returning `None` if `self == U12::max_value()`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x200].checked_next(), Some(u12![0x201]));
+    /// assert_eq!(U12::max_value().checked_next(), None);
+    /// # }
+    /// ```
+    pub const fn checked_next(self) -> Option<Self> {
+        if self.0 == MAX.0 {
+            None
+        } else {
+            Some(U12(self.0 + 1))
+        }
+    }
+
+    /// Checked predecessor.
+    /// Computes `self - 1`, returning `None` if `self == U12::min_value()`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x200].checked_prev(), Some(u12![0x1FF]));
+    /// assert_eq!(U12::min_value().checked_prev(), None);
+    /// # }
+    /// ```
+    pub const fn checked_prev(self) -> Option<Self> {
+        if self.0 == MIN.0 {
+            None
+        } else {
+            Some(U12(self.0 - 1))
+        }
+    }
+
     /// Checked integer multiplication.
     /// Computes `self * other`, returning `None` if overflow occurred.
     ///
@@ -382,6 +785,63 @@ impl U12 {
         }
     }
 
+    /// Unchecked integer multiplication.
+    /// Computes `self * other`, assuming overflow cannot occur.
+    ///
+    /// # Safety
+    /// This results in undefined behavior when `self * other > U12::max_value()`,
+    /// i.e. when `checked_mul` would return `None`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(unsafe { u12![64].unchecked_mul(u12![63]) }, u12![4032]);
+    /// # }
+    /// ```
+    pub const unsafe fn unchecked_mul(self, other: Self) -> Self {
+        debug_assert!(
+            self.checked_mul(other).is_some(),
+            "unchecked_mul requires that the multiplication does not overflow"
+        );
+        U12(self.0.unchecked_mul(other.0))
+    }
+
+    /// Strict integer multiplication.
+    /// Computes `self * other`, panicking if overflow occurred. Unlike the `Mul` operator,
+    /// this is guaranteed to panic regardless of the build profile or enabled features.
+    ///
+    /// # Panics
+    /// This function will panic if `self * other > U12::max_value()`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![64].strict_mul(u12![63]), u12![4032]);
+    /// # }
+    /// ```
+    ///
+    /// The following panics because of overflow:
+    ///
+    /// ```should_panic
+    /// use twelve_bit::u12::*;
+    ///
+    /// let _ = U12::max_value().strict_mul(2u8.into());
+    /// ```
+    pub const fn strict_mul(self, other: Self) -> Self {
+        match self.checked_mul(other) {
+            Some(result) => result,
+            None => panic!("arithmetic overflow"),
+        }
+    }
+
     /// Checked integer division.
     /// Computes `self / other`,  returning None if other == 0 or the operation results in
     /// underflow or overflow.
@@ -443,9 +903,76 @@ impl U12 {
         (self.wrapping_div(other), false)
     }
 
-    /// Checked integer negation.
-    /// Computes `-self`, returning `None` unless `self == 0`.
-    /// Note that negating any positive integer will overflow.
+    /// Saturating integer division.
+    /// Computes `self / other`. Division on unsigned types can never overflow, so this is
+    /// equivalent to regular division. This function exists, so that all operations are
+    /// accounted for in the saturating operations.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![5].saturating_div(u12![2]), u12![2]);
+    /// # }
+    /// ```
+    pub const fn saturating_div(self, other: Self) -> Self {
+        match self.checked_div(other) {
+            Some(result) => result,
+            None => panic!("arithmetic exception"),
+        }
+    }
+
+    /// Euclidean division.
+    /// Computes `self.div_euclid(other)`. Since, for the positive integers, all common
+    /// definitions of division are equal, this is exactly equal to `self / other`.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![7].div_euclid(u12![4]), u12![1]);
+    /// # }
+    /// ```
+    pub const fn div_euclid(self, other: Self) -> Self {
+        match self.checked_div_euclid(other) {
+            Some(result) => result,
+            None => panic!("arithmetic exception"),
+        }
+    }
+
+    /// Checked Euclidean division.
+    /// Computes `self.div_euclid(other)`, returning `None` if `other == 0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![7].checked_div_euclid(u12![4]), Some(u12![1]));
+    /// assert_eq!(u12![7].checked_div_euclid(u12![0]), None);
+    /// # }
+    /// ```
+    pub const fn checked_div_euclid(self, other: Self) -> Option<Self> {
+        self.checked_div(other)
+    }
+
+    /// Checked integer negation.
+    /// Computes `-self`, returning `None` unless `self == 0`.
+    /// Note that negating any positive integer will overflow.
     ///
     /// # Examples
     /// Basic usage:
@@ -566,6 +1093,181 @@ impl U12 {
         (U12(result), overflow)
     }
 
+    /// Euclidean remainder.
+    /// Computes `self.rem_euclid(other)`. Since, for the positive integers, all common
+    /// definitions of division are equal, this is exactly equal to `self % other`.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![7].rem_euclid(u12![4]), u12![3]);
+    /// # }
+    /// ```
+    pub const fn rem_euclid(self, other: Self) -> Self {
+        match self.checked_rem_euclid(other) {
+            Some(result) => result,
+            None => panic!("arithmetic exception"),
+        }
+    }
+
+    /// Checked Euclidean remainder.
+    /// Computes `self.rem_euclid(other)`, returning `None` if `other == 0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![7].checked_rem_euclid(u12![4]), Some(u12![3]));
+    /// assert_eq!(u12![7].checked_rem_euclid(u12![0]), None);
+    /// # }
+    /// ```
+    pub const fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+        self.checked_rem(other)
+    }
+
+    /// Simultaneous integer division and remainder.
+    /// Computes `(self / other, self % other)` in a single operation.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![4095].div_rem(u12![10]), (u12![409], u12![5]));
+    /// # }
+    /// ```
+    pub const fn div_rem(self, other: Self) -> (Self, Self) {
+        match self.checked_div_rem(other) {
+            Some(result) => result,
+            None => panic!("arithmetic exception"),
+        }
+    }
+
+    /// Checked simultaneous integer division and remainder.
+    /// Computes `(self / other, self % other)`, returning `None` if `other == 0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![4095].checked_div_rem(u12![10]), Some((u12![409], u12![5])));
+    /// assert_eq!(u12![4095].checked_div_rem(u12![0]), None);
+    /// # }
+    /// ```
+    pub const fn checked_div_rem(self, other: Self) -> Option<(Self, Self)> {
+        match self.0.checked_div(other.0) {
+            Some(quotient) => Some((U12(quotient), U12(self.0 % other.0))),
+            None => None,
+        }
+    }
+
+    /// Integer square root.
+    /// Returns the square root of `self`, rounded down.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![10].isqrt(), u12![3]);
+    /// assert_eq!(U12::max_value().isqrt(), u12![63]);
+    /// # }
+    /// ```
+    pub const fn isqrt(self) -> Self {
+        U12(self.0.isqrt())
+    }
+
+    /// Returns `true` if `self` is an integer multiple of `rhs`, and `false` otherwise.
+    /// Zero is a multiple of every value, including zero itself, but no other value is a
+    /// multiple of zero.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert!(u12![0x240].is_multiple_of(u12![16]));
+    /// assert!(!u12![0x241].is_multiple_of(u12![16]));
+    /// assert!(u12![0].is_multiple_of(u12![0]));
+    /// # }
+    /// ```
+    pub const fn is_multiple_of(self, rhs: Self) -> bool {
+        self.0.is_multiple_of(rhs.0)
+    }
+
+    /// Checked rounding up to a multiple.
+    /// Computes the smallest value greater than or equal to `self` that is a multiple of
+    /// `rhs`, returning `None` if `rhs == 0` or the result does not fit in 12 bits.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x241].checked_next_multiple_of(u12![16]), Some(u12![0x250]));
+    /// assert_eq!(u12![0xFF1].checked_next_multiple_of(u12![16]), None);
+    /// assert_eq!(u12![5].checked_next_multiple_of(u12![0]), None);
+    /// # }
+    /// ```
+    pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_next_multiple_of(rhs.0) {
+            Some(result) if result <= MAX.0 => Some(U12(result)),
+            _ => None,
+        }
+    }
+
+    /// Rounding up to a multiple.
+    /// Computes the smallest value greater than or equal to `self` that is a multiple of
+    /// `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is `0` or the result does not fit in 12 bits.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x241].next_multiple_of(u12![16]), u12![0x250]);
+    /// assert_eq!(u12![0x240].next_multiple_of(u12![16]), u12![0x240]);
+    /// # }
+    /// ```
+    pub const fn next_multiple_of(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            panic!("arithmetic exception");
+        }
+        match self.checked_next_multiple_of(rhs) {
+            Some(result) => result,
+            None => panic!("arithmetic overflow"),
+        }
+    }
+
     /// Checked shift left.
     /// Computes `self << rhs`, returning `None` if `rhs` is larger than or equal to
     /// the number of bits in the receiver.
@@ -712,6 +1414,47 @@ impl U12 {
         (self.wrapping_shr(rhs), rhs >= 12)
     }
 
+    /// Rotates the 13-bit quantity formed by `carry` and the receiver left by one bit, as
+    /// the PDP-8 `RAL` instruction does with its link bit. Bit 11 of the receiver is shifted
+    /// out into the returned carry, and `carry` is shifted into bit 0.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0b100000000001].rotate_left_through_carry(false), (u12![0b000000000010], true));
+    /// assert_eq!(u12![0b000000000001].rotate_left_through_carry(true), (u12![0b000000000011], false));
+    /// # }
+    /// ```
+    pub const fn rotate_left_through_carry(self, carry: bool) -> (Self, bool) {
+        (
+            U12((self.0 << 1) & 0xFFF | carry as u16),
+            self.0 & 0x800 != 0,
+        )
+    }
+
+    /// Rotates the 13-bit quantity formed by `carry` and the receiver right by one bit, as
+    /// the PDP-8 `RAR` instruction does with its link bit. Bit 0 of the receiver is shifted
+    /// out into the returned carry, and `carry` is shifted into bit 11.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0b100000000001].rotate_right_through_carry(false), (u12![0b010000000000], true));
+    /// assert_eq!(u12![0b100000000000].rotate_right_through_carry(true), (u12![0b110000000000], false));
+    /// # }
+    /// ```
+    pub const fn rotate_right_through_carry(self, carry: bool) -> (Self, bool) {
+        (U12(self.0 >> 1 | (carry as u16) << 11), self.0 & 1 != 0)
+    }
+
     /// Checked bitwise-and of the receiver with `rhs`.
     /// Computes `self & rhs`. This method cannot fail.
     ///
@@ -763,6 +1506,130 @@ impl U12 {
         Some(U12(self.0 ^ rhs.0))
     }
 
+    /// Returns an iterator over the 12 bits of `self`, starting with the least-significant bit.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let bits: Vec<bool> = u12![0b100000000101].bits().collect();
+    /// assert_eq!(bits.len(), 12);
+    /// assert_eq!(&bits[..4], &[true, false, true, false]);
+    /// assert_eq!(bits[11], true);
+    /// # }
+    /// ```
+    pub fn bits(self) -> Bits {
+        Bits {
+            value: self.0,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator over the indices of the set bits of `self`, in ascending order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let pending: Vec<u32> = u12![0b100000100101].iter_ones().collect();
+    /// assert_eq!(pending, [0, 2, 5, 11]);
+    /// # }
+    /// ```
+    pub const fn iter_ones(self) -> BitIndices {
+        BitIndices { remaining: self.0 }
+    }
+
+    /// Returns an iterator over the indices of the clear bits of `self`, in ascending order.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let free: Vec<u32> = u12![0b111111111010].iter_zeros().collect();
+    /// assert_eq!(free, [0, 2]);
+    /// # }
+    /// ```
+    pub const fn iter_zeros(self) -> BitIndices {
+        BitIndices {
+            remaining: !self.0 & 0xFFF,
+        }
+    }
+
+    /// Builds a value from up to 12 bits, starting with the least-significant bit.
+    /// This is the inverse of `bits()`; missing high-order bits are zero. Returns `None`
+    /// if `bits` yields more than 12 items.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::try_from_bits(vec![true, false, true]), Some(u12![0b101]));
+    /// assert_eq!(U12::try_from_bits(u12![0xABC].bits()), Some(u12![0xABC]));
+    /// assert_eq!(U12::try_from_bits(vec![false; 13]), None);
+    /// # }
+    /// ```
+    pub fn try_from_bits<I>(bits: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut value = 0u16;
+        for (index, bit) in bits.into_iter().enumerate() {
+            if index >= 12 {
+                return None;
+            }
+            value |= (bit as u16) << index;
+        }
+        Some(U12(value))
+    }
+
+    /// Returns an iterator over the digits of `self` in base `radix`, starting with the
+    /// most-significant digit. Each digit is yielded as its numeric value, from `0` to
+    /// `radix - 1`. Zero has the single digit `0`, and no leading zeros are yielded.
+    ///
+    /// # Panics
+    /// This method will panic if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let digits: Vec<u8> = u12![0o7415].digits(8).collect();
+    /// assert_eq!(digits, [7, 4, 1, 5]);
+    /// assert_eq!(u12![0].digits(16).collect::<Vec<u8>>(), [0]);
+    /// # }
+    /// ```
+    pub fn digits(self, radix: u32) -> Digits {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2 to 36"
+        );
+        let radix = radix as u16;
+        let mut divisor = 1;
+        while self.0 / divisor >= radix {
+            divisor *= radix;
+        }
+        Digits {
+            value: self.0,
+            radix,
+            divisor,
+        }
+    }
+
     /// Conversion of an u16 value into u12. Basically a workaround since we cannot have a const implementation of From.
     ///
     /// # Panics
@@ -774,87 +1641,1563 @@ impl U12 {
             Self(x)
         }
     }
-}
-
-// MARK: - Non-Failable Conversions - From Smaller Types
 
-impl From<u8> for U12 {
-    fn from(small: u8) -> Self {
-        U12(small as u16)
-    }
-}
+    /// Interprets the bits of `self` as a 12-bit two's complement value, and returns it
+    /// sign-extended to an `i16`. The result lies in the range `-2048...2047`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x7FF].sign_extend_to_i16(), 2047);
+    /// assert_eq!(u12![0x800].sign_extend_to_i16(), -2048);
+    /// assert_eq!(u12![0xFFF].sign_extend_to_i16(), -1);
+    /// # }
+    /// ```
+    pub const fn sign_extend_to_i16(self) -> i16 {
+        ((self.0 << 4) as i16) >> 4
+    }
+
+    /// Returns the low 12 bits of the two's complement representation of `x`. This is the
+    /// inverse of `sign_extend_to_i16` for values in the range `-2048...2047`; values
+    /// outside that range are truncated.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_i16_truncated(-1), u12![0xFFF]);
+    /// assert_eq!(U12::from_i16_truncated(-2048), u12![0x800]);
+    /// assert_eq!(U12::from_i16_truncated(0x1234), u12![0x234]);
+    /// # }
+    /// ```
+    pub const fn from_i16_truncated(x: i16) -> Self {
+        U12(x as u16 & 0xFFF)
+    }
+
+    /// Interleaves the bits of two 6-bit coordinates into a Morton (Z-order) code, with
+    /// the bits of `x` in the even positions and the bits of `y` in the odd positions.
+    /// Returns `None` if either coordinate exceeds `63`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_interleaved(0b111, 0b000), Some(u12![0b010101]));
+    /// assert_eq!(U12::from_interleaved(0b000, 0b111), Some(u12![0b101010]));
+    /// assert_eq!(U12::from_interleaved(63, 63), Some(U12::max_value()));
+    /// assert_eq!(U12::from_interleaved(64, 0), None);
+    /// # }
+    /// ```
+    pub const fn from_interleaved(x: u8, y: u8) -> Option<Self> {
+        if x > 0x3F || y > 0x3F {
+            return None;
+        }
+        let mut value = 0u16;
+        let mut bit = 0;
+        while bit < 6 {
+            value |= (((x >> bit) & 1) as u16) << (2 * bit);
+            value |= (((y >> bit) & 1) as u16) << (2 * bit + 1);
+            bit += 1;
+        }
+        Some(U12(value))
+    }
+
+    /// Splits a Morton (Z-order) code into its two 6-bit coordinates `(x, y)`, taking `x`
+    /// from the even bits and `y` from the odd bits. This is the inverse of
+    /// `from_interleaved`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0b100111].to_interleaved(), (0b011, 0b101));
+    /// # }
+    /// ```
+    pub const fn to_interleaved(self) -> (u8, u8) {
+        let (mut x, mut y) = (0u8, 0u8);
+        let mut bit = 0;
+        while bit < 6 {
+            x |= (((self.0 >> (2 * bit)) & 1) as u8) << bit;
+            y |= (((self.0 >> (2 * bit + 1)) & 1) as u8) << bit;
+            bit += 1;
+        }
+        (x, y)
+    }
+}
+
+// MARK: - Modular Arithmetic
+
+impl U12 {
+    /// Modular addition.
+    /// Computes `(self + other) mod modulus` using a widened intermediate, so that the sum
+    /// cannot overflow. The operands do not need to be reduced.
+    ///
+    /// # Panics
+    /// This function will panic if `modulus` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![4000].add_mod(u12![4000], u12![4093]), u12![3907]);
+    /// # }
+    /// ```
+    pub const fn add_mod(self, other: Self, modulus: Self) -> Self {
+        let modulus = Self::nonzero_modulus(modulus);
+        U12(((self.0 as u32 + other.0 as u32) % modulus) as u16)
+    }
+
+    /// Modular subtraction.
+    /// Computes `(self - other) mod modulus`, always returning a value in the range
+    /// `0..modulus`. The operands do not need to be reduced.
+    ///
+    /// # Panics
+    /// This function will panic if `modulus` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![3].sub_mod(u12![5], u12![7]), u12![5]);
+    /// # }
+    /// ```
+    pub const fn sub_mod(self, other: Self, modulus: Self) -> Self {
+        let modulus = Self::nonzero_modulus(modulus);
+        let (lhs, rhs) = (self.0 as u32 % modulus, other.0 as u32 % modulus);
+        U12(((lhs + modulus - rhs) % modulus) as u16)
+    }
+
+    /// Modular multiplication.
+    /// Computes `(self * other) mod modulus` using a widened intermediate, so that the
+    /// product cannot overflow. The operands do not need to be reduced.
+    ///
+    /// # Panics
+    /// This function will panic if `modulus` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![4000].mul_mod(u12![4000], u12![4093]), u12![463]);
+    /// # }
+    /// ```
+    pub const fn mul_mod(self, other: Self, modulus: Self) -> Self {
+        let modulus = Self::nonzero_modulus(modulus);
+        U12(((self.0 as u32 * other.0 as u32) % modulus) as u16)
+    }
+
+    /// Modular exponentiation.
+    /// Computes `self.pow(exp) mod modulus` by square-and-multiply, so that no intermediate
+    /// value overflows. Any value to the power of zero is `1 mod modulus`.
+    ///
+    /// # Panics
+    /// This function will panic if `modulus` is `0`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![2].pow_mod(12, u12![4095]), u12![1]);
+    /// assert_eq!(u12![3].pow_mod(4092, u12![4093]), u12![1]);
+    /// assert_eq!(u12![5].pow_mod(0, u12![1]), u12![0]);
+    /// # }
+    /// ```
+    pub const fn pow_mod(self, exp: u32, modulus: Self) -> Self {
+        let modulus = Self::nonzero_modulus(modulus);
+        let mut base = self.0 as u32 % modulus;
+        let mut exp = exp;
+        let mut result = 1 % modulus;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % modulus;
+            }
+            base = base * base % modulus;
+            exp >>= 1;
+        }
+        U12(result as u16)
+    }
+
+    /// Returns `modulus` widened to `u32`, panicking if it is zero.
+    const fn nonzero_modulus(modulus: Self) -> u32 {
+        if modulus.0 == 0 {
+            panic!("arithmetic exception");
+        }
+        modulus.0 as u32
+    }
+}
+
+// MARK: - Serial Number Arithmetic
+
+impl U12 {
+    /// Returns the number of increments needed to go from `self` to `other`, wrapping
+    /// around at the boundary of the type. This is `other.wrapping_sub(self)`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![10].wrapping_distance(u12![15]), u12![5]);
+    /// assert_eq!(u12![4090].wrapping_distance(u12![4]), u12![10]);
+    /// # }
+    /// ```
+    pub const fn wrapping_distance(self, other: Self) -> Self {
+        other.wrapping_sub(self)
+    }
+
+    /// Compares `self` and `other` as serial numbers, in the style of RFC 1982. Returns
+    /// `true` if `other` is ahead of `self` by fewer than half of the 4096 values, so that
+    /// comparisons remain meaningful across wraparound. Values exactly 2048 apart are
+    /// neither ahead of nor behind each other.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert!(u12![10].seq_lt(u12![15]));
+    /// assert!(u12![4090].seq_lt(u12![4]));
+    /// assert!(!u12![4].seq_lt(u12![4090]));
+    /// assert!(!u12![0].seq_lt(u12![2048]) && !u12![2048].seq_lt(u12![0]));
+    /// # }
+    /// ```
+    pub const fn seq_lt(self, other: Self) -> bool {
+        let distance = self.wrapping_distance(other).0;
+        distance != 0 && distance < 0x800
+    }
+
+    /// Compares `self` and `other` as serial numbers, in the style of RFC 1982. Returns
+    /// `true` if `self` is ahead of `other` by fewer than half of the 4096 values. This is
+    /// `other.seq_lt(self)`.
+    pub const fn seq_gt(self, other: Self) -> bool {
+        other.seq_lt(self)
+    }
+}
+
+// MARK: - Flagged Arithmetic
+
+/// The condition flags produced by an arithmetic operation, as kept in the status register
+/// of a CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Flags {
+    /// The unsigned result wrapped around: a carry out of bit 11 for additions, or a borrow
+    /// for subtractions.
+    pub carry: bool,
+    /// The result is zero.
+    pub zero: bool,
+    /// Bit 11 of the result is set, i.e. the result is negative in two's complement.
+    pub negative: bool,
+    /// The result is out of range when the operands are interpreted as two's complement,
+    /// i.e. the sign of the result is wrong.
+    pub overflow: bool,
+}
+
+impl U12 {
+    /// Computes `self + other` with wrapping, together with the condition flags of the
+    /// addition.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let (result, flags) = u12![0x7FF].flag_add(u12![1]);
+    /// assert_eq!(result, u12![0x800]);
+    /// assert!(!flags.carry && !flags.zero && flags.negative && flags.overflow);
+    ///
+    /// let (result, flags) = u12![0xFFF].flag_add(u12![1]);
+    /// assert_eq!(result, u12![0]);
+    /// assert!(flags.carry && flags.zero && !flags.negative && !flags.overflow);
+    /// # }
+    /// ```
+    pub const fn flag_add(self, other: Self) -> (Self, Flags) {
+        self.flag_add_with_carry(other, false)
+    }
+
+    /// Computes `self + other + carry` with wrapping, together with the condition flags of
+    /// the addition, as an add-with-carry instruction does.
+    pub const fn flag_add_with_carry(self, other: Self, carry: bool) -> (Self, Flags) {
+        let sum = self.0 + other.0 + carry as u16;
+        let result = U12(sum & 0xFFF);
+        // Overflow occurs when both operands have the same sign and the result does not.
+        let overflow = (self.0 ^ result.0) & (other.0 ^ result.0) & 0x800 != 0;
+        (result, Flags::of(result, sum > 0xFFF, overflow))
+    }
+
+    /// Computes `self - other` with wrapping, together with the condition flags of the
+    /// subtraction. The carry flag is set when the subtraction borrows, i.e. when `other`
+    /// is greater than `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let (result, flags) = u12![5].flag_sub(u12![5]);
+    /// assert_eq!(result, u12![0]);
+    /// assert!(!flags.carry && flags.zero);
+    ///
+    /// let (result, flags) = u12![0x800].flag_sub(u12![1]);
+    /// assert_eq!(result, u12![0x7FF]);
+    /// assert!(!flags.carry && !flags.negative && flags.overflow);
+    /// # }
+    /// ```
+    pub const fn flag_sub(self, other: Self) -> (Self, Flags) {
+        self.flag_sub_with_borrow(other, false)
+    }
+
+    /// Computes `self - other - borrow` with wrapping, together with the condition flags of
+    /// the subtraction, as a subtract-with-borrow instruction does. The carry flag is set
+    /// when the subtraction borrows.
+    pub const fn flag_sub_with_borrow(self, other: Self, borrow: bool) -> (Self, Flags) {
+        let subtrahend = other.0 + borrow as u16;
+        let result = U12(self.0.wrapping_sub(subtrahend) & 0xFFF);
+        // Overflow occurs when the operands have different signs and the result has the
+        // sign of the subtrahend.
+        let overflow = (self.0 ^ other.0) & (self.0 ^ result.0) & 0x800 != 0;
+        (result, Flags::of(result, subtrahend > self.0, overflow))
+    }
+}
+
+impl Flags {
+    /// Returns the flags of an operation that produced `result`.
+    const fn of(result: U12, carry: bool, overflow: bool) -> Self {
+        Flags {
+            carry,
+            zero: result.0 == 0,
+            negative: result.0 & 0x800 != 0,
+            overflow,
+        }
+    }
+}
+
+// MARK: - Proportional Conversions
+
+impl U12 {
+    /// Converts a percentage of full scale into the nearest value, such that `0` maps to
+    /// `U12::min_value()` and `100` maps to `U12::max_value()`. Returns `None` if `percent`
+    /// is greater than `100`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_percent(0), Some(U12::min_value()));
+    /// assert_eq!(U12::from_percent(50), Some(u12![2048]));
+    /// assert_eq!(U12::from_percent(100), Some(U12::max_value()));
+    /// assert_eq!(U12::from_percent(101), None);
+    /// # }
+    /// ```
+    pub const fn from_percent(percent: u8) -> Option<Self> {
+        Self::from_fraction(percent as u32, 100)
+    }
+
+    /// Converts a permille (tenth of a percent) of full scale into the nearest value, such
+    /// that `0` maps to `U12::min_value()` and `1000` maps to `U12::max_value()`. Returns
+    /// `None` if `permille` is greater than `1000`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_permille(500), Some(u12![2048]));
+    /// assert_eq!(U12::from_permille(1000), Some(U12::max_value()));
+    /// assert_eq!(U12::from_permille(1001), None);
+    /// # }
+    /// ```
+    pub const fn from_permille(permille: u16) -> Option<Self> {
+        Self::from_fraction(permille as u32, 1000)
+    }
+
+    /// Returns `self` as the nearest percentage of full scale, in the range `0...100`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![2048].to_percent(), 50);
+    /// assert_eq!(U12::max_value().to_percent(), 100);
+    /// # }
+    /// ```
+    pub const fn to_percent(self) -> u8 {
+        self.to_fraction(100) as u8
+    }
+
+    /// Returns `self` as the nearest permille (tenth of a percent) of full scale, in the
+    /// range `0...1000`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![2048].to_permille(), 500);
+    /// assert_eq!(U12::max_value().to_permille(), 1000);
+    /// # }
+    /// ```
+    pub const fn to_permille(self) -> u16 {
+        self.to_fraction(1000) as u16
+    }
+
+    /// Linear interpolation between `self` and `other`.
+    /// Computes `self + (other - self) * t / 4095`, rounded to the nearest value, where `t`
+    /// is the interpolation fraction such that `U12::min_value()` returns `self` and
+    /// `U12::max_value()` returns `other`. The computation uses a widened intermediate and
+    /// cannot overflow.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![100].lerp(u12![200], U12::min_value()), u12![100]);
+    /// assert_eq!(u12![100].lerp(u12![200], u12![2048]), u12![150]);
+    /// assert_eq!(u12![200].lerp(u12![100], u12![2048]), u12![150]);
+    /// assert_eq!(u12![100].lerp(u12![200], U12::max_value()), u12![200]);
+    /// # }
+    /// ```
+    pub const fn lerp(self, other: Self, t: Self) -> Self {
+        let full_scale = MAX.0 as u32;
+        let t = t.0 as u32;
+        let weighted = self.0 as u32 * (full_scale - t) + other.0 as u32 * t;
+        U12(((weighted + full_scale / 2) / full_scale) as u16)
+    }
+
+    /// Scaling by a rational factor.
+    /// Computes `self * num / den` using a widened intermediate, so that the product
+    /// cannot overflow, rounding the quotient toward zero. Returns `None` if `den == 0`
+    /// or the result does not fit in 12 bits.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// // Scale a full-scale ADC reading to millivolts with a 3300 mV reference.
+    /// assert_eq!(U12::max_value().mul_div(u12![3300], U12::max_value()), Some(u12![3300]));
+    /// assert_eq!(u12![2048].mul_div(u12![3], u12![2]), Some(u12![3072]));
+    /// assert_eq!(u12![4000].mul_div(u12![2], u12![1]), None);
+    /// assert_eq!(u12![4000].mul_div(u12![2], u12![0]), None);
+    /// # }
+    /// ```
+    pub const fn mul_div(self, num: Self, den: Self) -> Option<Self> {
+        match (self.0 as u32 * num.0 as u32).checked_div(den.0 as u32) {
+            Some(result) if result <= MAX.0 as u32 => Some(U12(result as u16)),
+            _ => None,
+        }
+    }
+
+    /// Converts an ADC reading to the nearest millivolt, given the reference voltage
+    /// `vref_mv` in millivolts. Full scale follows the same convention as the other
+    /// proportional conversions: `U12::min_value()` maps to 0 mV and `U12::max_value()`
+    /// maps to exactly `vref_mv`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::max_value().to_millivolts(3300), 3300);
+    /// assert_eq!(u12![2048].to_millivolts(3300), 1650);
+    /// assert_eq!(U12::min_value().to_millivolts(3300), 0);
+    /// # }
+    /// ```
+    pub const fn to_millivolts(self, vref_mv: u32) -> u32 {
+        let full_scale = MAX.0 as u64;
+        ((self.0 as u64 * vref_mv as u64 + full_scale / 2) / full_scale) as u32
+    }
+
+    /// Converts a voltage in millivolts to the nearest DAC code, given the reference
+    /// voltage `vref_mv` in millivolts. Voltages at or above `vref_mv` saturate to
+    /// `U12::max_value()`. This is the inverse of `to_millivolts`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_millivolts(1650, 3300), u12![2048]);
+    /// assert_eq!(U12::from_millivolts(3300, 3300), U12::max_value());
+    /// assert_eq!(U12::from_millivolts(5000, 3300), U12::max_value());
+    /// # }
+    /// ```
+    pub const fn from_millivolts(mv: u32, vref_mv: u32) -> Self {
+        if mv >= vref_mv {
+            MAX
+        } else {
+            let scaled = mv as u64 * MAX.0 as u64 + vref_mv as u64 / 2;
+            U12((scaled / vref_mv as u64) as u16)
+        }
+    }
+
+    /// Returns the nearest value to `numerator / denominator` of full scale, rounding
+    /// halves up, or `None` if the fraction exceeds one.
+    const fn from_fraction(numerator: u32, denominator: u32) -> Option<Self> {
+        if numerator > denominator {
+            None
+        } else {
+            Some(U12(
+                ((numerator * MAX.0 as u32 + denominator / 2) / denominator) as u16,
+            ))
+        }
+    }
+
+    /// Returns the nearest number of `1 / denominator` steps of full scale in `self`,
+    /// rounding halves up.
+    const fn to_fraction(self, denominator: u32) -> u32 {
+        (self.0 as u32 * denominator + MAX.0 as u32 / 2) / MAX.0 as u32
+    }
+}
+
+// MARK: - Floating-Point Conversions
+
+/// The rounding applied when converting a floating-point number to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest integer, with halfway cases rounded away from zero.
+    Nearest,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+impl Rounding {
+    /// Returns `value` rounded to an integer in this mode.
+    fn apply(self, value: f32) -> f32 {
+        match self {
+            Rounding::Nearest => value.round(),
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+        }
+    }
+}
+
+impl U12 {
+    /// Converts `value` to a `U12`, rounding as specified by `rounding`. Returns `None` if
+    /// `value` is NaN, or if the rounded value is outside the range `0...4095`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_f32(2.5, Rounding::Nearest), Some(u12![3]));
+    /// assert_eq!(U12::from_f32(2.5, Rounding::Floor), Some(u12![2]));
+    /// assert_eq!(U12::from_f32(4094.2, Rounding::Ceil), Some(u12![4095]));
+    /// assert_eq!(U12::from_f32(4095.5, Rounding::Nearest), None);
+    /// assert_eq!(U12::from_f32(-0.4, Rounding::Nearest), Some(u12![0]));
+    /// assert_eq!(U12::from_f32(f32::NAN, Rounding::Nearest), None);
+    /// # }
+    /// ```
+    pub fn from_f32(value: f32, rounding: Rounding) -> Option<Self> {
+        let rounded = rounding.apply(value);
+        if (0.0..=4095.0).contains(&rounded) {
+            Some(U12(rounded as u16))
+        } else {
+            None
+        }
+    }
+
+    /// Converts `value` to a `U12`, rounding as specified by `rounding` and clamping the
+    /// result to the range `0...4095`. NaN converts to zero, like a primitive `as` cast.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::saturating_from_f32(1e6, Rounding::Nearest), U12::max_value());
+    /// assert_eq!(U12::saturating_from_f32(-3.0, Rounding::Nearest), U12::min_value());
+    /// assert_eq!(U12::saturating_from_f32(f32::NAN, Rounding::Nearest), U12::min_value());
+    /// # }
+    /// ```
+    pub fn saturating_from_f32(value: f32, rounding: Rounding) -> Self {
+        if value.is_nan() {
+            MIN
+        } else {
+            U12(rounding.apply(value).clamp(0.0, 4095.0) as u16)
+        }
+    }
+}
+
+// MARK: - Companding
+
+impl U12 {
+    /// Compresses `self` to an 8-bit code on a logarithmic curve, in the style of the A-law
+    /// and µ-law codecs. The code holds a 3-bit segment and a 5-bit mantissa. Values below
+    /// `64` are encoded exactly, and each following segment doubles both its range and its
+    /// step size, so the error after expansion stays within 1/64 of the value across the whole range.
+    /// Codes preserve order, so they can be compared directly.
+    ///
+    /// | Segment | Values          | Step |
+    /// |---------|-----------------|------|
+    /// | 0 and 1 | `0...63`        | 1    |
+    /// | 2       | `64...127`      | 2    |
+    /// | 3       | `128...255`     | 4    |
+    /// | 4       | `256...511`     | 8    |
+    /// | 5       | `512...1023`    | 16   |
+    /// | 6       | `1024...2047`   | 32   |
+    /// | 7       | `2048...4095`   | 64   |
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![40].compand_to_u8(), 40);
+    /// assert_eq!(u12![1005].compand_to_u8(), 0xBE);
+    /// assert_eq!(U12::expand_from_u8(0xBE), u12![1000]);
+    /// # }
+    /// ```
+    pub const fn compand_to_u8(self) -> u8 {
+        if self.0 < 32 {
+            return self.0 as u8;
+        }
+        let segment = 11 - self.0.leading_zeros();
+        let mantissa = (self.0 >> (segment - 1)) & 0x1F;
+        (segment << 5) as u8 | mantissa as u8
+    }
+
+    /// Expands an 8-bit code produced by `compand_to_u8()` back to a linear value. Codes
+    /// in the lossy segments expand to the middle of the range of values they represent,
+    /// which halves the worst-case error compared to truncation.
+    pub const fn expand_from_u8(code: u8) -> Self {
+        let segment = (code >> 5) as u16;
+        let mantissa = (code & 0x1F) as u16;
+        match segment {
+            0 => U12(mantissa),
+            1 => U12(0x20 | mantissa),
+            _ => U12(((0x20 | mantissa) << (segment - 1)) + (1 << (segment - 2))),
+        }
+    }
+}
+
+// MARK: - Non-Failable Conversions - From Smaller Types
+
+impl From<u8> for U12 {
+    fn from(small: u8) -> Self {
+        U12(small as u16)
+    }
+}
+
+// MARK: - Non-Failable Conversions - Into Larger Types
+
+/// Implements From<U12> for the specified type.
+macro_rules! impl_from_u12 {
+    ($result:path) => {
+        impl From<U12> for $result {
+            fn from(small: U12) -> Self {
+                small.0 as Self
+            }
+        }
+    };
+}
+
+impl_from_u12!(u16);
+impl_from_u12!(u32);
+impl_from_u12!(u64);
+impl_from_u12!(usize);
+impl_from_u12!(u128);
+impl_from_u12!(i16);
+impl_from_u12!(i32);
+impl_from_u12!(i64);
+impl_from_u12!(i128);
+
+// MARK: - Failable Conversions - From Larger Types
+
+/// Trait for implementing failable conversions in a generic way.
+pub trait FailableInto<T>
+where
+    Self: marker::Sized,
+    T: marker::Sized,
+{
+    /// Returns the receiver as `Some(T)` if non-truncating, or `None`.
+    fn failable_into(self) -> Option<T>;
+
+    /// Returns the receiver as `T` by using `failable_into()` and unwrapping the result.
+    ///
+    /// # Panics
+    /// This method will panic if `failable_into` fails.
+    fn unchecked_into(self) -> T {
+        match self.failable_into() {
+            Some(value) => value,
+            None => panic!("unchecked conversion failed"),
+        }
+    }
+}
+
+/// Implements FailableAs<U12> for the specified type.
+macro_rules! impl_failable_into_u12 {
+    ($source_type:path) => {
+        impl FailableInto<U12> for $source_type {
+            fn failable_into(self) -> Option<U12> {
+                if self > 0xFFF {
+                    None
+                } else {
+                    Some(U12(self as u16))
+                }
+            }
+        }
+    };
+}
+
+impl_failable_into_u12!(u16);
+impl_failable_into_u12!(u32);
+impl_failable_into_u12!(u64);
+impl_failable_into_u12!(usize);
+
+// MARK: - Failable Conversions - From Signed Types
+
+/// Implements FailableInto<U12> for the specified signed type.
+macro_rules! impl_failable_into_u12_from_signed {
+    ($source_type:path) => {
+        impl FailableInto<U12> for $source_type {
+            fn failable_into(self) -> Option<U12> {
+                if self < 0 || self as i64 > 0xFFF {
+                    None
+                } else {
+                    Some(U12(self as u16))
+                }
+            }
+        }
+    };
+}
+
+impl_failable_into_u12_from_signed!(i8);
+impl_failable_into_u12_from_signed!(i16);
+impl_failable_into_u12_from_signed!(i32);
+impl_failable_into_u12_from_signed!(i64);
+impl_failable_into_u12_from_signed!(isize);
+
+// MARK: - Saturating Conversions - From Larger Types
+
+/// Trait for implementing conversions which clamp out-of-range values to the nearest bound.
+pub trait SaturatingFrom<T>
+where
+    Self: marker::Sized,
+    T: marker::Sized,
+{
+    /// Returns `value` converted to `Self`, clamped to the largest value of `Self` if it
+    /// does not fit.
+    fn saturating_from(value: T) -> Self;
+}
+
+/// Implements SaturatingFrom<$source_type> for U12.
+macro_rules! impl_saturating_from_for_u12 {
+    ($source_type:path) => {
+        impl SaturatingFrom<$source_type> for U12 {
+            fn saturating_from(value: $source_type) -> Self {
+                if value > 0xFFF {
+                    MAX
+                } else {
+                    U12(value as u16)
+                }
+            }
+        }
+    };
+}
+
+impl_saturating_from_for_u12!(u16);
+impl_saturating_from_for_u12!(u32);
+impl_saturating_from_for_u12!(u64);
+impl_saturating_from_for_u12!(usize);
+impl_saturating_from_for_u12!(u128);
+
+// MARK: - Truncating Conversions - From Larger Types
+
+/// Trait for implementing conversions which intentionally discard the high bits of a value.
+pub trait TruncatingFrom<T>
+where
+    Self: marker::Sized,
+    T: marker::Sized,
+{
+    /// Returns the low bits of `value` which fit in `Self`, discarding the rest. This is
+    /// lossy by design, and is the named equivalent of masking with `& 0xFFF`.
+    fn truncate_from(value: T) -> Self;
+}
+
+/// Implements TruncatingFrom<$source_type> for U12.
+macro_rules! impl_truncating_from_for_u12 {
+    ($source_type:path) => {
+        impl TruncatingFrom<$source_type> for U12 {
+            fn truncate_from(value: $source_type) -> Self {
+                U12((value & 0xFFF) as u16)
+            }
+        }
+    };
+}
+
+impl_truncating_from_for_u12!(u16);
+impl_truncating_from_for_u12!(u32);
+impl_truncating_from_for_u12!(u64);
+impl_truncating_from_for_u12!(usize);
+impl_truncating_from_for_u12!(u128);
+
+// MARK: - Narrowing Conversions - Into Smaller Types
+
+impl U12 {
+    /// Returns `self` as a `u8` if it is at most `255`, or `None` otherwise.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![255].try_to_u8(), Some(255));
+    /// assert_eq!(u12![256].try_to_u8(), None);
+    /// # }
+    /// ```
+    pub const fn try_to_u8(self) -> Option<u8> {
+        if self.0 > 0xFF {
+            None
+        } else {
+            Some(self.0 as u8)
+        }
+    }
+
+    /// Returns `self` as a `u8`, clamped to `255` if it does not fit.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![200].to_u8_saturating(), 200);
+    /// assert_eq!(u12![0x123].to_u8_saturating(), 255);
+    /// # }
+    /// ```
+    pub const fn to_u8_saturating(self) -> u8 {
+        if self.0 > 0xFF {
+            0xFF
+        } else {
+            self.0 as u8
+        }
+    }
+
+    /// Returns the low eight bits of `self`, discarding the high nibble. This is lossy by
+    /// design, and is the named equivalent of `u16::from(value) as u8`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x123].to_u8_truncating(), 0x23);
+    /// # }
+    /// ```
+    pub const fn to_u8_truncating(self) -> u8 {
+        self.0 as u8
+    }
+}
+
+// MARK: - Character Conversions
+
+/// Converts a value to the character with the same code point. Every code point from `0x000`
+/// to `0xFFF` is a valid Unicode scalar value, so this conversion cannot fail.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// # fn main() {
+/// assert_eq!(char::from(u12![0x41]), 'A');
+/// assert_eq!(char::from(u12![0x3A9]), 'Ω');
+/// # }
+/// ```
+impl From<U12> for char {
+    fn from(small: U12) -> Self {
+        match char::from_u32(small.0 as u32) {
+            Some(c) => c,
+            None => unreachable!("every 12-bit code point is a scalar value"),
+        }
+    }
+}
+
+/// The error returned when converting a character above `U+0FFF` to a `U12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromCharError(());
+
+impl fmt::Display for TryFromCharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("character code point out of range for U12")
+    }
+}
+
+impl Error for TryFromCharError {}
+
+/// Converts a character to the value of its code point, if it is at most `U+0FFF`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use std::convert::TryFrom;
+/// use twelve_bit::u12::*;
+/// # fn main() {
+/// assert_eq!(U12::try_from('Ω'), Ok(u12![0x3A9]));
+/// assert!(U12::try_from('😀').is_err());
+/// # }
+/// ```
+impl TryFrom<char> for U12 {
+    type Error = TryFromCharError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let code_point = c as u32;
+        if code_point > 0xFFF {
+            Err(TryFromCharError(()))
+        } else {
+            Ok(U12(code_point as u16))
+        }
+    }
+}
+
+// MARK: - Field Access - Within Wider Words
+
+/// Trait for unsigned words that can contain a 12-bit field at an arbitrary bit offset, such
+/// as memory-mapped registers.
+pub trait FieldWord
+where
+    Self: marker::Sized,
+{
+    /// Returns the 12-bit field whose least significant bit is at `lsb`, or `None` if the
+    /// field would extend past the most significant bit of `self`.
+    fn checked_extract_u12(self, lsb: u32) -> Option<U12>;
+
+    /// Returns `self` with the 12-bit field whose least significant bit is at `lsb` replaced
+    /// by `value`, or `None` if the field would extend past the most significant bit of
+    /// `self`. All other bits are left unchanged.
+    fn checked_insert_u12(self, lsb: u32, value: U12) -> Option<Self>;
+}
+
+/// Implements FieldWord for $word_type.
+macro_rules! impl_field_word_for_u12 {
+    ($word_type:ident) => {
+        impl FieldWord for $word_type {
+            fn checked_extract_u12(self, lsb: u32) -> Option<U12> {
+                if lsb > $word_type::BITS - 12 {
+                    return None;
+                }
+                Some(U12((self >> lsb) as u16 & 0xFFF))
+            }
+
+            fn checked_insert_u12(self, lsb: u32, value: U12) -> Option<Self> {
+                if lsb > $word_type::BITS - 12 {
+                    return None;
+                }
+                let mask = (0xFFF as $word_type) << lsb;
+                Some(self & !mask | (value.0 as $word_type) << lsb)
+            }
+        }
+    };
+}
+
+impl_field_word_for_u12!(u16);
+impl_field_word_for_u12!(u32);
+impl_field_word_for_u12!(u64);
+
+impl U12 {
+    /// Returns the 12-bit field of `word` whose least significant bit is at `lsb`, or
+    /// `None` if the field would extend past the most significant bit of `word`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::checked_extract_from(0x00AB_C000u32, 12), Some(u12![0xABC]));
+    /// assert_eq!(U12::checked_extract_from(0xFFFFu16, 5), None);
+    /// # }
+    /// ```
+    pub fn checked_extract_from<W: FieldWord>(word: W, lsb: u32) -> Option<Self> {
+        word.checked_extract_u12(lsb)
+    }
+
+    /// Returns the 12-bit field of `word` whose least significant bit is at `lsb`.
+    ///
+    /// # Panics
+    /// This method will panic if the field would extend past the most significant bit of
+    /// `word`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let register: u32 = 0x8000_0000 | 0x123 << 4 | 0x1;
+    /// assert_eq!(U12::extract_from(register, 4), u12![0x123]);
+    /// # }
+    /// ```
+    pub fn extract_from<W: FieldWord>(word: W, lsb: u32) -> Self {
+        match word.checked_extract_u12(lsb) {
+            Some(value) => value,
+            None => panic!("field out of bounds"),
+        }
+    }
+
+    /// Replaces the 12-bit field of `word` whose least significant bit is at `lsb` with
+    /// `self`, leaving all other bits of `word` unchanged.
+    ///
+    /// # Panics
+    /// This method will panic if the field would extend past the most significant bit of
+    /// `word`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let mut register: u32 = 0x8000_FFF1;
+    /// u12![0x123].insert_into(&mut register, 4);
+    /// assert_eq!(register, 0x8000_1231);
+    /// # }
+    /// ```
+    pub fn insert_into<W: FieldWord + Copy>(self, word: &mut W, lsb: u32) {
+        match word.checked_insert_u12(lsb, self) {
+            Some(updated) => *word = updated,
+            None => panic!("field out of bounds"),
+        }
+    }
+}
+
+// MARK: - Narrow Integer Trait
+
+/// Trait for unsigned integers narrower than the primitive type that stores them, so that
+/// codecs and containers can be written once and monomorphized over the field width.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+///
+/// fn checked_total<T: NarrowUInt>(values: &[T]) -> Option<T> {
+///     values.iter().try_fold(T::MIN, |sum, &value| sum.checked_add(value))
+/// }
+///
+/// # fn main() {
+/// assert_eq!(checked_total(&[u12![4000], u12![95]]), Some(u12![4095]));
+/// assert_eq!(checked_total(&[u12![4000], u12![96]]), None);
+/// assert_eq!(<U12 as NarrowUInt>::narrow(0x1000), None);
+/// # }
+/// ```
+pub trait NarrowUInt
+where
+    Self: marker::Sized + Copy + Debug + Eq + Ord,
+{
+    /// The primitive type that stores values of `Self`.
+    type Wide: Copy;
+
+    /// The number of bits in a value of `Self`.
+    const BITS: u32;
+
+    /// The smallest value of `Self`.
+    const MIN: Self;
+
+    /// The largest value of `Self`.
+    const MAX: Self;
+
+    /// Returns the sum of `self` and `other`, or `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns the sum of `self` and `other`, wrapping around at the boundary of the type.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Returns the difference of `self` and `other`, or `None` on underflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Returns the difference of `self` and `other`, wrapping around at the boundary of
+    /// the type.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// Returns the product of `self` and `other`, or `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Returns the product of `self` and `other`, wrapping around at the boundary of the
+    /// type.
+    fn wrapping_mul(self, other: Self) -> Self;
+
+    /// Returns `self` as its storage type. This conversion is lossless.
+    fn widen(self) -> Self::Wide;
+
+    /// Returns `wide` as a value of `Self`, or `None` if it exceeds `MAX`.
+    fn narrow(wide: Self::Wide) -> Option<Self>;
+
+    /// Returns the low `BITS` bits of `wide` as a value of `Self`, discarding the rest.
+    fn narrow_truncated(wide: Self::Wide) -> Self;
+}
+
+impl NarrowUInt for U12 {
+    type Wide = u16;
+
+    const BITS: u32 = 12;
+    const MIN: U12 = MIN;
+    const MAX: U12 = MAX;
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        U12::checked_add(self, other)
+    }
+
+    fn wrapping_add(self, other: Self) -> Self {
+        U12::wrapping_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        U12::checked_sub(self, other)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        U12::wrapping_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        U12::checked_mul(self, other)
+    }
+
+    fn wrapping_mul(self, other: Self) -> Self {
+        U12::wrapping_mul(self, other)
+    }
+
+    fn widen(self) -> u16 {
+        self.0
+    }
+
+    fn narrow(wide: u16) -> Option<Self> {
+        wide.failable_into()
+    }
+
+    fn narrow_truncated(wide: u16) -> Self {
+        U12::truncate_from(wide)
+    }
+}
+
+// MARK: - Formatting
+
+///
+/// Implements the formatting trait `$trait_name` for `U12` by forwarding to the implementation
+/// for the underlying `u16`, passing the formatter through so that width, fill, alignment,
+/// sign-aware zero-padding and the `#` alternate flag are all honored.
+///
+macro_rules! impl_fmt_trait_for_u12 {
+    ($trait_name:ident) => {
+        impl fmt::$trait_name for U12 {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::$trait_name::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_fmt_trait_for_u12!(Display);
+impl_fmt_trait_for_u12!(LowerHex);
+impl_fmt_trait_for_u12!(UpperHex);
+impl_fmt_trait_for_u12!(Octal);
+impl_fmt_trait_for_u12!(Binary);
+
+/// Formats the value as three uppercase hex digits, e.g. `U12(0xFFF)`. The alternate form,
+/// `{:#?}`, also shows the decimal value, e.g. `U12(0xFFF = 4095)`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// # fn main() {
+/// assert_eq!(format!("{:?}", u12![0x2A]), "U12(0x02A)");
+/// assert_eq!(format!("{:#?}", u12![0x2A]), "U12(0x02A = 42)");
+/// assert_eq!(format!("{:?}", Some(U12::max_value())), "Some(U12(0xFFF))");
+/// # }
+/// ```
+impl fmt::Debug for U12 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "U12(0x{:03X} = {})", self.0, self.0)
+        } else {
+            write!(f, "U12(0x{:03X})", self.0)
+        }
+    }
+}
+
+impl U12 {
+    /// Formats `self` in decimal into the start of `buf`, and returns the written digits.
+    /// This does not use the `std::fmt` machinery. A buffer of 4 bytes is always large
+    /// enough.
+    ///
+    /// # Panics
+    /// This method will panic if `buf` is too small to hold the digits of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let mut buf = [0; 4];
+    /// assert_eq!(u12![4095].write_dec(&mut buf), "4095");
+    /// assert_eq!(u12![7].write_dec(&mut buf), "7");
+    /// # }
+    /// ```
+    pub fn write_dec(self, buf: &mut [u8]) -> &str {
+        self.write_radix(buf, 10)
+    }
+
+    /// Formats `self` in lowercase hexadecimal, without a prefix, into the start of `buf`,
+    /// and returns the written digits. This does not use the `std::fmt` machinery. A buffer
+    /// of 3 bytes is always large enough.
+    ///
+    /// # Panics
+    /// This method will panic if `buf` is too small to hold the digits of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let mut buf = [0; 3];
+    /// assert_eq!(u12![0xABC].write_hex(&mut buf), "abc");
+    /// # }
+    /// ```
+    pub fn write_hex(self, buf: &mut [u8]) -> &str {
+        self.write_radix(buf, 16)
+    }
+
+    /// Formats `self` in octal, without a prefix, into the start of `buf`, and returns the
+    /// written digits. This does not use the `std::fmt` machinery. A buffer of 4 bytes is
+    /// always large enough.
+    ///
+    /// # Panics
+    /// This method will panic if `buf` is too small to hold the digits of `self`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let mut buf = [0; 4];
+    /// assert_eq!(u12![0o7654].write_oct(&mut buf), "7654");
+    /// # }
+    /// ```
+    pub fn write_oct(self, buf: &mut [u8]) -> &str {
+        self.write_radix(buf, 8)
+    }
 
-// MARK: - Non-Failable Conversions - Into Larger Types
+    /// Writes the ASCII digits of `self` in base `radix` into the start of `buf`.
+    fn write_radix(self, buf: &mut [u8], radix: u32) -> &str {
+        let digits = self.digits(radix);
+        let len = digits.len();
+        assert!(buf.len() >= len, "buffer too small");
+        for (byte, digit) in buf.iter_mut().zip(digits) {
+            *byte = if digit < 10 {
+                b'0' + digit
+            } else {
+                b'a' + digit - 10
+            };
+        }
+        std::str::from_utf8(&buf[..len]).unwrap()
+    }
+}
 
-/// Implements From<U12> for the specified type.
-macro_rules! impl_from_u12 {
-    ($result:path) => {
-        impl From<U12> for $result {
-            fn from(small: U12) -> Self {
-                small.0 as Self
-            }
+// MARK: - Default
+
+impl Default for U12 {
+    fn default() -> Self {
+        U12::min_value()
+    }
+}
+
+// MARK: - Bit Iteration
+
+/// An iterator over the bits of a `U12`, starting with the least-significant bit.
+/// This is created by the `U12::bits()` method.
+#[derive(Debug, Clone)]
+pub struct Bits {
+    value: u16,
+    index: u32,
+}
+
+impl Iterator for Bits {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.index >= 12 {
+            None
+        } else {
+            let bit = (self.value >> self.index) & 1 == 1;
+            self.index += 1;
+            Some(bit)
         }
-    };
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (12 - self.index) as usize;
+        (remaining, Some(remaining))
+    }
 }
 
-impl_from_u12!(u16);
-impl_from_u12!(u32);
-impl_from_u12!(u64);
-impl_from_u12!(usize);
+impl ExactSizeIterator for Bits {}
 
-// MARK: - Failable Conversions - From Larger Types
+/// An iterator over the indices of the set or clear bits of a `U12`, in ascending order.
+/// This is created by the `U12::iter_ones()` and `U12::iter_zeros()` methods.
+#[derive(Debug, Clone)]
+pub struct BitIndices {
+    remaining: u16,
+}
 
-/// Trait for implementing failable conversions in a generic way.
-pub trait FailableInto<T>
-where
-    Self: marker::Sized,
-    T: marker::Sized,
-{
-    /// Returns the receiver as `Some(T)` if non-truncating, or `None`.
-    fn failable_into(self) -> Option<T>;
+impl Iterator for BitIndices {
+    type Item = u32;
 
-    /// Returns the receiver as `T` by using `failable_into()` and unwrapping the result.
-    ///
-    /// # Panics
-    /// This method will panic if `failable_into` fails.
-    fn unchecked_into(self) -> T {
-        match self.failable_into() {
+    fn next(&mut self) -> Option<u32> {
+        if self.remaining == 0 {
+            None
+        } else {
+            let index = self.remaining.trailing_zeros();
+            self.remaining &= self.remaining - 1;
+            Some(index)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for BitIndices {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.remaining == 0 {
+            None
+        } else {
+            let index = 15 - self.remaining.leading_zeros();
+            self.remaining &= !(1 << index);
+            Some(index)
+        }
+    }
+}
+
+impl ExactSizeIterator for BitIndices {}
+
+/// Collects up to 12 bits, least-significant first, into a `U12`.
+///
+/// # Panics
+/// Panics if the iterator yields more than 12 bits. Use `U12::try_from_bits()` to
+/// handle this case without panicking.
+impl FromIterator<bool> for U12 {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        match U12::try_from_bits(iter) {
             Some(value) => value,
-            None => panic!("unchecked conversion failed"),
+            None => panic!("too many bits for an u12"),
         }
     }
 }
 
-/// Implements FailableAs<U12> for the specified type.
-macro_rules! impl_failable_into_u12 {
-    ($source_type:path) => {
-        impl FailableInto<U12> for $source_type {
-            fn failable_into(self) -> Option<U12> {
-                if self > 0xFFF {
-                    None
-                } else {
-                    Some(U12(self as u16))
-                }
-            }
+// MARK: - Digit Iteration
+
+/// An iterator over the digits of a `U12` in some radix, starting with the most-significant
+/// digit. This is created by the `U12::digits()` method.
+#[derive(Debug, Clone)]
+pub struct Digits {
+    value: u16,
+    radix: u16,
+    divisor: u16,
+}
+
+impl Iterator for Digits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let digit = self.value.checked_div(self.divisor)?;
+        self.value %= self.divisor;
+        self.divisor /= self.radix;
+        Some(digit as u8)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut remaining = 0;
+        let mut divisor = self.divisor;
+        while divisor != 0 {
+            remaining += 1;
+            divisor /= self.radix;
         }
-    };
+        (remaining, Some(remaining))
+    }
 }
 
-impl_failable_into_u12!(u16);
-impl_failable_into_u12!(u32);
-impl_failable_into_u12!(u64);
-impl_failable_into_u12!(usize);
+impl ExactSizeIterator for Digits {}
 
-// MARK: - Default
+// MARK: - Iterator Extensions
 
-impl Default for U12 {
-    fn default() -> Self {
-        U12::min_value()
+/// Extension trait providing checked reductions over iterators of `U12` values, or of
+/// references to them.
+pub trait U12IteratorExt: Iterator + marker::Sized
+where
+    Self::Item: Borrow<U12>,
+{
+    /// Sums the values of the iterator, returning `None` as soon as the running total
+    /// overflows. The sum of an empty iterator is zero.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let samples = [u12![1000], u12![2000], u12![1000]];
+    /// assert_eq!(samples.iter().checked_sum_u12(), Some(u12![4000]));
+    /// assert_eq!(samples.iter().cycle().take(5).checked_sum_u12(), None);
+    /// # }
+    /// ```
+    fn checked_sum_u12(mut self) -> Option<U12> {
+        self.try_fold(MIN, |sum, value| sum.checked_add(*value.borrow()))
+    }
+
+    /// Multiplies the values of the iterator, returning `None` as soon as the running
+    /// product overflows. The product of an empty iterator is one.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!([u12![2], u12![3], u12![4]].iter().checked_product_u12(), Some(u12![24]));
+    /// assert_eq!([u12![64], u12![64]].iter().checked_product_u12(), None);
+    /// # }
+    /// ```
+    fn checked_product_u12(mut self) -> Option<U12> {
+        self.try_fold(U12(1), |product, value| {
+            product.checked_mul(*value.borrow())
+        })
     }
 }
 
+impl<I> U12IteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<U12>,
+{
+}
+
 // MARK: - Arithmetic Operator Traits (Add, Sub, Mul, Div)
 
+/// Whether the `Add`, `Sub` and `Mul` operator families wrap on overflow instead of
+/// panicking. This mirrors the primitive integer types, which only check for overflow
+/// in builds with debug assertions, and is opt-in via the `primitive-overflow` feature.
+const OPERATORS_WRAP: bool = cfg!(all(feature = "primitive-overflow", not(debug_assertions)));
+
+impl U12 {
+    /// Addition as performed by the `Add` operator family.
+    /// Returns `None` if the operator should panic.
+    const fn operator_add(self, other: Self) -> Option<Self> {
+        if OPERATORS_WRAP {
+            Some(self.wrapping_add(other))
+        } else {
+            self.checked_add(other)
+        }
+    }
+
+    /// Subtraction as performed by the `Sub` operator family.
+    /// Returns `None` if the operator should panic.
+    const fn operator_sub(self, other: Self) -> Option<Self> {
+        if OPERATORS_WRAP {
+            Some(self.wrapping_sub(other))
+        } else {
+            self.checked_sub(other)
+        }
+    }
+
+    /// Multiplication as performed by the `Mul` operator family.
+    /// Returns `None` if the operator should panic.
+    const fn operator_mul(self, other: Self) -> Option<Self> {
+        if OPERATORS_WRAP {
+            Some(self.wrapping_mul(other))
+        } else {
+            self.checked_mul(other)
+        }
+    }
+}
+
 ///
 /// Implements an arithmetic trait family for `U12`. This macro generates
 /// implementations for an arithmetic trait `$trait_name` such that the
@@ -920,25 +3263,25 @@ macro_rules! impl_arithmetic_assign_trait_family_for_u12 {
     };
 }
 
-impl_arithmetic_trait_family_for_u12!(Add, add, checked_add, "arithmetic overflow");
+impl_arithmetic_trait_family_for_u12!(Add, add, operator_add, "arithmetic overflow");
 impl_arithmetic_assign_trait_family_for_u12!(
     AddAssign,
     add_assign,
-    checked_add,
+    operator_add,
     "arithmetic overflow"
 );
-impl_arithmetic_trait_family_for_u12!(Sub, sub, checked_sub, "arithmetic underflow");
+impl_arithmetic_trait_family_for_u12!(Sub, sub, operator_sub, "arithmetic underflow");
 impl_arithmetic_assign_trait_family_for_u12!(
     SubAssign,
     sub_assign,
-    checked_sub,
+    operator_sub,
     "arithmetic underflow"
 );
-impl_arithmetic_trait_family_for_u12!(Mul, mul, checked_mul, "arithmetic overflow");
+impl_arithmetic_trait_family_for_u12!(Mul, mul, operator_mul, "arithmetic overflow");
 impl_arithmetic_assign_trait_family_for_u12!(
     MulAssign,
     mul_assign,
-    checked_mul,
+    operator_mul,
     "arithmetic overflow"
 );
 impl_arithmetic_trait_family_for_u12!(Div, div, checked_div, "arithmetic exception");
@@ -965,7 +3308,7 @@ impl Not for U12 {
     }
 }
 
-impl<'a> Not for &'a U12 {
+impl Not for &U12 {
     type Output = U12;
     fn not(self) -> Self::Output {
         (*self).not()
@@ -999,9 +3342,148 @@ macro_rules! impl_bitwise_assign_trait_family_for_u12 {
 impl_bitwise_trait_family_for_u12!(BitAnd, bitand, checked_bitand);
 impl_bitwise_assign_trait_family_for_u12!(BitAndAssign, bitand_assign, checked_bitand);
 impl_bitwise_trait_family_for_u12!(BitOr, bitor, checked_bitor);
-impl_bitwise_assign_trait_family_for_u12!(BitOrAssign, bitor_assign, checked_bitand);
+impl_bitwise_assign_trait_family_for_u12!(BitOrAssign, bitor_assign, checked_bitor);
 impl_bitwise_trait_family_for_u12!(BitXor, bitxor, checked_bitxor);
-impl_bitwise_assign_trait_family_for_u12!(BitXorAssign, bitxor_assign, checked_bitand);
+impl_bitwise_assign_trait_family_for_u12!(BitXorAssign, bitxor_assign, checked_bitxor);
+
+// MARK: - Operations With Primitive Operands
+
+///
+/// Implements an operator trait family for `U12` with a primitive right-hand side.
+/// This macro generates implementations for `$trait_name` such that it is possible to
+/// invoke `$trait_method` on all of `U12.op($rhs_type)`, `(&'a U12).op($rhs_type)`,
+/// `U12.op(&'a $rhs_type)` and `(&'a U12).op(&'b $rhs_type)`, as well as the assigning
+/// `$assign_trait_name` for `U12 op= $rhs_type`. The right-hand side is converted to `U12`
+/// with `$convert` and the operation is then performed as for a `U12` right-hand side.
+///
+macro_rules! impl_primitive_operand_trait_family_for_u12 {
+    ($rhs_type:ident, $convert:path, $trait_name:ident, $trait_method:ident, $assign_trait_name:ident, $assign_trait_method:ident) => {
+        // Implementation of U12.op($rhs_type) -> U12.
+        impl $trait_name<$rhs_type> for U12 {
+            type Output = U12;
+            fn $trait_method(self, other: $rhs_type) -> Self::Output {
+                let other: U12 = $convert(other);
+                self.$trait_method(other)
+            }
+        }
+
+        // Implementation of (&'a U12).op($rhs_type) -> U12.
+        impl<'a> $trait_name<$rhs_type> for &'a U12 {
+            type Output = U12;
+            fn $trait_method(self, other: $rhs_type) -> Self::Output {
+                (*self).$trait_method(other)
+            }
+        }
+
+        // Implementation of U12.op(&'a $rhs_type) -> U12.
+        impl<'a> $trait_name<&'a $rhs_type> for U12 {
+            type Output = U12;
+            fn $trait_method(self, other: &'a $rhs_type) -> Self::Output {
+                self.$trait_method(*other)
+            }
+        }
+
+        // Implementation of (&'a U12).op(&'b $rhs_type) -> U12.
+        impl<'a, 'b> $trait_name<&'a $rhs_type> for &'b U12 {
+            type Output = U12;
+            fn $trait_method(self, other: &'a $rhs_type) -> Self::Output {
+                (*self).$trait_method(*other)
+            }
+        }
+
+        // Implementation of U12 op= $rhs_type.
+        impl $assign_trait_name<$rhs_type> for U12 {
+            fn $assign_trait_method(&mut self, other: $rhs_type) {
+                let other: U12 = $convert(other);
+                self.$assign_trait_method(other)
+            }
+        }
+    };
+}
+
+///
+/// Implements every arithmetic and bitwise operator trait family for `U12` with a
+/// right-hand side of `$rhs_type`. The operand of an arithmetic operator is converted to
+/// `U12` with `$arithmetic_convert`, and the operand of a bitwise operator with
+/// `$bitwise_convert`.
+///
+macro_rules! impl_primitive_operand_traits_for_u12 {
+    ($rhs_type:ident, $arithmetic_convert:path, $bitwise_convert:path) => {
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $arithmetic_convert,
+            Add,
+            add,
+            AddAssign,
+            add_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $arithmetic_convert,
+            Sub,
+            sub,
+            SubAssign,
+            sub_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $arithmetic_convert,
+            Mul,
+            mul,
+            MulAssign,
+            mul_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $arithmetic_convert,
+            Div,
+            div,
+            DivAssign,
+            div_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $arithmetic_convert,
+            Rem,
+            rem,
+            RemAssign,
+            rem_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $bitwise_convert,
+            BitAnd,
+            bitand,
+            BitAndAssign,
+            bitand_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $bitwise_convert,
+            BitOr,
+            bitor,
+            BitOrAssign,
+            bitor_assign
+        );
+        impl_primitive_operand_trait_family_for_u12!(
+            $rhs_type,
+            $bitwise_convert,
+            BitXor,
+            bitxor,
+            BitXorAssign,
+            bitxor_assign
+        );
+    };
+}
+
+// Only `u16` is accepted, so that an unsuffixed literal such as `pc + 2` has a single
+// candidate type. An arithmetic operand panics if it exceeds `U12::max_value()`, while a
+// bitwise operand is masked to its low 12 bits.
+impl_primitive_operand_traits_for_u12!(
+    u16,
+    FailableInto::unchecked_into,
+    TruncatingFrom::truncate_from
+);
 
 // MARK: - Logic Operations
 
@@ -1019,7 +3501,7 @@ macro_rules! impl_shift_trait_family_for_u12 {
         impl $trait_name<$rhs_type> for U12 {
             type Output = U12;
             fn $trait_method(self, other: $rhs_type) -> Self::Output {
-                if (other as u64) > (u32::max_value() as u64) {
+                if (other as u64) > (u32::MAX as u64) {
                     panic!($message)
                 } else {
                     match self.$checked_method(other as u32) {
@@ -1098,9 +3580,7 @@ impl ParseU12Error {
 
 impl From<ParseIntError> for ParseU12Error {
     fn from(x: ParseIntError) -> Self {
-        ParseU12Error {
-            kind: x.kind().clone(),
-        }
+        ParseU12Error { kind: *x.kind() }
     }
 }
 
@@ -1110,11 +3590,28 @@ impl std::fmt::Display for ParseU12Error {
     }
 }
 
-impl FromStr for U12 {
-    type Err = ParseU12Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let val = u16::from_str_radix(s, 10)?;
+impl U12 {
+    /// Converts a string slice in a given base to a `U12`.
+    /// The string is expected to be an optional `+` sign followed by digits. Digits are a
+    /// subset of these characters, depending on `radix`: `0-9`, `a-z` and `A-Z`.
+    ///
+    /// # Panics
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_str_radix("FFF", 16), Ok(u12![0xFFF]));
+    /// assert_eq!(U12::from_str_radix("7777", 8), Ok(u12![0o7777]));
+    /// assert!(U12::from_str_radix("1000", 16).is_err());
+    /// # }
+    /// ```
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseU12Error> {
+        let val = u16::from_str_radix(src, radix)?;
         if val > U12::max_value().0 {
             Err(ParseU12Error {
                 kind: IntErrorKind::PosOverflow,
@@ -1123,4 +3620,79 @@ impl FromStr for U12 {
             Ok(U12(val))
         }
     }
+
+    /// Converts `self` to a string in base `radix`, using lowercase letters for digits
+    /// above `9`. This is the inverse of `from_str_radix()`.
+    ///
+    /// # Panics
+    /// This method will panic if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0xABC].to_string_radix(16), "abc");
+    /// assert_eq!(u12![0o7777].to_string_radix(8), "7777");
+    /// assert_eq!(u12![35].to_string_radix(36), "z");
+    /// # }
+    /// ```
+    pub fn to_string_radix(self, radix: u32) -> String {
+        self.digits(radix)
+            .map(|digit| std::char::from_digit(digit as u32, radix).unwrap())
+            .collect()
+    }
+
+    /// Converts a string slice in any of the common bases to a `U12`.
+    /// The base is selected by an optional prefix: `0x` for hexadecimal, `0o` for octal,
+    /// `0b` for binary, and decimal otherwise. Prefixes are case-insensitive. Underscores
+    /// may be used to separate digits anywhere after the first character, and are ignored.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::from_str_any("4095"), Ok(u12![4095]));
+    /// assert_eq!(U12::from_str_any("0xABC"), Ok(u12![0xABC]));
+    /// assert_eq!(U12::from_str_any("0o7_777"), Ok(u12![0o7777]));
+    /// assert_eq!(U12::from_str_any("0b1010_0101"), Ok(u12![0b10100101]));
+    /// assert!(U12::from_str_any("0x1000").is_err());
+    /// # }
+    /// ```
+    pub fn from_str_any(src: &str) -> Result<Self, ParseU12Error> {
+        let (digits, radix) = match src.get(..2) {
+            Some("0x") | Some("0X") => (&src[2..], 16),
+            Some("0o") | Some("0O") => (&src[2..], 8),
+            Some("0b") | Some("0B") => (&src[2..], 2),
+            _ => (src, 10),
+        };
+
+        // Signs are not accepted, even behind separators, and separators may not lead an
+        // undecorated number.
+        if digits.contains('+') || (radix == 10 && digits.starts_with('_')) {
+            return Err(ParseU12Error {
+                kind: IntErrorKind::InvalidDigit,
+            });
+        }
+
+        if digits.contains('_') {
+            let digits: String = digits.chars().filter(|&c| c != '_').collect();
+            U12::from_str_radix(&digits, radix)
+        } else {
+            U12::from_str_radix(digits, radix)
+        }
+    }
+}
+
+impl FromStr for U12 {
+    type Err = ParseU12Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        U12::from_str_radix(s, 10)
+    }
 }
@@ -0,0 +1,19 @@
+//! Conversions to and from `arbitrary_int::u12`, enabled by the `arbitrary-int` feature.
+//!
+//! Both types represent exactly the range `0...4095`, so the conversions are lossless in
+//! both directions.
+
+use super::U12;
+extern crate arbitrary_int;
+
+impl From<arbitrary_int::u12> for U12 {
+    fn from(value: arbitrary_int::u12) -> Self {
+        U12(value.value())
+    }
+}
+
+impl From<U12> for arbitrary_int::u12 {
+    fn from(value: U12) -> Self {
+        arbitrary_int::u12::new(value.0)
+    }
+}
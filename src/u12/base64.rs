@@ -0,0 +1,200 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! A text encoding of `U12` values, with exactly two base64 characters per value.
+//!
+//! Each value is split into its high and low six bits, and each half is written as one
+//! character of the URL-safe base64 alphabet of RFC 4648 (`A-Z`, `a-z`, `0-9`, `-`, `_`).
+//! Since every value maps to a fixed-width pair of characters, no padding is needed and
+//! the encoded text can be split at any even offset. Note that this is not the same as
+//! base64-encoding packed bytes: the text for `n` values is always `2n` characters long.
+
+use std::error::Error;
+use std::fmt;
+use std::str::Bytes;
+
+use super::U12;
+
+/// The URL-safe base64 alphabet, indexed by six-bit value.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The error returned when decoding malformed text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The byte at the given offset is not in the alphabet.
+    InvalidCharacter(usize),
+    /// The text ends with half of a value.
+    TruncatedInput,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::InvalidCharacter(offset) => {
+                write!(f, "invalid character at offset {}", offset)
+            }
+            DecodeError::TruncatedInput => f.write_str("text ends with half of a value"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Encodes `value` as two characters, the high six bits first.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::base64::{decode, encode};
+/// # fn main() {
+/// assert_eq!(&encode(u12![0]), b"AA");
+/// assert_eq!(&encode(u12![0xFFF]), b"__");
+/// assert_eq!(decode(*b"Bf"), Some(u12![0x05F]));
+/// # }
+/// ```
+pub const fn encode(value: U12) -> [u8; 2] {
+    [
+        ALPHABET[(value.0 >> 6) as usize],
+        ALPHABET[(value.0 & 0x3F) as usize],
+    ]
+}
+
+/// Decodes two characters into a value, or returns `None` if either is not in the alphabet.
+pub const fn decode(chars: [u8; 2]) -> Option<U12> {
+    match (sextet(chars[0]), sextet(chars[1])) {
+        (Some(high), Some(low)) => Some(U12((high as u16) << 6 | low as u16)),
+        _ => None,
+    }
+}
+
+/// Encodes every value in `values` into a string of `2 * values.len()` characters.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::base64::{decode_str, encode_slice};
+/// # fn main() {
+/// let values = [u12![0x000], u12![0x123], u12![0xFFF]];
+/// let text = encode_slice(&values);
+/// assert_eq!(text, "AAEj__");
+/// assert_eq!(decode_str(&text), Ok(values.to_vec()));
+/// # }
+/// ```
+pub fn encode_slice(values: &[U12]) -> String {
+    encode_iter(values.iter().cloned()).collect()
+}
+
+/// Decodes a string produced by `encode_slice()` into its values.
+///
+/// # Errors
+/// Returns an error if `text` contains a character outside the alphabet, or has an odd
+/// length.
+pub fn decode_str(text: &str) -> Result<Vec<U12>, DecodeError> {
+    decode_iter(text).collect()
+}
+
+/// Returns an iterator over the characters encoding each value of `values`.
+pub fn encode_iter<I>(values: I) -> EncodeIter<I::IntoIter>
+where
+    I: IntoIterator<Item = U12>,
+{
+    EncodeIter {
+        values: values.into_iter(),
+        pending: None,
+    }
+}
+
+/// Returns an iterator over the values encoded in `text`, which stops after the first error.
+pub fn decode_iter(text: &str) -> DecodeIter<'_> {
+    DecodeIter {
+        bytes: text.bytes(),
+        offset: 0,
+        failed: false,
+    }
+}
+
+/// An iterator over the characters encoding a sequence of values.
+/// This is created by the `encode_iter()` function.
+#[derive(Debug, Clone)]
+pub struct EncodeIter<I> {
+    values: I,
+    pending: Option<char>,
+}
+
+impl<I: Iterator<Item = U12>> Iterator for EncodeIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(low) = self.pending.take() {
+            return Some(low);
+        }
+        let [high, low] = encode(self.values.next()?);
+        self.pending = Some(low as char);
+        Some(high as char)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (lower, upper) = self.values.size_hint();
+        (
+            lower.saturating_mul(2).saturating_add(pending),
+            upper.and_then(|upper| upper.checked_mul(2)?.checked_add(pending)),
+        )
+    }
+}
+
+/// An iterator over the values encoded in a string.
+/// This is created by the `decode_iter()` function.
+#[derive(Debug, Clone)]
+pub struct DecodeIter<'a> {
+    bytes: Bytes<'a>,
+    offset: usize,
+    failed: bool,
+}
+
+impl<'a> Iterator for DecodeIter<'a> {
+    type Item = Result<U12, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let high = self.bytes.next()?;
+        let result = match self.bytes.next() {
+            None => Err(DecodeError::TruncatedInput),
+            Some(low) => match (sextet(high), sextet(low)) {
+                (Some(high), Some(low)) => Ok(U12((high as u16) << 6 | low as u16)),
+                (None, _) => Err(DecodeError::InvalidCharacter(self.offset)),
+                (Some(_), None) => Err(DecodeError::InvalidCharacter(self.offset + 1)),
+            },
+        };
+        self.offset += 2;
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+/// Returns the six-bit value of the alphabet character `c`, or `None` if it is not in the
+/// alphabet.
+const fn sextet(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    }
+}
@@ -0,0 +1,114 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! The `u12_bitfield!` macro, for defining structured views of a `U12`.
+
+///
+/// Defines a struct over a `U12` with named fields at consecutive bit ranges. Fields are
+/// listed from the most significant bit down, each as `getter, setter: width`, and their
+/// widths must add up to exactly 12 bits. This is checked at compile time.
+///
+/// The generated struct derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Default`,
+/// converts to and from `U12`, and has `from_bits` and `to_bits` methods. Every field
+/// gets a getter returning its value as an `u16`, and a setter which panics if the value
+/// does not fit in the field.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+///
+/// u12_bitfield! {
+///     /// The operand word of a CHIP-8 `8XYN` arithmetic instruction.
+///     pub struct Operands {
+///         opcode, set_opcode: 4,
+///         x, set_x: 4,
+///         y, set_y: 4,
+///     }
+/// }
+///
+/// # fn main() {
+/// let mut operands = Operands::from_bits(u12![0x8AB]);
+/// assert_eq!((operands.opcode(), operands.x(), operands.y()), (0x8, 0xA, 0xB));
+/// operands.set_y(0x3);
+/// assert_eq!(operands.to_bits(), u12![0x8A3]);
+/// # }
+/// ```
+///
+#[macro_export]
+macro_rules! u12_bitfield {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_attr:meta])* $getter:ident, $setter:ident : $width:expr),+ $(,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        $vis struct $name($crate::u12::U12);
+
+        const _: () = assert!(0 $(+ ($width))+ == 12, "bitfield widths must add up to 12 bits");
+
+        impl $name {
+            /// Creates a value from its raw 12-bit representation.
+            pub const fn from_bits(bits: $crate::u12::U12) -> Self {
+                $name(bits)
+            }
+
+            /// Returns the raw 12-bit representation of `self`.
+            pub const fn to_bits(self) -> $crate::u12::U12 {
+                self.0
+            }
+        }
+
+        impl From<$crate::u12::U12> for $name {
+            fn from(bits: $crate::u12::U12) -> Self {
+                $name(bits)
+            }
+        }
+
+        impl From<$name> for $crate::u12::U12 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        $crate::u12_bitfield!(@fields $name, 12; $($(#[$field_attr])* $getter, $setter: $width,)+);
+    };
+
+    (@fields $name:ident, $end:expr;) => {};
+
+    (
+        @fields $name:ident, $end:expr;
+        $(#[$field_attr:meta])* $getter:ident, $setter:ident : $width:expr, $($rest:tt)*
+    ) => {
+        impl $name {
+            $(#[$field_attr])*
+            pub fn $getter(&self) -> u16 {
+                u16::from(self.0) >> (($end) - ($width)) & ((1u16 << ($width)) - 1)
+            }
+
+            /// Sets the field to `value`.
+            ///
+            /// # Panics
+            /// This method will panic if `value` does not fit in the width of the field.
+            pub fn $setter(&mut self, value: u16) {
+                assert!(value < 1u16 << ($width), "bitfield value out of range");
+                let shift = ($end) - ($width);
+                let mask = ((1u16 << ($width)) - 1) << shift;
+                let bits = u16::from(self.0) & !mask | value << shift;
+                self.0 = $crate::u12::U12::from_u16(bits);
+            }
+        }
+
+        $crate::u12_bitfield!(@fields $name, ($end) - ($width); $($rest)*);
+    };
+}
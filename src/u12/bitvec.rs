@@ -0,0 +1,90 @@
+//! Interoperability with `bitvec`, enabled by the `bitvec` feature.
+//!
+//! A `U12` occupies 12 consecutive bits of a `BitSlice`, most significant bit first. The
+//! order in which those bits map onto memory is chosen by the `BitOrder` parameter of the
+//! slice, so `Msb0` storage reproduces a big-endian bit stream and `Lsb0` storage matches
+//! the conventions of most little-endian hardware registers. The slice functions are
+//! re-exported from `packing`.
+
+use super::U12;
+extern crate bitvec;
+
+use self::bitvec::order::BitOrder;
+use self::bitvec::slice::BitSlice;
+use self::bitvec::store::BitStore;
+use self::bitvec::vec::BitVec;
+
+impl U12 {
+    /// Reads a value from `bits`, whose first bit is the most significant. Returns `None` if
+    /// `bits` is not exactly 12 bits long.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// extern crate bitvec;
+    /// use bitvec::prelude::*;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// let bytes = [0xABu8, 0xC0];
+    /// let bits = bytes.view_bits::<Msb0>();
+    /// assert_eq!(U12::from_bitslice(&bits[..12]), Some(u12![0xABC]));
+    /// assert_eq!(U12::from_bitslice(&bits[..11]), None);
+    /// # }
+    /// ```
+    pub fn from_bitslice<T: BitStore, O: BitOrder>(bits: &BitSlice<T, O>) -> Option<Self> {
+        if bits.len() != 12 {
+            return None;
+        }
+        Some(U12(bits
+            .iter()
+            .fold(0, |value, bit| value << 1 | *bit as u16)))
+    }
+
+    /// Writes `self` into `bits`, most significant bit first.
+    ///
+    /// # Panics
+    /// This method will panic if `bits` is not exactly 12 bits long.
+    pub fn store_bitslice<T: BitStore, O: BitOrder>(self, bits: &mut BitSlice<T, O>) {
+        assert_eq!(bits.len(), 12, "bit slice must hold exactly 12 bits");
+        for (index, mut bit) in bits.iter_mut().enumerate() {
+            *bit = self.0 >> (11 - index) & 1 == 1;
+        }
+    }
+}
+
+/// Returns `values` stored in a bit vector of `12 * values.len()` bits, each value most
+/// significant bit first.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// extern crate bitvec;
+/// use bitvec::prelude::*;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::packing::{values_from_bitslice, values_to_bitvec};
+/// # fn main() {
+/// let bits: BitVec<u8, Msb0> = values_to_bitvec(&[u12![0xABC], u12![0x123]]);
+/// assert_eq!(bits.as_raw_slice(), &[0xAB, 0xC1, 0x23]);
+/// assert_eq!(values_from_bitslice(&bits), Some(vec![u12![0xABC], u12![0x123]]));
+/// # }
+/// ```
+pub fn values_to_bitvec<T: BitStore, O: BitOrder>(values: &[U12]) -> BitVec<T, O> {
+    let mut bits = BitVec::repeat(false, values.len() * 12);
+    for (value, chunk) in values.iter().zip(bits.chunks_exact_mut(12)) {
+        value.store_bitslice(chunk);
+    }
+    bits
+}
+
+/// Returns the values stored in `bits` by `values_to_bitvec()`, or `None` if the length of
+/// `bits` is not a multiple of 12.
+pub fn values_from_bitslice<T: BitStore, O: BitOrder>(bits: &BitSlice<T, O>) -> Option<Vec<U12>> {
+    if !bits.len().is_multiple_of(12) {
+        return None;
+    }
+    bits.chunks_exact(12).map(U12::from_bitslice).collect()
+}
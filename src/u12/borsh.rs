@@ -0,0 +1,28 @@
+//! Borsh support for `U12`, enabled by the `borsh` feature.
+//!
+//! `U12` is encoded as a little-endian `u16`, like its underlying representation, so the
+//! encoding is deterministic. Deserialization rejects values above `0xFFF` with an
+//! `InvalidData` error.
+
+use super::U12;
+extern crate borsh;
+
+use self::borsh::io::{Error, ErrorKind, Read, Result, Write};
+use self::borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for U12 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for U12 {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let value = u16::deserialize_reader(reader)?;
+        if value > U12::max_value().0 {
+            Err(Error::new(ErrorKind::InvalidData, "U12 out of range"))
+        } else {
+            Ok(U12(value))
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Packed 12-bit colors.
+//!
+//! `Rgb444` stores one color in a single `U12`, with four bits per channel: red in bits
+//! `8...11`, green in bits `4...7` and blue in bits `0...3`. This is the pixel format of the
+//! 12-bit modes of many small TFT display controllers.
+
+use super::U12;
+
+/// An RGB color with four bits per channel, packed into a single `U12` as `0xRGB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgb444(U12);
+
+impl Rgb444 {
+    /// Creates a color from its 4-bit channels.
+    /// Returns `None` if any channel exceeds `15`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::color::Rgb444;
+    /// # fn main() {
+    /// let color = Rgb444::new(0xA, 0xB, 0xC).unwrap();
+    /// assert_eq!(color.to_bits(), u12![0xABC]);
+    /// assert_eq!(Rgb444::new(16, 0, 0), None);
+    /// # }
+    /// ```
+    pub const fn new(r: u8, g: u8, b: u8) -> Option<Self> {
+        if r > 0xF || g > 0xF || b > 0xF {
+            None
+        } else {
+            Some(Rgb444(U12((r as u16) << 8 | (g as u16) << 4 | b as u16)))
+        }
+    }
+
+    /// Creates a color from 8-bit channels, rounding each to the nearest 4-bit value.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use twelve_bit::u12::color::Rgb444;
+    ///
+    /// let color = Rgb444::from_rgb8(255, 128, 0);
+    /// assert_eq!((color.r(), color.g(), color.b()), (0xF, 0x8, 0x0));
+    /// assert_eq!(color.to_rgb8(), [255, 136, 0]);
+    /// ```
+    pub const fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Rgb444(U12((narrow_channel(r) as u16) << 8
+            | (narrow_channel(g) as u16) << 4
+            | narrow_channel(b) as u16))
+    }
+
+    /// Creates a color from its packed `0xRGB` representation.
+    pub const fn from_bits(bits: U12) -> Self {
+        Rgb444(bits)
+    }
+
+    /// Returns the packed `0xRGB` representation of `self`.
+    pub const fn to_bits(self) -> U12 {
+        self.0
+    }
+
+    /// Returns the 4-bit red channel.
+    pub const fn r(self) -> u8 {
+        (self.0 .0 >> 8) as u8
+    }
+
+    /// Returns the 4-bit green channel.
+    pub const fn g(self) -> u8 {
+        (self.0 .0 >> 4) as u8 & 0xF
+    }
+
+    /// Returns the 4-bit blue channel.
+    pub const fn b(self) -> u8 {
+        self.0 .0 as u8 & 0xF
+    }
+
+    /// Returns the color as 8-bit channels, in `[r, g, b]` order. Each channel is scaled to
+    /// the full 8-bit range, such that `0x0` maps to `0` and `0xF` maps to `255`.
+    pub const fn to_rgb8(self) -> [u8; 3] {
+        [self.r() * 17, self.g() * 17, self.b() * 17]
+    }
+}
+
+impl From<U12> for Rgb444 {
+    fn from(bits: U12) -> Self {
+        Rgb444(bits)
+    }
+}
+
+impl From<Rgb444> for U12 {
+    fn from(color: Rgb444) -> Self {
+        color.0
+    }
+}
+
+/// Rounds an 8-bit channel to the nearest 4-bit value.
+const fn narrow_channel(value: u8) -> u8 {
+    ((value as u16 * 15 + 127) / 255) as u8
+}
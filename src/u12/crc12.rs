@@ -0,0 +1,159 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! 12-bit cyclic redundancy checks.
+//!
+//! Each supported CRC-12 variant is described by an `Algorithm`, named after its entry in
+//! the catalogue of parametrised CRC algorithms. A checksum can be computed in one call
+//! with `Algorithm::checksum`, or incrementally through a `Digest`. Input is processed most
+//! significant bit first, either as bytes or as whole 12-bit values.
+
+use super::U12;
+
+/// The parameters of a CRC-12 algorithm. Input bits are never reflected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Algorithm {
+    /// The generator polynomial, without its implicit `x^12` term.
+    pub poly: U12,
+    /// The initial value of the register.
+    pub init: U12,
+    /// Whether the register is bit-reversed before the final xor.
+    pub refout: bool,
+    /// The value xored into the register to produce the checksum.
+    pub xorout: U12,
+}
+
+/// CRC-12/UMTS, also known as CRC-12/3GPP. Its checksum is the bit-reversed register.
+pub const CRC_12_UMTS: Algorithm = Algorithm {
+    poly: U12(0x80F),
+    init: U12(0x000),
+    refout: true,
+    xorout: U12(0x000),
+};
+
+/// CRC-12/DECT, also known as X-CRC-12.
+pub const CRC_12_DECT: Algorithm = Algorithm {
+    poly: U12(0x80F),
+    init: U12(0x000),
+    refout: false,
+    xorout: U12(0x000),
+};
+
+/// CRC-12/CDMA2000.
+pub const CRC_12_CDMA2000: Algorithm = Algorithm {
+    poly: U12(0xF13),
+    init: U12(0xFFF),
+    refout: false,
+    xorout: U12(0x000),
+};
+
+impl Algorithm {
+    /// Computes the checksum of `bytes`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::crc12::*;
+    /// # fn main() {
+    /// assert_eq!(CRC_12_UMTS.checksum(b"123456789"), u12![0xDAF]);
+    /// assert_eq!(CRC_12_DECT.checksum(b"123456789"), u12![0xF5B]);
+    /// assert_eq!(CRC_12_CDMA2000.checksum(b"123456789"), u12![0xD4D]);
+    /// # }
+    /// ```
+    pub fn checksum(&self, bytes: &[u8]) -> U12 {
+        let mut digest = self.digest();
+        digest.update(bytes);
+        digest.finalize()
+    }
+
+    /// Computes the checksum of a sequence of 12-bit values, each processed as twelve bits
+    /// most significant bit first.
+    pub fn checksum_values<I>(&self, values: I) -> U12
+    where
+        I: IntoIterator<Item = U12>,
+    {
+        let mut digest = self.digest();
+        digest.update_values(values);
+        digest.finalize()
+    }
+
+    /// Returns a new `Digest` for computing a checksum incrementally.
+    pub const fn digest(&self) -> Digest {
+        Digest {
+            algorithm: *self,
+            register: self.init.0,
+        }
+    }
+}
+
+/// An in-progress CRC-12 computation. This is created by the `Algorithm::digest()` method.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::crc12::*;
+/// # fn main() {
+/// let mut digest = CRC_12_DECT.digest();
+/// digest.update(b"1234");
+/// digest.update(b"56789");
+/// assert_eq!(digest.finalize(), CRC_12_DECT.checksum(b"123456789"));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Digest {
+    algorithm: Algorithm,
+    register: u16,
+}
+
+impl Digest {
+    /// Feeds `bytes` into the computation.
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.update_bits(byte as u16, 8);
+        }
+    }
+
+    /// Feeds a sequence of 12-bit values into the computation, each processed as twelve
+    /// bits most significant bit first.
+    pub fn update_values<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = U12>,
+    {
+        for value in values {
+            self.update_bits(value.0, 12);
+        }
+    }
+
+    /// Returns the checksum of all input fed so far, consuming the digest.
+    pub fn finalize(self) -> U12 {
+        let register = if self.algorithm.refout {
+            self.register.reverse_bits() >> 4
+        } else {
+            self.register
+        };
+        U12(register ^ self.algorithm.xorout.0)
+    }
+
+    /// Shifts the low `count` bits of `bits` into the register, most significant first.
+    fn update_bits(&mut self, bits: u16, count: u32) {
+        for index in (0..count).rev() {
+            let feedback = (self.register >> 11 ^ bits >> index) & 1;
+            self.register = (self.register << 1) & 0xFFF;
+            if feedback != 0 {
+                self.register ^= self.algorithm.poly.0;
+            }
+        }
+    }
+}
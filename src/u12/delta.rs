@@ -0,0 +1,230 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Lossless compression of `U12` streams by delta encoding and bit packing.
+//!
+//! Values are split into blocks of up to `BLOCK_LEN` values. Each block starts with a
+//! four-byte header holding the number of values, the bit width of its residuals, and the
+//! first value as a big-endian `u16`. Every later value is stored as the wrapping difference
+//! from its predecessor, zigzag-encoded so that small negative steps stay small, and packed
+//! most significant bit first at the block's width. The last byte of a block is padded with
+//! zero bits.
+//!
+//! ```text
+//! byte 0: number of values (1...64)
+//! byte 1: residual width in bits (0...12)
+//! byte 2: first[11:8]
+//! byte 3: first[7:0]
+//! byte 4...: residuals
+//! ```
+//!
+//! Slowly varying signals such as ADC traces need only a few bits per residual, so they
+//! compress to a fraction of the 1.5 bytes per value of `packing`, and a constant signal
+//! costs nothing beyond the block headers. The whole compressed stream must be held in
+//! memory, but `Decompressor` decodes it lazily, one value at a time, without allocating.
+
+use std::error::Error;
+use std::fmt;
+
+use super::U12;
+
+/// The largest number of values stored in one block.
+pub const BLOCK_LEN: usize = 64;
+
+/// The number of bytes in a block header.
+const HEADER_LEN: usize = 4;
+
+/// The reasons a compressed stream can fail to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecompressError {
+    /// The block header at the given offset has an invalid count, width or first value.
+    InvalidHeader(usize),
+    /// The stream ends partway through a block.
+    TruncatedInput,
+}
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecompressError::InvalidHeader(offset) => {
+                write!(f, "invalid block header at offset {}", offset)
+            }
+            DecompressError::TruncatedInput => f.write_str("stream ends partway through a block"),
+        }
+    }
+}
+
+impl Error for DecompressError {}
+
+/// Compresses `values` into a sequence of delta-encoded blocks.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::delta::{compress, decompress};
+/// # fn main() {
+/// let ramp: Vec<U12> = (0..64).map(|i| U12::from_u16(1000 + i)).collect();
+/// let compressed = compress(&ramp);
+/// assert_eq!(compressed.len(), 20);
+/// assert_eq!(decompress(&compressed), Ok(ramp));
+/// # }
+/// ```
+pub fn compress(values: &[U12]) -> Vec<u8> {
+    let mut compressed = Vec::new();
+    for block in values.chunks(BLOCK_LEN) {
+        let mut residuals = [0u16; BLOCK_LEN];
+        for (residual, pair) in residuals.iter_mut().zip(block.windows(2)) {
+            *residual = zigzag(pair[1].0.wrapping_sub(pair[0].0) & 0xFFF);
+        }
+        let residuals = &residuals[..block.len() - 1];
+        let width = residuals
+            .iter()
+            .map(|&residual| 16 - residual.leading_zeros())
+            .max()
+            .unwrap_or(0);
+
+        compressed.push(block.len() as u8);
+        compressed.push(width as u8);
+        compressed.extend_from_slice(&block[0].0.to_be_bytes());
+
+        let mut accumulator = 0u32;
+        let mut bits = 0;
+        for &residual in residuals {
+            accumulator = accumulator << width | residual as u32;
+            bits += width;
+            while bits >= 8 {
+                bits -= 8;
+                compressed.push((accumulator >> bits) as u8);
+            }
+            accumulator &= (1 << bits) - 1;
+        }
+        if bits > 0 {
+            compressed.push((accumulator << (8 - bits)) as u8);
+        }
+    }
+    compressed
+}
+
+/// Decompresses a stream produced by `compress()`.
+pub fn decompress(bytes: &[u8]) -> Result<Vec<U12>, DecompressError> {
+    decompress_iter(bytes).collect()
+}
+
+/// Returns an iterator that lazily decodes the values of a compressed stream held in
+/// `bytes`, one at a time. The iterator stops after the first error.
+pub fn decompress_iter(bytes: &[u8]) -> Decompressor<'_> {
+    Decompressor {
+        bytes,
+        offset: 0,
+        remaining: 0,
+        width: 0,
+        accumulator: 0,
+        bits: 0,
+        previous: 0,
+        failed: false,
+    }
+}
+
+/// An iterator over the values of a compressed stream.
+/// This is created by the `decompress_iter()` function.
+#[derive(Debug, Clone)]
+pub struct Decompressor<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    remaining: usize,
+    width: u32,
+    accumulator: u32,
+    bits: u32,
+    previous: u16,
+    failed: bool,
+}
+
+impl<'a> Decompressor<'a> {
+    /// Reads the header of the block at `offset` and returns its first value, after
+    /// checking that the whole block is present.
+    fn start_block(&mut self) -> Result<U12, DecompressError> {
+        let header = self
+            .bytes
+            .get(self.offset..self.offset + HEADER_LEN)
+            .ok_or(DecompressError::TruncatedInput)?;
+        let count = header[0] as usize;
+        let width = header[1] as u32;
+        let first = u16::from_be_bytes([header[2], header[3]]);
+        if count == 0 || count > BLOCK_LEN || width > 12 || first > 0xFFF {
+            return Err(DecompressError::InvalidHeader(self.offset));
+        }
+        let residual_len = ((count - 1) * width as usize).div_ceil(8);
+        if self.bytes.len() - self.offset - HEADER_LEN < residual_len {
+            return Err(DecompressError::TruncatedInput);
+        }
+        self.offset += HEADER_LEN;
+        self.remaining = count - 1;
+        self.width = width;
+        self.accumulator = 0;
+        self.bits = 0;
+        self.previous = first;
+        Ok(U12(first))
+    }
+
+    /// Reads the next residual of the current block and applies it to the previous value.
+    fn next_in_block(&mut self) -> U12 {
+        while self.bits < self.width {
+            self.accumulator = self.accumulator << 8 | self.bytes[self.offset] as u32;
+            self.offset += 1;
+            self.bits += 8;
+        }
+        self.bits -= self.width;
+        let residual = (self.accumulator >> self.bits) as u16 & ((1 << self.width) - 1);
+        self.accumulator &= (1 << self.bits) - 1;
+        self.remaining -= 1;
+        self.previous = self.previous.wrapping_add(unzigzag(residual)) & 0xFFF;
+        U12(self.previous)
+    }
+}
+
+impl<'a> Iterator for Decompressor<'a> {
+    type Item = Result<U12, DecompressError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        if self.remaining > 0 {
+            return Some(Ok(self.next_in_block()));
+        }
+        if self.offset == self.bytes.len() {
+            return None;
+        }
+        let result = self.start_block();
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+/// Maps a wrapping 12-bit difference onto `0...0xFFF` so that differences close to zero in
+/// either direction map to small values: `0, -1, 1, -2, 2, ...` become `0, 1, 2, 3, 4, ...`.
+const fn zigzag(difference: u16) -> u16 {
+    if difference < 0x800 {
+        difference << 1
+    } else {
+        ((0x1000 - difference) << 1) - 1
+    }
+}
+
+/// Inverts `zigzag()`.
+const fn unzigzag(residual: u16) -> u16 {
+    if residual & 1 == 0 {
+        residual >> 1
+    } else {
+        (0x1000 - ((residual + 1) >> 1)) & 0xFFF
+    }
+}
@@ -0,0 +1,104 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Dithered conversion of 12-bit pixel buffers to 8 bits.
+//!
+//! Truncating 12-bit samples to 8 bits discards the low four bits of every pixel, which turns
+//! smooth gradients into visible bands. The routines here instead spread the quantization
+//! error spatially, so that the average brightness of any region is preserved. Buffers hold
+//! rows of `width` pixels back to back, and full scale maps to full scale.
+
+use super::U12;
+
+#[cfg(feature = "rayon")]
+pub use super::rayon::par_ordered;
+
+/// The 4×4 Bayer threshold matrix, with entries from 0 to 15.
+const BAYER_4X4: [[u32; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Converts the rows in `src` to 8 bits in `dst` using ordered dithering with a 4×4 Bayer
+/// matrix. Each pixel is converted independently of its neighbors, so rows can be processed
+/// in any order and the output is stable under small changes to the input.
+///
+/// # Panics
+/// This function will panic if `width` is zero, if `src` and `dst` have different lengths,
+/// or if their length is not a multiple of `width`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::dither::ordered;
+/// # fn main() {
+/// // 2064 lies about halfway between the 8-bit levels 128 and 129.
+/// let src = [u12![2064]; 16];
+/// let mut dst = [0; 16];
+/// ordered(&src, &mut dst, 4);
+/// assert_eq!(dst.iter().filter(|&&level| level == 129).count(), 8);
+/// # }
+/// ```
+pub fn ordered(src: &[U12], dst: &mut [u8], width: usize) {
+    check_buffers(src, dst, width);
+    for (y, (src_row, dst_row)) in src.chunks(width).zip(dst.chunks_mut(width)).enumerate() {
+        ordered_row(src_row, dst_row, y);
+    }
+}
+
+/// Converts the single row `src`, at row index `y`, using ordered dithering.
+pub(super) fn ordered_row(src: &[U12], dst: &mut [u8], y: usize) {
+    for (x, (pixel, level)) in src.iter().zip(dst.iter_mut()).enumerate() {
+        let threshold = (BAYER_4X4[y % 4][x % 4] * 2 + 1) * 4095 / 32;
+        *level = ((pixel.0 as u32 * 255 + threshold) / 4095) as u8;
+    }
+}
+
+/// Converts the rows in `src` to 8 bits in `dst` using Floyd–Steinberg error diffusion.
+/// The quantization error of each pixel is carried to its unprocessed neighbors, which gives
+/// a finer-grained result than ordered dithering, at the cost of processing rows in order.
+///
+/// # Panics
+/// This function will panic if `width` is zero, if `src` and `dst` have different lengths,
+/// or if their length is not a multiple of `width`.
+pub fn floyd_steinberg(src: &[U12], dst: &mut [u8], width: usize) {
+    check_buffers(src, dst, width);
+
+    // Errors are kept in units of 1/4095 of an output level, with one slot of padding on
+    // either side so that the edge pixels need no special cases.
+    let mut current = vec![0i32; width + 2];
+    let mut next = vec![0i32; width + 2];
+    for (src_row, dst_row) in src.chunks(width).zip(dst.chunks_mut(width)) {
+        for (x, (pixel, level)) in src_row.iter().zip(dst_row.iter_mut()).enumerate() {
+            let target = pixel.0 as i32 * 255 + current[x + 1];
+            let quantized = ((target + 2047).div_euclid(4095)).clamp(0, 255);
+            *level = quantized as u8;
+
+            let error = target - quantized * 4095;
+            let (right, below_left, below) = (error * 7 / 16, error * 3 / 16, error * 5 / 16);
+            current[x + 2] += right;
+            next[x] += below_left;
+            next[x + 1] += below;
+            next[x + 2] += error - right - below_left - below;
+        }
+        std::mem::swap(&mut current, &mut next);
+        for error in next.iter_mut() {
+            *error = 0;
+        }
+    }
+}
+
+/// Asserts that `src` and `dst` hold the same whole number of rows of `width` pixels.
+pub(super) fn check_buffers(src: &[U12], dst: &[u8], width: usize) {
+    assert_eq!(src.len(), dst.len(), "buffer lengths differ");
+    assert!(
+        width != 0 && src.len().is_multiple_of(width),
+        "buffer is not a whole number of rows"
+    );
+}
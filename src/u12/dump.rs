@@ -0,0 +1,118 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Hex dumps of `U12` buffers, one three-digit column per value.
+//!
+//! A generic hex viewer shows packed 12-bit data split across byte boundaries, which makes
+//! it nearly unreadable. These routines instead show each value as three hex digits, sixteen
+//! values to a line, with the index of the first value of each line as an offset:
+//!
+//! ```text
+//! 00000000: 000 001 002 003 004 005 006 007  008 009 00a 00b 00c 00d 00e 00f
+//! 00000010: 010 011
+//! ```
+//!
+//! Packed buffers are expected in the layout of `twelve_bit::u12::packing`.
+
+use std::fmt::{self, Write};
+
+use super::packing::unpack_slice;
+use super::U12;
+
+/// The number of values shown on each line.
+const VALUES_PER_LINE: usize = 16;
+
+/// Writes a dump of `values` to `out`.
+///
+/// # Errors
+/// Returns any error returned by `out`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::dump::write_values;
+/// # fn main() {
+/// let mut text = String::new();
+/// write_values(&mut text, &[u12![0xABC], u12![0x123]]).unwrap();
+/// assert_eq!(text, "00000000: abc 123\n");
+/// # }
+/// ```
+pub fn write_values<W: Write + ?Sized>(out: &mut W, values: &[U12]) -> fmt::Result {
+    for (line, chunk) in values.chunks(VALUES_PER_LINE).enumerate() {
+        write_line(out, line * VALUES_PER_LINE, chunk)?;
+    }
+    Ok(())
+}
+
+/// Writes a dump of the values packed in `packed` to `out`.
+///
+/// # Errors
+/// Returns any error returned by `out`.
+///
+/// # Panics
+/// This function will panic if `packed` has a length of the form `3n + 1`, which no number
+/// of packed values occupies.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// use twelve_bit::u12::dump::write_packed;
+///
+/// let mut text = String::new();
+/// write_packed(&mut text, &[0xAB, 0x12, 0x3C, 0xFE, 0x0D]).unwrap();
+/// assert_eq!(text, "00000000: abc 123 fed\n");
+/// ```
+pub fn write_packed<W: Write + ?Sized>(out: &mut W, packed: &[u8]) -> fmt::Result {
+    assert!(packed.len() % 3 != 1, "invalid packed length");
+
+    // Each line holds a whole number of packed pairs, so lines can be unpacked separately.
+    let mut values = [U12(0); VALUES_PER_LINE];
+    for (line, chunk) in packed.chunks(VALUES_PER_LINE / 2 * 3).enumerate() {
+        let count = chunk.len() / 3 * 2 + chunk.len() % 3 / 2;
+        unpack_slice(chunk, &mut values[..count]);
+        write_line(out, line * VALUES_PER_LINE, &values[..count])?;
+    }
+    Ok(())
+}
+
+/// Returns a dump of `values`.
+pub fn dump_values(values: &[U12]) -> String {
+    let mut text = String::new();
+    write_values(&mut text, values).unwrap();
+    text
+}
+
+/// Returns a dump of the values packed in `packed`.
+///
+/// # Panics
+/// This function will panic if `packed` has a length of the form `3n + 1`, which no number
+/// of packed values occupies.
+pub fn dump_packed(packed: &[u8]) -> String {
+    let mut text = String::new();
+    write_packed(&mut text, packed).unwrap();
+    text
+}
+
+/// Writes one line of at most `VALUES_PER_LINE` values, starting at `offset`.
+fn write_line<W: Write + ?Sized>(out: &mut W, offset: usize, values: &[U12]) -> fmt::Result {
+    write!(out, "{:08x}:", offset)?;
+    for (index, value) in values.iter().enumerate() {
+        let separator = if index == VALUES_PER_LINE / 2 {
+            "  "
+        } else {
+            " "
+        };
+        write!(out, "{}{:03x}", separator, value.0)?;
+    }
+    out.write_char('\n')
+}
@@ -0,0 +1,152 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Hamming(12,8) single-error correction (SEC) of one byte in a 12-bit codeword.
+//!
+//! Bit `i` of the codeword holds Hamming position `i + 1`. The four parity bits sit at the
+//! power-of-two positions 1, 2, 4 and 8, and the data bits, least significant first, fill
+//! positions 3, 5, 6, 7, 9, 10, 11 and 12. Each parity bit makes the parity of the positions
+//! whose index contains it even.
+//!
+//! ```text
+//! position: 12 11 10  9  8  7  6  5  4  3  2  1
+//! contents: d7 d6 d5 d4 p8 d3 d2 d1 p4 d0 p2 p1
+//! ```
+//!
+//! Every single-bit error is corrected. This is not a SECDED code: the overall parity bit of
+//! an extended code would need a 13th bit, so double-bit errors are detected only when their
+//! syndrome points outside the codeword, at positions 13 to 15; otherwise they are
+//! miscorrected like a single-bit error.
+
+use std::error::Error;
+use std::fmt;
+
+use super::U12;
+
+/// The Hamming positions holding the data bits, least significant first.
+const DATA_POSITIONS: [u16; 8] = [3, 5, 6, 7, 9, 10, 11, 12];
+
+/// The error returned when a codeword contains an error that cannot be corrected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EccError {
+    syndrome: u16,
+}
+
+impl EccError {
+    /// Returns the syndrome of the rejected codeword, which is always in the range 13 to 15.
+    pub fn syndrome(&self) -> u16 {
+        self.syndrome
+    }
+}
+
+impl fmt::Display for EccError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "uncorrectable error (syndrome {})", self.syndrome)
+    }
+}
+
+impl Error for EccError {}
+
+/// Encodes `data` into a 12-bit codeword.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::ecc::{decode, encode};
+/// # fn main() {
+/// let codeword = encode(0xA5);
+/// assert_eq!(decode(codeword), Ok(0xA5));
+///
+/// // Any single flipped bit is corrected.
+/// assert_eq!(decode(codeword ^ u12![0x040]), Ok(0xA5));
+/// # }
+/// ```
+pub const fn encode(data: u8) -> U12 {
+    let mut codeword = 0;
+    let mut index = 0;
+    while index < 8 {
+        if data >> index & 1 == 1 {
+            codeword |= 1 << (DATA_POSITIONS[index] - 1);
+        }
+        index += 1;
+    }
+
+    // Setting each parity bit to the syndrome bit it covers brings the syndrome to zero.
+    let syndrome = syndrome(codeword);
+    let mut parity = 1;
+    while parity <= 8 {
+        if syndrome & parity != 0 {
+            codeword |= 1 << (parity - 1);
+        }
+        parity <<= 1;
+    }
+    U12(codeword)
+}
+
+/// Decodes a 12-bit codeword, correcting a single-bit error if there is one.
+///
+/// Only single-bit errors are handled reliably. A double-bit error whose syndrome points to
+/// a bit of the codeword is indistinguishable from a single-bit error there, so it is
+/// miscorrected and decodes to the wrong byte without an error.
+///
+/// # Errors
+/// Returns an error if the syndrome of `codeword` does not point to one of its bits, which
+/// indicates an error in more than one bit.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::ecc::{decode, encode};
+/// # fn main() {
+/// let codeword = encode(0xA5);
+///
+/// // Flipping positions 1 and 2 gives syndrome 3, so data bit 0 is wrongly flipped.
+/// assert_eq!(decode(codeword ^ u12![0b0000_0000_0011]), Ok(0xA4));
+///
+/// // Flipping positions 5 and 8 gives syndrome 13, which is detected.
+/// assert!(decode(codeword ^ u12![0b0000_1001_0000]).is_err());
+/// # }
+/// ```
+pub const fn decode(codeword: U12) -> Result<u8, EccError> {
+    let mut codeword = codeword.0;
+    let syndrome = syndrome(codeword);
+    if syndrome > 12 {
+        return Err(EccError { syndrome });
+    }
+    if syndrome != 0 {
+        codeword ^= 1 << (syndrome - 1);
+    }
+
+    let mut data = 0;
+    let mut index = 0;
+    while index < 8 {
+        data |= ((codeword >> (DATA_POSITIONS[index] - 1) & 1) as u8) << index;
+        index += 1;
+    }
+    Ok(data)
+}
+
+/// Returns the exclusive or of the Hamming positions of every set bit of `codeword`.
+const fn syndrome(codeword: u16) -> u16 {
+    let mut syndrome = 0;
+    let mut position = 1;
+    while position <= 12 {
+        if codeword >> (position - 1) & 1 == 1 {
+            syndrome ^= position;
+        }
+        position += 1;
+    }
+    syndrome
+}
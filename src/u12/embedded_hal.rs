@@ -0,0 +1,79 @@
+//! Driving PWM outputs with `U12` duty values, enabled by the `embedded-hal` feature.
+//!
+//! PWM peripherals count up to a hardware-specific `max_duty_cycle()`, which is rarely 4095.
+//! `SetDutyCycleU12` is implemented for every `SetDutyCycle` implementor and rescales a
+//! 12-bit duty value onto that range, rounding to nearest, so that `U12::min_value()` is
+//! always fully off and `U12::max_value()` is always fully on.
+
+use super::U12;
+extern crate embedded_hal;
+
+use self::embedded_hal::pwm::SetDutyCycle;
+
+impl U12 {
+    /// Returns the nearest duty cycle to `self` of full scale, for a PWM peripheral whose
+    /// duty cycle ranges from `0` to `max_duty`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(U12::max_value().to_duty_cycle(999), 999);
+    /// assert_eq!(u12![2048].to_duty_cycle(999), 500);
+    /// assert_eq!(U12::min_value().to_duty_cycle(999), 0);
+    /// # }
+    /// ```
+    pub const fn to_duty_cycle(self, max_duty: u16) -> u16 {
+        let full_scale = U12::max_value().0 as u32;
+        ((self.0 as u32 * max_duty as u32 + full_scale / 2) / full_scale) as u16
+    }
+}
+
+/// An extension of `SetDutyCycle` for setting the duty cycle from a `U12`.
+pub trait SetDutyCycleU12: SetDutyCycle {
+    /// Sets the duty cycle to `duty` of full scale, rescaled to `max_duty_cycle()`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// extern crate embedded_hal;
+    /// use embedded_hal::pwm::{ErrorType, SetDutyCycle};
+    /// use std::convert::Infallible;
+    /// use twelve_bit::u12::*;
+    ///
+    /// struct Timer {
+    ///     compare: u16,
+    /// }
+    ///
+    /// impl ErrorType for Timer {
+    ///     type Error = Infallible;
+    /// }
+    ///
+    /// impl SetDutyCycle for Timer {
+    ///     fn max_duty_cycle(&self) -> u16 {
+    ///         255
+    ///     }
+    ///     fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Infallible> {
+    ///         self.compare = duty;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// let mut timer = Timer { compare: 0 };
+    /// timer.set_duty_cycle_u12(u12![2048]).unwrap();
+    /// assert_eq!(timer.compare, 128);
+    /// # }
+    /// ```
+    fn set_duty_cycle_u12(&mut self, duty: U12) -> Result<(), Self::Error> {
+        let max_duty = self.max_duty_cycle();
+        self.set_duty_cycle(duty.to_duty_cycle(max_duty))
+    }
+}
+
+impl<T: SetDutyCycle + ?Sized> SetDutyCycleU12 for T {}
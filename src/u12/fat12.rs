@@ -0,0 +1,189 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Access to the 12-bit entries of a FAT12 file allocation table.
+//!
+//! Entries are packed little-endian, two to every three bytes. Entry `n` starts at byte
+//! `n * 3 / 2`: an even entry takes the whole first byte and the low nibble of the second,
+//! and an odd entry takes the high nibble of the first byte and the whole second byte.
+//!
+//! ```text
+//! byte 0: a[7:0]
+//! byte 1: b[3:0] a[11:8]
+//! byte 2: b[11:4]
+//! ```
+//!
+//! Note that this differs from the layout of `packing`, which stores the high bits of each
+//! value first.
+//!
+//! Each entry of a data cluster holds the number of the next cluster of its file, an
+//! end-of-chain marker, or `FREE` if the cluster is unused. `chain()` follows these links
+//! from the first cluster of a file.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use super::U12;
+
+/// The entry of an unused cluster.
+pub const FREE: U12 = U12(0x000);
+
+/// The entry of a cluster marked as unusable.
+pub const BAD_CLUSTER: U12 = U12(0xFF7);
+
+/// The smallest end-of-chain marker. Every entry from `0xFF8` to `0xFFF` ends a chain.
+pub const END_OF_CHAIN: U12 = U12(0xFF8);
+
+/// The number of the first data cluster. Entries 0 and 1 are reserved.
+pub const FIRST_CLUSTER: U12 = U12(0x002);
+
+/// Returns the number of whole entries in a table of `len` bytes.
+pub const fn entry_count(len: usize) -> usize {
+    len / 3 * 2 + len % 3 / 2
+}
+
+/// Returns entry `index` of `table`, or `None` if `table` does not contain it.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::fat12::get_entry;
+/// # fn main() {
+/// let table = [0xF0, 0xFF, 0xFF, 0x03, 0x40, 0x00];
+/// assert_eq!(get_entry(&table, 0), Some(u12![0xFF0]));
+/// assert_eq!(get_entry(&table, 2), Some(u12![0x003]));
+/// assert_eq!(get_entry(&table, 3), Some(u12![0x004]));
+/// assert_eq!(get_entry(&table, 4), None);
+/// # }
+/// ```
+pub fn get_entry(table: &[u8], index: usize) -> Option<U12> {
+    let bytes = table.get(entry_range(index)?)?;
+    let word = u16::from_le_bytes([bytes[0], bytes[1]]);
+    if index.is_multiple_of(2) {
+        Some(U12(word & 0xFFF))
+    } else {
+        Some(U12(word >> 4))
+    }
+}
+
+/// Sets entry `index` of `table` to `value`, leaving the neighboring entries untouched.
+///
+/// # Panics
+/// This function will panic if `table` does not contain entry `index`.
+pub fn set_entry(table: &mut [u8], index: usize, value: U12) {
+    let bytes = match entry_range(index).and_then(|range| table.get_mut(range)) {
+        Some(bytes) => bytes,
+        None => panic!("index out of bounds"),
+    };
+    if index.is_multiple_of(2) {
+        bytes[0] = value.0 as u8;
+        bytes[1] = bytes[1] & 0xF0 | (value.0 >> 8) as u8;
+    } else {
+        bytes[0] = bytes[0] & 0x0F | (value.0 << 4) as u8;
+        bytes[1] = (value.0 >> 4) as u8;
+    }
+}
+
+/// Returns the range of the two bytes holding entry `index`, or `None` if it overflows.
+fn entry_range(index: usize) -> Option<Range<usize>> {
+    let offset = index.checked_mul(3).map(|n| n / 2)?;
+    Some(offset..offset.checked_add(2)?)
+}
+
+/// Returns an iterator over the clusters of the chain starting at cluster `start`, in
+/// order. A start of `FREE` denotes an empty file, and yields no clusters.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::fat12::{chain, set_entry, END_OF_CHAIN};
+/// # fn main() {
+/// let mut table = [0; 12];
+/// set_entry(&mut table, 2, u12![5]);
+/// set_entry(&mut table, 5, u12![3]);
+/// set_entry(&mut table, 3, END_OF_CHAIN);
+/// let clusters: Result<Vec<U12>, _> = chain(&table, u12![2]).collect();
+/// assert_eq!(clusters, Ok(vec![u12![2], u12![5], u12![3]]));
+/// # }
+/// ```
+pub fn chain(table: &[u8], start: U12) -> Chain<'_> {
+    Chain {
+        table,
+        next: if start == FREE { END_OF_CHAIN } else { start },
+        remaining: entry_count(table.len()),
+        failed: false,
+    }
+}
+
+/// The reasons a cluster chain can be malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainError {
+    /// The chain links to the given cluster, which is free, reserved, bad, or beyond the end
+    /// of the table.
+    InvalidCluster(U12),
+    /// The chain is longer than the table, so it must contain a cycle.
+    Cycle,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChainError::InvalidCluster(cluster) => {
+                write!(f, "chain links to invalid cluster {:#05x}", cluster.0)
+            }
+            ChainError::Cycle => f.write_str("chain contains a cycle"),
+        }
+    }
+}
+
+impl Error for ChainError {}
+
+/// An iterator over the clusters of a chain.
+/// This is created by the `chain()` function.
+#[derive(Debug, Clone)]
+pub struct Chain<'a> {
+    table: &'a [u8],
+    next: U12,
+    remaining: usize,
+    failed: bool,
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = Result<U12, ChainError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.next >= END_OF_CHAIN {
+            return None;
+        }
+        let cluster = self.next;
+        let result = if self.remaining == 0 {
+            Err(ChainError::Cycle)
+        } else if cluster < FIRST_CLUSTER || cluster == BAD_CLUSTER {
+            Err(ChainError::InvalidCluster(cluster))
+        } else {
+            match get_entry(self.table, cluster.0 as usize) {
+                Some(entry) => {
+                    self.next = entry;
+                    self.remaining -= 1;
+                    Ok(cluster)
+                }
+                None => Err(ChainError::InvalidCluster(cluster)),
+            }
+        };
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
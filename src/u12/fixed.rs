@@ -0,0 +1,211 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Unsigned fixed-point numbers stored in 12 bits.
+//!
+//! `UQ0_12` has no integer bits and twelve fractional bits, representing values in
+//! `0...4095/4096` with a resolution of `1/4096`. `UQ4_8` has four integer bits and eight
+//! fractional bits, representing values in `0...4095/256` with a resolution of `1/256`.
+
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use super::U12;
+
+///
+/// Defines an unsigned fixed-point type `$name` over `U12` with `$frac_bits` fractional
+/// bits, along with its arithmetic, conversions and formatting.
+///
+macro_rules! define_fixed_point_type {
+    ($(#[$attr:meta])* $name:ident, $frac_bits:expr, $example_bits:expr, $example_value:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+        pub struct $name(U12);
+
+        impl $name {
+            /// The number of fractional bits.
+            pub const FRAC_BITS: u32 = $frac_bits;
+
+            /// The smallest representable value, zero.
+            pub const MIN: $name = $name(U12(0));
+
+            /// The largest representable value.
+            pub const MAX: $name = $name(U12(0xFFF));
+
+            /// The difference between two adjacent representable values.
+            pub const EPSILON: $name = $name(U12(1));
+
+            /// Creates a value from its raw 12-bit representation.
+            ///
+            /// # Examples
+            /// Basic usage:
+            ///
+            /// ```rust
+            /// # #[macro_use] extern crate twelve_bit;
+            /// use twelve_bit::u12::*;
+            #[doc = concat!("use twelve_bit::u12::fixed::", stringify!($name), ";")]
+            /// # fn main() {
+            #[doc = concat!("let value = ", stringify!($name), "::from_bits(u12![", stringify!($example_bits), "]);")]
+            #[doc = concat!("assert_eq!(value.to_f32(), ", stringify!($example_value), ");")]
+            /// # }
+            /// ```
+            pub const fn from_bits(bits: U12) -> Self {
+                $name(bits)
+            }
+
+            /// Returns the raw 12-bit representation of `self`.
+            pub const fn to_bits(self) -> U12 {
+                self.0
+            }
+
+            /// Converts `value` to the nearest representable value.
+            /// Returns `None` if `value` is NaN or lies outside the representable range
+            /// after rounding.
+            pub fn from_f64(value: f64) -> Option<Self> {
+                let scaled = (value * (1u32 << $frac_bits) as f64).round();
+                if (0.0..=4095.0).contains(&scaled) {
+                    Some($name(U12(scaled as u16)))
+                } else {
+                    None
+                }
+            }
+
+            /// Converts `value` to the nearest representable value.
+            /// Returns `None` if `value` is NaN or lies outside the representable range
+            /// after rounding.
+            pub fn from_f32(value: f32) -> Option<Self> {
+                Self::from_f64(value as f64)
+            }
+
+            /// Returns the value of `self` as an `f64`. This conversion is exact.
+            pub fn to_f64(self) -> f64 {
+                self.0 .0 as f64 / (1u32 << $frac_bits) as f64
+            }
+
+            /// Returns the value of `self` as an `f32`. This conversion is exact.
+            pub fn to_f32(self) -> f32 {
+                self.0 .0 as f32 / (1u32 << $frac_bits) as f32
+            }
+
+            /// Checked fixed-point addition.
+            /// Computes `self + other`, returning `None` if overflow occurred.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                match self.0.checked_add(other.0) {
+                    Some(bits) => Some($name(bits)),
+                    None => None,
+                }
+            }
+
+            /// Saturating fixed-point addition.
+            /// Computes `self + other`, saturating at the numeric bounds instead of overflowing.
+            pub const fn saturating_add(self, other: Self) -> Self {
+                $name(self.0.saturating_add(other.0))
+            }
+
+            /// Checked fixed-point subtraction.
+            /// Computes `self - other`, returning `None` if underflow occurred.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                match self.0.checked_sub(other.0) {
+                    Some(bits) => Some($name(bits)),
+                    None => None,
+                }
+            }
+
+            /// Saturating fixed-point subtraction.
+            /// Computes `self - other`, saturating at the numeric bounds instead of overflowing.
+            pub const fn saturating_sub(self, other: Self) -> Self {
+                $name(self.0.saturating_sub(other.0))
+            }
+
+            /// Checked fixed-point multiplication.
+            /// Computes `self * other` rounded to the nearest representable value (ties
+            /// round up), returning `None` if overflow occurred.
+            pub const fn checked_mul(self, other: Self) -> Option<Self> {
+                let product = self.0 .0 as u32 * other.0 .0 as u32;
+                let rounded = (product + (1 << ($frac_bits - 1))) >> $frac_bits;
+                if rounded > 0xFFF {
+                    None
+                } else {
+                    Some($name(U12(rounded as u16)))
+                }
+            }
+
+            /// Saturating fixed-point multiplication.
+            /// Computes `self * other` rounded to the nearest representable value (ties
+            /// round up), saturating at the numeric bounds instead of overflowing.
+            pub const fn saturating_mul(self, other: Self) -> Self {
+                match self.checked_mul(other) {
+                    Some(result) => result,
+                    None => Self::MAX,
+                }
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+            fn add(self, other: $name) -> Self::Output {
+                match self.checked_add(other) {
+                    Some(result) => result,
+                    None => panic!("arithmetic overflow"),
+                }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+            fn sub(self, other: $name) -> Self::Output {
+                match self.checked_sub(other) {
+                    Some(result) => result,
+                    None => panic!("arithmetic underflow"),
+                }
+            }
+        }
+
+        impl Mul for $name {
+            type Output = $name;
+            fn mul(self, other: $name) -> Self::Output {
+                match self.checked_mul(other) {
+                    Some(result) => result,
+                    None => panic!("arithmetic overflow"),
+                }
+            }
+        }
+
+        /// Formats the exact decimal value, honoring the precision and padding flags.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+    };
+}
+
+define_fixed_point_type!(
+    /// An unsigned fixed-point number with no integer bits and 12 fractional bits,
+    /// representing values from `0` to `4095/4096` in steps of `1/4096`. This is the usual
+    /// format of 12-bit phase accumulators and normalized calibration coefficients.
+    UQ0_12,
+    12,
+    0x800,
+    0.5
+);
+
+define_fixed_point_type!(
+    /// An unsigned fixed-point number with 4 integer bits and 8 fractional bits,
+    /// representing values from `0` to `4095/256` (just below 16) in steps of `1/256`.
+    UQ4_8,
+    8,
+    0x180,
+    1.5
+);
+
+impl UQ4_8 {
+    /// The value one.
+    pub const ONE: UQ4_8 = UQ4_8(U12(0x100));
+}
@@ -0,0 +1,238 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Experimental 12-bit floating-point numbers.
+//!
+//! Each type stores a sign bit, an exponent field and a mantissa field in a `U12`, following
+//! the conventions of IEEE 754: the exponent is biased by `2^(exponent bits - 1) - 1`, an
+//! all-zero exponent field encodes zero and subnormal values, and an all-ones exponent field
+//! encodes infinities and NaN. `F12` uses a 1-5-6 split between sign, exponent and mantissa,
+//! and `F12E4M7` trades range for precision with a 1-4-7 split.
+//!
+//! Conversions from `f32` round to the nearest representable value, with ties to even, and
+//! conversions to `f32` are exact. Arithmetic is performed in `f32` and rounded back.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::U12;
+
+///
+/// Defines a 12-bit floating-point type `$name` with `$exp_bits` exponent bits and
+/// `$man_bits` mantissa bits, along with its conversions, comparisons and arithmetic.
+///
+macro_rules! define_minifloat_type {
+    ($(#[$attr:meta])* $name:ident, $exp_bits:expr, $man_bits:expr, $example_max:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name(U12);
+
+        impl $name {
+            /// The number of exponent bits.
+            pub const EXPONENT_BITS: u32 = $exp_bits;
+
+            /// The number of explicitly stored mantissa bits.
+            pub const MANTISSA_BITS: u32 = $man_bits;
+
+            /// The exponent bias.
+            const BIAS: i32 = (1 << ($exp_bits - 1)) - 1;
+
+            /// The all-ones exponent field, reserved for infinities and NaN.
+            const EXPONENT_MASK: u16 = ((1 << $exp_bits) - 1) << $man_bits;
+
+            /// The mantissa field.
+            const MANTISSA_MASK: u16 = (1 << $man_bits) - 1;
+
+            /// The sign bit.
+            const SIGN_MASK: u16 = 0x800;
+
+            /// Positive zero.
+            pub const ZERO: $name = $name(U12(0));
+
+            /// Positive infinity.
+            pub const INFINITY: $name = $name(U12(Self::EXPONENT_MASK));
+
+            /// Negative infinity.
+            pub const NEG_INFINITY: $name = $name(U12(Self::SIGN_MASK | Self::EXPONENT_MASK));
+
+            /// The canonical quiet NaN.
+            pub const NAN: $name = $name(U12(Self::EXPONENT_MASK | 1 << ($man_bits - 1)));
+
+            /// The largest finite value.
+            pub const MAX: $name = $name(U12(Self::EXPONENT_MASK - 1));
+
+            /// The smallest finite value, i.e. `-MAX`.
+            pub const MIN: $name = $name(U12(Self::SIGN_MASK | (Self::EXPONENT_MASK - 1)));
+
+            /// The smallest positive normal value.
+            pub const MIN_POSITIVE: $name = $name(U12(1 << $man_bits));
+
+            /// Creates a value from its raw 12-bit representation.
+            pub const fn from_bits(bits: U12) -> Self {
+                $name(bits)
+            }
+
+            /// Returns the raw 12-bit representation of `self`.
+            pub const fn to_bits(self) -> U12 {
+                self.0
+            }
+
+            /// Converts `value` to the nearest representable value, rounding ties to even.
+            /// Values too large in magnitude become infinities, and NaN becomes `NAN`.
+            ///
+            /// # Examples
+            /// Basic usage:
+            ///
+            /// ```rust
+            #[doc = concat!("use twelve_bit::u12::float::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_f32(1.5).to_f32(), 1.5);")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::MAX.to_f32(), ", stringify!($example_max), ");")]
+            #[doc = concat!("assert!(", stringify!($name), "::from_f32(1e9).is_infinite());")]
+            /// ```
+            pub fn from_f32(value: f32) -> Self {
+                let sign = if value.is_sign_negative() { Self::SIGN_MASK } else { 0 };
+                if value.is_nan() {
+                    return Self::NAN;
+                }
+                if value.is_infinite() {
+                    return $name(U12(sign | Self::EXPONENT_MASK));
+                }
+                if value == 0.0 {
+                    return $name(U12(sign));
+                }
+
+                // The unbiased exponent, clamped to the subnormal range. Every nonzero `f32`
+                // is a normal `f64`, so the exponent can be read directly from its bits.
+                let magnitude = (value as f64).abs();
+                let exponent = ((magnitude.to_bits() >> 52) as i32 - 1023).max(1 - Self::BIAS);
+
+                // The significand including its implicit bit, scaled so that one unit is the
+                // spacing of representable values at this exponent. Rounding may carry into
+                // the next exponent, which the addition below propagates naturally.
+                let quantum = exponent - $man_bits;
+                let significand = (magnitude * 2f64.powi(-quantum)).round_ties_even() as u32;
+                let bits = (((exponent + Self::BIAS - 1) as u32) << $man_bits) + significand;
+                if bits >= Self::EXPONENT_MASK as u32 {
+                    $name(U12(sign | Self::EXPONENT_MASK))
+                } else {
+                    $name(U12(sign | bits as u16))
+                }
+            }
+
+            /// Returns the value of `self` as an `f32`. This conversion is exact.
+            pub fn to_f32(self) -> f32 {
+                let bits = self.0 .0;
+                let exponent = ((bits & Self::EXPONENT_MASK) >> $man_bits) as i32;
+                let mantissa = (bits & Self::MANTISSA_MASK) as f32;
+                let magnitude = if bits & Self::EXPONENT_MASK == Self::EXPONENT_MASK {
+                    if mantissa == 0.0 {
+                        f32::INFINITY
+                    } else {
+                        f32::NAN
+                    }
+                } else if exponent == 0 {
+                    mantissa * 2f32.powi(1 - Self::BIAS - $man_bits)
+                } else {
+                    (mantissa + (1 << $man_bits) as f32) * 2f32.powi(exponent - Self::BIAS - $man_bits)
+                };
+                if bits & Self::SIGN_MASK != 0 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+
+            /// Returns `true` if `self` is NaN.
+            pub const fn is_nan(self) -> bool {
+                self.0 .0 & Self::EXPONENT_MASK == Self::EXPONENT_MASK
+                    && self.0 .0 & Self::MANTISSA_MASK != 0
+            }
+
+            /// Returns `true` if `self` is positive or negative infinity.
+            pub const fn is_infinite(self) -> bool {
+                self.0 .0 & !Self::SIGN_MASK == Self::EXPONENT_MASK
+            }
+
+            /// Returns `true` if `self` is neither infinite nor NaN.
+            pub const fn is_finite(self) -> bool {
+                self.0 .0 & Self::EXPONENT_MASK != Self::EXPONENT_MASK
+            }
+
+            /// Returns `true` if the sign bit of `self` is set, including for `-0.0`.
+            pub const fn is_sign_negative(self) -> bool {
+                self.0 .0 & Self::SIGN_MASK != 0
+            }
+        }
+
+        /// Compares numerically: NaN is unequal to everything, and `-0.0 == 0.0`.
+        impl PartialEq for $name {
+            fn eq(&self, other: &$name) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        /// Orders numerically: NaN is unordered with respect to everything.
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+            fn neg(self) -> Self::Output {
+                $name(U12(self.0 .0 ^ Self::SIGN_MASK))
+            }
+        }
+
+        define_minifloat_type!(@binary_op $name, Add, add, +);
+        define_minifloat_type!(@binary_op $name, Sub, sub, -);
+        define_minifloat_type!(@binary_op $name, Mul, mul, *);
+        define_minifloat_type!(@binary_op $name, Div, div, /);
+
+        /// Formats the exact decimal value, honoring the precision and padding flags.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f32(), f)
+            }
+        }
+    };
+
+    (@binary_op $name:ident, $trait_name:ident, $method:ident, $op:tt) => {
+        /// Computes the result in `f32` and rounds it to the nearest representable value.
+        impl $trait_name for $name {
+            type Output = $name;
+            fn $method(self, other: $name) -> Self::Output {
+                $name::from_f32(self.to_f32() $op other.to_f32())
+            }
+        }
+    };
+}
+
+define_minifloat_type!(
+    /// A 12-bit floating-point number with 1 sign bit, 5 exponent bits and 6 mantissa bits.
+    /// It covers magnitudes from about `9.5e-7` (subnormal) up to `65024`, with roughly two
+    /// significant decimal digits.
+    F12,
+    5,
+    6,
+    65024.0
+);
+
+define_minifloat_type!(
+    /// A 12-bit floating-point number with 1 sign bit, 4 exponent bits and 7 mantissa bits.
+    /// It covers magnitudes from about `1.2e-4` (subnormal) up to `255`, with more precision
+    /// but less range than `F12`.
+    F12E4M7,
+    4,
+    7,
+    255.0
+);
@@ -0,0 +1,141 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! SPI frames for common 12-bit DACs and ADCs.
+//!
+//! The MCP4921 and MCP4922 DACs take a 16-bit write command, most significant bit first,
+//! with four configuration bits above the 12-bit value:
+//!
+//! ```text
+//! bit  15:     channel (0 = DAC A, 1 = DAC B)
+//! bit  14:     reference input buffered
+//! bit  13:     gain (0 = 2x, 1 = 1x)
+//! bit  12:     output active (0 = shutdown)
+//! bits 11...0: value
+//! ```
+//!
+//! The MCP3204 and MCP3208 ADCs are read with a three-byte transaction. The first five bits
+//! clocked in select the channel, and the 12-bit result is clocked out in the low nibble of
+//! the second byte and the whole third byte.
+
+use super::U12;
+
+/// The channel of a dual DAC written by a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DacChannel {
+    /// DAC A, the only channel of single DACs.
+    A,
+    /// DAC B.
+    B,
+}
+
+/// The output gain of a DAC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DacGain {
+    /// The output spans `0` to `Vref`.
+    X1,
+    /// The output spans `0` to `2 * Vref`.
+    X2,
+}
+
+/// The configuration bits of an MCP4921 or MCP4922 write command.
+///
+/// The default configuration writes DAC A with an unbuffered reference, unity gain, and
+/// the output active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DacConfig {
+    /// The channel to write.
+    pub channel: DacChannel,
+    /// Whether the reference input is buffered.
+    pub buffered: bool,
+    /// The output gain.
+    pub gain: DacGain,
+    /// Whether the output is shut down, leaving it in a high-impedance state.
+    pub shutdown: bool,
+}
+
+impl Default for DacConfig {
+    fn default() -> Self {
+        DacConfig {
+            channel: DacChannel::A,
+            buffered: false,
+            gain: DacGain::X1,
+            shutdown: false,
+        }
+    }
+}
+
+/// Returns the MCP4921 or MCP4922 write command setting the output to `value`. Send it as
+/// `frame.to_be_bytes()`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::frames::{mcp4921_frame, DacConfig, DacGain};
+/// # fn main() {
+/// assert_eq!(mcp4921_frame(u12![0x800], DacConfig::default()), 0x3800);
+///
+/// let config = DacConfig { gain: DacGain::X2, buffered: true, ..DacConfig::default() };
+/// assert_eq!(mcp4921_frame(U12::max_value(), config), 0x5FFF);
+/// # }
+/// ```
+pub const fn mcp4921_frame(value: U12, config: DacConfig) -> u16 {
+    let channel = match config.channel {
+        DacChannel::A => 0,
+        DacChannel::B => 1,
+    };
+    let gain = match config.gain {
+        DacGain::X1 => 1,
+        DacGain::X2 => 0,
+    };
+    channel << 15
+        | (config.buffered as u16) << 14
+        | gain << 13
+        | (!config.shutdown as u16) << 12
+        | value.0
+}
+
+/// Returns the bytes to send to an MCP3204 or MCP3208 to convert `channel`, either as a
+/// single-ended input or, if `single_ended` is `false`, as a differential pair.
+///
+/// # Panics
+/// This function will panic if `channel` is greater than `7`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::frames::{mcp3208_command, mcp3208_parse};
+/// # fn main() {
+/// assert_eq!(mcp3208_command(5, true), [0x07, 0x40, 0x00]);
+///
+/// // The bytes clocked out by the ADC while the command is sent.
+/// let response = [0xFF, 0xEA, 0xBC];
+/// assert_eq!(mcp3208_parse(response), u12![0xABC]);
+/// # }
+/// ```
+pub const fn mcp3208_command(channel: u8, single_ended: bool) -> [u8; 3] {
+    assert!(channel <= 7, "channel out of range");
+    [
+        0x04 | (single_ended as u8) << 1 | channel >> 2,
+        (channel & 0x3) << 6,
+        0x00,
+    ]
+}
+
+/// Returns the conversion result from the bytes clocked out by an MCP3204 or MCP3208 in
+/// response to `mcp3208_command()`. Bits that the ADC leaves undriven are ignored.
+pub const fn mcp3208_parse(response: [u8; 3]) -> U12 {
+    U12(((response[1] & 0x0F) as u16) << 8 | response[2] as u16)
+}
@@ -0,0 +1,161 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! A histogram with one bin for every `U12` value.
+//!
+//! `Histogram12` counts occurrences of each of the 4096 possible values exactly, which makes
+//! it suitable for characterizing 12-bit sensors and ADCs. Percentiles are computed with the
+//! nearest-rank method, so every result is a value that was actually recorded.
+
+use super::U12;
+
+/// A histogram of `U12` values, with 4096 bins of exact counts.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::histogram::Histogram12;
+/// # fn main() {
+/// let mut histogram = Histogram12::new();
+/// histogram.record_slice(&[u12![10], u12![20], u12![20], u12![4000]]);
+/// assert_eq!(histogram.count(u12![20]), 2);
+/// assert_eq!(histogram.total(), 4);
+/// assert_eq!(histogram.median(), Some(u12![20]));
+/// assert_eq!(histogram.percentile(100.0), Some(u12![4000]));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram12 {
+    counts: Vec<u64>,
+    total: u64,
+}
+
+impl Histogram12 {
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        Histogram12 {
+            counts: vec![0; 4096],
+            total: 0,
+        }
+    }
+
+    /// Records one occurrence of `value`.
+    pub fn record(&mut self, value: U12) {
+        self.counts[value.0 as usize] += 1;
+        self.total += 1;
+    }
+
+    /// Records one occurrence of each value in `values`.
+    pub fn record_slice(&mut self, values: &[U12]) {
+        for value in values {
+            self.counts[value.0 as usize] += 1;
+        }
+        self.total += values.len() as u64;
+    }
+
+    /// Returns the number of recorded occurrences of `value`.
+    pub fn count(&self, value: U12) -> u64 {
+        self.counts[value.0 as usize]
+    }
+
+    /// Returns the counts of every bin, indexed by value.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the total number of recorded values.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns `true` if no values have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// Returns the smallest recorded value, or `None` if the histogram is empty.
+    pub fn min(&self) -> Option<U12> {
+        self.counts
+            .iter()
+            .position(|&count| count != 0)
+            .map(|index| U12(index as u16))
+    }
+
+    /// Returns the largest recorded value, or `None` if the histogram is empty.
+    pub fn max(&self) -> Option<U12> {
+        self.counts
+            .iter()
+            .rposition(|&count| count != 0)
+            .map(|index| U12(index as u16))
+    }
+
+    /// Returns the smallest recorded value such that at least `percentile` percent of the
+    /// recorded values are less than or equal to it, or `None` if the histogram is empty.
+    /// A percentile of `0` returns the smallest recorded value.
+    ///
+    /// # Panics
+    /// This method will panic if `percentile` is not in the range from 0 to 100.
+    pub fn percentile(&self, percentile: f64) -> Option<U12> {
+        assert!(
+            (0.0..=100.0).contains(&percentile),
+            "percentile must be in the range 0 to 100"
+        );
+        if self.total == 0 {
+            return None;
+        }
+        let rank = ((percentile / 100.0 * self.total as f64).ceil() as u64).max(1);
+        let mut cumulative = 0;
+        for (index, &count) in self.counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= rank {
+                return Some(U12(index as u16));
+            }
+        }
+        self.max()
+    }
+
+    /// Returns the median of the recorded values, or `None` if the histogram is empty. For
+    /// an even number of values, this is the lower of the two middle values.
+    pub fn median(&self) -> Option<U12> {
+        self.percentile(50.0)
+    }
+
+    /// Adds the counts of `other` to `self`.
+    pub fn merge(&mut self, other: &Histogram12) {
+        for (count, other_count) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *count += other_count;
+        }
+        self.total += other.total;
+    }
+
+    /// Removes all recorded values.
+    pub fn clear(&mut self) {
+        for count in self.counts.iter_mut() {
+            *count = 0;
+        }
+        self.total = 0;
+    }
+}
+
+impl Default for Histogram12 {
+    fn default() -> Self {
+        Histogram12::new()
+    }
+}
+
+impl Extend<U12> for Histogram12 {
+    fn extend<I: IntoIterator<Item = U12>>(&mut self, values: I) {
+        for value in values {
+            self.record(value);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! A grayscale image with 12-bit pixels, stored packed.
+//!
+//! Each row of pixels is packed independently using the layout of `twelve_bit::u12::packing`,
+//! so a row of `width` pixels occupies `packed_len(width)` bytes and the whole image occupies
+//! `height` times that. This is the layout produced by most RAW12 camera sensors.
+
+use super::packing::{pack_slice, packed_len, unpack_slice};
+use super::U12;
+
+/// A width × height grayscale image with 12-bit pixels, stored packed at 1.5 bytes per pixel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image12 {
+    width: usize,
+    height: usize,
+    pub(super) data: Vec<u8>,
+}
+
+impl Image12 {
+    /// Creates an image of the given dimensions with every pixel set to zero.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::image::Image12;
+    /// # fn main() {
+    /// let mut image = Image12::new(3, 2);
+    /// image.set_pixel(2, 1, u12![0xABC]);
+    /// assert_eq!(image.get_pixel(2, 1), u12![0xABC]);
+    /// assert_eq!(image.get_pixel(0, 0), u12![0]);
+    /// # }
+    /// ```
+    pub fn new(width: usize, height: usize) -> Self {
+        Image12 {
+            width,
+            height,
+            data: vec![0; packed_len(width) * height],
+        }
+    }
+
+    /// Creates an image from packed row data, as returned by `as_packed()`. Returns `None`
+    /// if `data` does not have a length of `packed_len(width) * height` bytes.
+    pub fn from_packed(width: usize, height: usize, data: Vec<u8>) -> Option<Self> {
+        if data.len() != packed_len(width) * height {
+            None
+        } else {
+            Some(Image12 {
+                width,
+                height,
+                data,
+            })
+        }
+    }
+
+    /// Creates an image from row-major 16-bit samples. Returns `None` if `samples` does
+    /// not contain exactly `width * height` samples, or if any sample exceeds `0xFFF`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::image::Image12;
+    /// # fn main() {
+    /// let image = Image12::from_u16_samples(2, 1, &[0x123, 0xFFF]).unwrap();
+    /// assert_eq!(image.get_pixel(1, 0), u12![0xFFF]);
+    /// assert_eq!(image.to_u16_samples(), vec![0x123, 0xFFF]);
+    /// assert_eq!(Image12::from_u16_samples(2, 1, &[0x123, 0x1000]), None);
+    /// # }
+    /// ```
+    pub fn from_u16_samples(width: usize, height: usize, samples: &[u16]) -> Option<Self> {
+        if samples.len() != width * height || samples.iter().any(|&sample| sample > 0xFFF) {
+            return None;
+        }
+        let mut image = Image12::new(width, height);
+        let mut row = vec![U12::min_value(); width];
+        for (y, samples) in samples.chunks(width.max(1)).enumerate().take(height) {
+            for (pixel, &sample) in row.iter_mut().zip(samples) {
+                *pixel = U12(sample);
+            }
+            image.set_row(y, &row);
+        }
+        Some(image)
+    }
+
+    /// Creates an image from row-major 8-bit samples, scaling each sample to the full
+    /// 12-bit range by bit replication, such that `0` maps to `0x000` and `255` maps to
+    /// `0xFFF`. Returns `None` if `samples` does not contain exactly `width * height` samples.
+    pub fn from_u8_samples(width: usize, height: usize, samples: &[u8]) -> Option<Self> {
+        if samples.len() != width * height {
+            return None;
+        }
+        let mut image = Image12::new(width, height);
+        let mut row = vec![U12::min_value(); width];
+        for (y, samples) in samples.chunks(width.max(1)).enumerate().take(height) {
+            for (pixel, &sample) in row.iter_mut().zip(samples) {
+                *pixel = U12((sample as u16) << 4 | (sample as u16) >> 4);
+            }
+            image.set_row(y, &row);
+        }
+        Some(image)
+    }
+
+    /// Returns the width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the packed pixel data, one packed row after another.
+    pub fn as_packed(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consumes the image, returning the packed pixel data.
+    pub fn into_packed(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns the pixel at column `x` of row `y`.
+    ///
+    /// # Panics
+    /// This method will panic if `x >= width` or `y >= height`.
+    pub fn get_pixel(&self, x: usize, y: usize) -> U12 {
+        let (offset, odd) = self.locate(x, y);
+        let data = &self.data;
+        if odd {
+            U12((data[offset + 1] as u16) << 4 | (data[offset + 2] >> 4) as u16)
+        } else if x + 1 < self.width {
+            U12((data[offset] as u16) << 4 | (data[offset + 2] & 0xF) as u16)
+        } else {
+            U12((data[offset] as u16) << 4 | (data[offset + 1] & 0xF) as u16)
+        }
+    }
+
+    /// Sets the pixel at column `x` of row `y` to `value`.
+    ///
+    /// # Panics
+    /// This method will panic if `x >= width` or `y >= height`.
+    pub fn set_pixel(&mut self, x: usize, y: usize, value: U12) {
+        let (offset, odd) = self.locate(x, y);
+        let high = (value.0 >> 4) as u8;
+        let low = (value.0 & 0xF) as u8;
+        if odd {
+            self.data[offset + 1] = high;
+            self.data[offset + 2] = (self.data[offset + 2] & 0x0F) | low << 4;
+        } else if x + 1 < self.width {
+            self.data[offset] = high;
+            self.data[offset + 2] = (self.data[offset + 2] & 0xF0) | low;
+        } else {
+            self.data[offset] = high;
+            self.data[offset + 1] = low;
+        }
+    }
+
+    /// Unpacks row `y` into `pixels`.
+    ///
+    /// # Panics
+    /// This method will panic if `y >= height` or `pixels.len() != width`.
+    pub fn read_row(&self, y: usize, pixels: &mut [U12]) {
+        unpack_slice(self.packed_row(y), pixels);
+    }
+
+    /// Packs `pixels` into row `y`.
+    ///
+    /// # Panics
+    /// This method will panic if `y >= height` or `pixels.len() != width`.
+    pub fn set_row(&mut self, y: usize, pixels: &[U12]) {
+        assert!(y < self.height, "row out of bounds");
+        let stride = packed_len(self.width);
+        pack_slice(pixels, &mut self.data[y * stride..(y + 1) * stride]);
+    }
+
+    /// Returns the packed bytes of row `y`.
+    ///
+    /// # Panics
+    /// This method will panic if `y >= height`.
+    pub fn packed_row(&self, y: usize) -> &[u8] {
+        assert!(y < self.height, "row out of bounds");
+        let stride = packed_len(self.width);
+        &self.data[y * stride..(y + 1) * stride]
+    }
+
+    /// Returns an iterator over the rows of the image, from top to bottom, each unpacked
+    /// into a vector of `width` pixels.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::image::Image12;
+    /// # fn main() {
+    /// let image = Image12::from_u16_samples(2, 2, &[1, 2, 3, 4]).unwrap();
+    /// let rows: Vec<Vec<U12>> = image.rows().collect();
+    /// assert_eq!(rows, vec![vec![u12![1], u12![2]], vec![u12![3], u12![4]]]);
+    /// # }
+    /// ```
+    pub fn rows(&self) -> Rows<'_> {
+        Rows { image: self, y: 0 }
+    }
+
+    /// Returns the pixels as row-major 16-bit samples.
+    pub fn to_u16_samples(&self) -> Vec<u16> {
+        self.rows()
+            .flat_map(|row| row.into_iter().map(u16::from))
+            .collect()
+    }
+
+    /// Returns the pixels as row-major 8-bit samples, each rounded to the nearest 8-bit
+    /// value, such that `0x000` maps to `0` and `0xFFF` maps to `255`.
+    pub fn to_u8_samples(&self) -> Vec<u8> {
+        self.rows()
+            .flat_map(|row| {
+                row.into_iter()
+                    .map(|pixel| ((pixel.0 as u32 * 255 + 2047) / 4095) as u8)
+            })
+            .collect()
+    }
+
+    /// Returns the offset of the packed pair containing pixel `(x, y)`, and whether the
+    /// pixel is the second of the pair.
+    fn locate(&self, x: usize, y: usize) -> (usize, bool) {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        (y * packed_len(self.width) + x / 2 * 3, x % 2 == 1)
+    }
+}
+
+/// An iterator over the rows of an `Image12`, each unpacked into a vector of pixels.
+/// This is created by the `Image12::rows()` method.
+#[derive(Debug, Clone)]
+pub struct Rows<'a> {
+    image: &'a Image12,
+    y: usize,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Vec<U12>;
+
+    fn next(&mut self) -> Option<Vec<U12>> {
+        if self.y >= self.image.height {
+            None
+        } else {
+            let mut row = vec![U12::min_value(); self.image.width];
+            self.image.read_row(self.y, &mut row);
+            self.y += 1;
+            Some(row)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.image.height - self.y;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Rows<'a> {}
@@ -0,0 +1,371 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Extension traits for reading and writing `U12` values with `std::io`.
+//!
+//! `ReadU12Ext` and `WriteU12Ext` are implemented for every `Read` and `Write` respectively.
+//! Single values are stored in two bytes, in either byte order. Pairs of values can also be
+//! stored in three bytes, using the layout of `twelve_bit::u12::packing`.
+//!
+//! `CodeWriter` and `CodeReader` handle streams of variable-width codes, as produced by LZW
+//! compressors. Codes are 1 to 12 bits wide, and the width can change between any two codes,
+//! typically growing by one bit each time the dictionary doubles in size. Codes are packed
+//! either least significant bit first, as in GIF, or most significant bit first, as in TIFF.
+
+use std::io::{self, Read, Write};
+
+use super::U12;
+
+/// Extends `Read` with methods for reading 12-bit values.
+pub trait ReadU12Ext: Read {
+    /// Reads a value stored big-endian in two bytes.
+    ///
+    /// # Errors
+    /// Returns an error of kind `InvalidData` if the value exceeds `0xFFF`, and any error
+    /// returned by the underlying reader.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::io::ReadU12Ext;
+    /// # fn main() {
+    /// let mut reader: &[u8] = &[0x0A, 0xBC, 0x10, 0x00];
+    /// assert_eq!(reader.read_u12_be().unwrap(), u12![0xABC]);
+    /// assert!(reader.read_u12_be().is_err());
+    /// # }
+    /// ```
+    fn read_u12_be(&mut self) -> io::Result<U12> {
+        let mut bytes = [0; 2];
+        self.read_exact(&mut bytes)?;
+        checked_value(u16::from_be_bytes(bytes))
+    }
+
+    /// Reads a value stored little-endian in two bytes.
+    ///
+    /// # Errors
+    /// Returns an error of kind `InvalidData` if the value exceeds `0xFFF`, and any error
+    /// returned by the underlying reader.
+    fn read_u12_le(&mut self) -> io::Result<U12> {
+        let mut bytes = [0; 2];
+        self.read_exact(&mut bytes)?;
+        checked_value(u16::from_le_bytes(bytes))
+    }
+
+    /// Reads a pair of values packed into three bytes.
+    ///
+    /// # Errors
+    /// Returns any error returned by the underlying reader.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::io::ReadU12Ext;
+    /// # fn main() {
+    /// let mut reader: &[u8] = &[0xAB, 0x12, 0x3C];
+    /// assert_eq!(reader.read_u12_pair_packed().unwrap(), (u12![0xABC], u12![0x123]));
+    /// # }
+    /// ```
+    fn read_u12_pair_packed(&mut self) -> io::Result<(U12, U12)> {
+        let mut bytes = [0; 3];
+        self.read_exact(&mut bytes)?;
+        let a = (bytes[0] as u16) << 4 | (bytes[2] & 0xF) as u16;
+        let b = (bytes[1] as u16) << 4 | (bytes[2] >> 4) as u16;
+        Ok((U12(a), U12(b)))
+    }
+}
+
+impl<R: Read + ?Sized> ReadU12Ext for R {}
+
+/// Extends `Write` with methods for writing 12-bit values.
+pub trait WriteU12Ext: Write {
+    /// Writes `value` big-endian in two bytes.
+    ///
+    /// # Errors
+    /// Returns any error returned by the underlying writer.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::io::WriteU12Ext;
+    /// # fn main() {
+    /// let mut writer = Vec::new();
+    /// writer.write_u12_be(u12![0xABC]).unwrap();
+    /// writer.write_u12_le(u12![0xABC]).unwrap();
+    /// assert_eq!(writer, [0x0A, 0xBC, 0xBC, 0x0A]);
+    /// # }
+    /// ```
+    fn write_u12_be(&mut self, value: U12) -> io::Result<()> {
+        self.write_all(&value.0.to_be_bytes())
+    }
+
+    /// Writes `value` little-endian in two bytes.
+    ///
+    /// # Errors
+    /// Returns any error returned by the underlying writer.
+    fn write_u12_le(&mut self, value: U12) -> io::Result<()> {
+        self.write_all(&value.0.to_le_bytes())
+    }
+
+    /// Writes the pair of values `a` and `b` packed into three bytes.
+    ///
+    /// # Errors
+    /// Returns any error returned by the underlying writer.
+    fn write_u12_pair_packed(&mut self, a: U12, b: U12) -> io::Result<()> {
+        self.write_all(&[
+            (a.0 >> 4) as u8,
+            (b.0 >> 4) as u8,
+            ((b.0 & 0xF) << 4) as u8 | (a.0 & 0xF) as u8,
+        ])
+    }
+}
+
+impl<W: Write + ?Sized> WriteU12Ext for W {}
+
+/// Returns `value` as a `U12`, or an `InvalidData` error if it does not fit.
+fn checked_value(value: u16) -> io::Result<U12> {
+    if value > 0xFFF {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "value does not fit in 12 bits",
+        ))
+    } else {
+        Ok(U12(value))
+    }
+}
+
+/// The order in which the bits of variable-width codes are packed into bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeOrder {
+    /// Codes fill each byte from its least significant bit upwards, as in GIF.
+    LsbFirst,
+    /// Codes fill each byte from its most significant bit downwards, as in TIFF and PDF.
+    MsbFirst,
+}
+
+/// Writes a stream of variable-width codes to a `Write`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::io::{CodeOrder, CodeReader, CodeWriter};
+/// # fn main() {
+/// let mut writer = CodeWriter::new(Vec::new(), CodeOrder::LsbFirst, 9);
+/// writer.write_code(u12![0x100]).unwrap();
+/// writer.set_width(12);
+/// writer.write_code(u12![0xABC]).unwrap();
+/// let bytes = writer.finish().unwrap();
+/// assert_eq!(bytes, [0x00, 0x79, 0x15]);
+///
+/// let mut reader = CodeReader::new(&bytes[..], CodeOrder::LsbFirst, 9);
+/// assert_eq!(reader.read_code().unwrap(), Some(u12![0x100]));
+/// reader.set_width(12);
+/// assert_eq!(reader.read_code().unwrap(), Some(u12![0xABC]));
+/// assert_eq!(reader.read_code().unwrap(), None);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CodeWriter<W: Write> {
+    inner: W,
+    order: CodeOrder,
+    width: u32,
+    buffer: u32,
+    bits: u32,
+}
+
+impl<W: Write> CodeWriter<W> {
+    /// Creates a writer of codes `width` bits wide, packed in `order`.
+    ///
+    /// # Panics
+    /// This function will panic if `width` is not in the range from 1 to 12.
+    pub fn new(inner: W, order: CodeOrder, width: u32) -> Self {
+        check_width(width);
+        CodeWriter {
+            inner,
+            order,
+            width,
+            buffer: 0,
+            bits: 0,
+        }
+    }
+
+    /// Returns the width of the codes being written.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Changes the width of the following codes.
+    ///
+    /// # Panics
+    /// This method will panic if `width` is not in the range from 1 to 12.
+    pub fn set_width(&mut self, width: u32) {
+        check_width(width);
+        self.width = width;
+    }
+
+    /// Writes `code` at the current width. Complete bytes are passed on to the underlying
+    /// writer immediately.
+    ///
+    /// # Errors
+    /// Returns an error of kind `InvalidInput` if `code` does not fit in the current width,
+    /// and any error returned by the underlying writer.
+    pub fn write_code(&mut self, code: U12) -> io::Result<()> {
+        if code.0 >> self.width != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "code does not fit in the current width",
+            ));
+        }
+        let mut bytes = [0; 3];
+        let mut len = 0;
+        match self.order {
+            CodeOrder::LsbFirst => {
+                self.buffer |= (code.0 as u32) << self.bits;
+                self.bits += self.width;
+                while self.bits >= 8 {
+                    bytes[len] = self.buffer as u8;
+                    len += 1;
+                    self.buffer >>= 8;
+                    self.bits -= 8;
+                }
+            }
+            CodeOrder::MsbFirst => {
+                self.buffer = self.buffer << self.width | code.0 as u32;
+                self.bits += self.width;
+                while self.bits >= 8 {
+                    self.bits -= 8;
+                    bytes[len] = (self.buffer >> self.bits) as u8;
+                    len += 1;
+                }
+                self.buffer &= (1 << self.bits) - 1;
+            }
+        }
+        self.inner.write_all(&bytes[..len])
+    }
+
+    /// Writes any buffered bits, padded with zero bits to a whole byte, and returns the
+    /// underlying writer.
+    ///
+    /// # Errors
+    /// Returns any error returned by the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.bits > 0 {
+            let last = match self.order {
+                CodeOrder::LsbFirst => self.buffer as u8,
+                CodeOrder::MsbFirst => (self.buffer << (8 - self.bits)) as u8,
+            };
+            self.inner.write_all(&[last])?;
+        }
+        Ok(self.inner)
+    }
+}
+
+/// Reads a stream of variable-width codes from a `Read`.
+/// This is the counterpart of `CodeWriter`.
+#[derive(Debug)]
+pub struct CodeReader<R: Read> {
+    inner: R,
+    order: CodeOrder,
+    width: u32,
+    buffer: u32,
+    bits: u32,
+}
+
+impl<R: Read> CodeReader<R> {
+    /// Creates a reader of codes `width` bits wide, packed in `order`.
+    ///
+    /// # Panics
+    /// This function will panic if `width` is not in the range from 1 to 12.
+    pub fn new(inner: R, order: CodeOrder, width: u32) -> Self {
+        check_width(width);
+        CodeReader {
+            inner,
+            order,
+            width,
+            buffer: 0,
+            bits: 0,
+        }
+    }
+
+    /// Returns the width of the codes being read.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Changes the width of the following codes.
+    ///
+    /// # Panics
+    /// This method will panic if `width` is not in the range from 1 to 12.
+    pub fn set_width(&mut self, width: u32) {
+        check_width(width);
+        self.width = width;
+    }
+
+    /// Reads the next code at the current width. Returns `None` at the end of the stream,
+    /// when too few bits remain for a whole code; those bits are taken to be padding.
+    ///
+    /// # Errors
+    /// Returns any error returned by the underlying reader.
+    pub fn read_code(&mut self) -> io::Result<Option<U12>> {
+        while self.bits < self.width {
+            let mut byte = [0];
+            match self.inner.read(&mut byte) {
+                Ok(0) => return Ok(None),
+                Ok(_) => {}
+                Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+            match self.order {
+                CodeOrder::LsbFirst => self.buffer |= (byte[0] as u32) << self.bits,
+                CodeOrder::MsbFirst => self.buffer = self.buffer << 8 | byte[0] as u32,
+            }
+            self.bits += 8;
+        }
+        let mask = (1 << self.width) - 1;
+        self.bits -= self.width;
+        let code = match self.order {
+            CodeOrder::LsbFirst => {
+                let code = self.buffer & mask;
+                self.buffer >>= self.width;
+                code
+            }
+            CodeOrder::MsbFirst => {
+                let code = self.buffer >> self.bits & mask;
+                self.buffer &= (1 << self.bits) - 1;
+                code
+            }
+        };
+        Ok(Some(U12(code as u16)))
+    }
+
+    /// Returns the underlying reader. Any bits read from it but not yet returned as codes
+    /// are discarded.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Panics if `width` is not a valid code width.
+fn check_width(width: u32) {
+    assert!(
+        (1..=12).contains(&width),
+        "code width must be in the range 1 to 12"
+    );
+}
@@ -0,0 +1,144 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! A 12-bit linear-feedback shift register.
+//!
+//! `Lfsr12` produces a deterministic sequence of `U12` values, fully determined by its taps,
+//! its configuration and its seed. With the default taps, `x^12 + x^11 + x^10 + x^4 + 1`,
+//! the register visits every nonzero state exactly once before repeating, for a period of
+//! 4095. The all-zero state is a fixed point of every LFSR, so it can never be used as a seed.
+
+use super::U12;
+
+/// The way the taps of an `Lfsr12` feed back into the register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfsrKind {
+    /// The register shifts left, and the parity of the tapped bits is shifted in at the
+    /// bottom.
+    Fibonacci,
+    /// The register shifts right, and the bit shifted out at the bottom is xored into the
+    /// tapped bits.
+    Galois,
+}
+
+/// A 12-bit linear-feedback shift register.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::lfsr::Lfsr12;
+/// # fn main() {
+/// let mut lfsr = Lfsr12::new(u12![1]).unwrap();
+/// let first: Vec<U12> = lfsr.clone().take(4).collect();
+/// assert_eq!(lfsr.step(), first[0]);
+/// assert_eq!(Lfsr12::new(u12![1]).unwrap().take(4095).filter(|&v| v == u12![1]).count(), 1);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lfsr12 {
+    state: u16,
+    taps: u16,
+    kind: LfsrKind,
+}
+
+impl Lfsr12 {
+    /// The taps of the maximal-length polynomial `x^12 + x^11 + x^10 + x^4 + 1`.
+    pub const DEFAULT_TAPS: U12 = U12(0xE08);
+
+    /// Creates a Fibonacci register with the default taps, starting from `seed`.
+    /// Returns `None` if `seed` is zero.
+    pub const fn new(seed: U12) -> Option<Self> {
+        Self::with_taps(seed, Self::DEFAULT_TAPS, LfsrKind::Fibonacci)
+    }
+
+    /// Creates a register of the given kind with custom taps, starting from `seed`. Bit `n`
+    /// of `taps` corresponds to the term `x^(n + 1)` of the feedback polynomial, so bit 11
+    /// should always be set.
+    /// Returns `None` if `seed` or `taps` is zero.
+    pub const fn with_taps(seed: U12, taps: U12, kind: LfsrKind) -> Option<Self> {
+        if seed.0 == 0 || taps.0 == 0 {
+            None
+        } else {
+            Some(Lfsr12 {
+                state: seed.0,
+                taps: taps.0,
+                kind,
+            })
+        }
+    }
+
+    /// Returns the current state of the register.
+    pub const fn state(&self) -> U12 {
+        U12(self.state)
+    }
+
+    /// Returns the taps of the register.
+    pub const fn taps(&self) -> U12 {
+        U12(self.taps)
+    }
+
+    /// Returns the kind of the register.
+    pub const fn kind(&self) -> LfsrKind {
+        self.kind
+    }
+
+    /// Restarts the sequence from `seed`.
+    ///
+    /// # Panics
+    /// This method will panic if `seed` is zero.
+    pub fn reseed(&mut self, seed: U12) {
+        assert!(seed.0 != 0, "LFSR seed must be nonzero");
+        self.state = seed.0;
+    }
+
+    /// Advances the register by one step and returns its new state.
+    pub fn step(&mut self) -> U12 {
+        self.state = match self.kind {
+            LfsrKind::Fibonacci => {
+                let feedback = (self.state & self.taps).count_ones() as u16 & 1;
+                (self.state << 1 & 0xFFF) | feedback
+            }
+            LfsrKind::Galois => {
+                if self.state & 1 != 0 {
+                    self.state >> 1 ^ self.taps
+                } else {
+                    self.state >> 1
+                }
+            }
+        };
+        U12(self.state)
+    }
+}
+
+impl Default for Lfsr12 {
+    /// Returns a Fibonacci register with the default taps, seeded with one.
+    fn default() -> Self {
+        Lfsr12 {
+            state: 1,
+            taps: Self::DEFAULT_TAPS.0,
+            kind: LfsrKind::Fibonacci,
+        }
+    }
+}
+
+/// An endless iterator over the successive states of the register.
+impl Iterator for Lfsr12 {
+    type Item = U12;
+
+    fn next(&mut self) -> Option<U12> {
+        Some(self.step())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
@@ -0,0 +1,138 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Lookup tables mapping every `U12` value to another.
+//!
+//! `Lut12` holds one output for each of the 4096 possible inputs, so any per-value transfer
+//! function, such as gamma correction or tone mapping, can be computed once and then applied
+//! to whole images with a single table lookup per pixel.
+
+use std::ops::Index;
+
+use super::U12;
+
+/// A lookup table with one `U12` output for every `U12` input.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::lut::Lut12;
+/// # fn main() {
+/// let invert = Lut12::from_fn(|value| !value);
+/// let mut pixels = [u12![0], u12![0x0FF], u12![0xFFF]];
+/// invert.apply_slice(&mut pixels);
+/// assert_eq!(pixels, [u12![0xFFF], u12![0xF00], u12![0]]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lut12 {
+    table: Vec<U12>,
+}
+
+impl Lut12 {
+    /// Creates the table which maps every value to itself.
+    pub fn identity() -> Self {
+        Lut12::from_fn(|value| value)
+    }
+
+    /// Creates a table by evaluating `f` once for every value.
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(U12) -> U12,
+    {
+        Lut12 {
+            table: (0..4096).map(U12).map(f).collect(),
+        }
+    }
+
+    /// Creates a table from its 4096 outputs, indexed by input. Returns `None` if `table`
+    /// does not have exactly 4096 entries.
+    pub fn from_table(table: &[U12]) -> Option<Self> {
+        if table.len() != 4096 {
+            None
+        } else {
+            Some(Lut12 {
+                table: table.to_vec(),
+            })
+        }
+    }
+
+    /// Creates the table for the power-law transfer function `x^gamma`, with inputs and
+    /// outputs normalized to the full 12-bit range and outputs rounded to the nearest value.
+    /// Use `gamma` below `1` to brighten, as when encoding linear sensor data for display,
+    /// and above `1` to darken.
+    ///
+    /// # Panics
+    /// This method will panic if `gamma` is not a positive, finite number.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::lut::Lut12;
+    /// # fn main() {
+    /// let lut = Lut12::gamma(0.5);
+    /// assert_eq!(lut.apply(u12![0]), u12![0]);
+    /// assert_eq!(lut.apply(u12![1024]), u12![2048]);
+    /// assert_eq!(lut.apply(u12![4095]), u12![4095]);
+    /// # }
+    /// ```
+    pub fn gamma(gamma: f64) -> Self {
+        assert!(
+            gamma > 0.0 && gamma.is_finite(),
+            "gamma must be positive and finite"
+        );
+        Lut12::from_fn(|value| {
+            let normalized = value.0 as f64 / 4095.0;
+            U12((normalized.powf(gamma) * 4095.0).round() as u16)
+        })
+    }
+
+    /// Returns the output for `value`.
+    pub fn apply(&self, value: U12) -> U12 {
+        self.table[value.0 as usize]
+    }
+
+    /// Replaces every value in `values` with its output.
+    pub fn apply_slice(&self, values: &mut [U12]) {
+        for value in values {
+            *value = self.table[value.0 as usize];
+        }
+    }
+
+    /// Returns the table which applies `self` and then `next`.
+    pub fn then(&self, next: &Lut12) -> Self {
+        Lut12 {
+            table: self.table.iter().map(|&value| next.apply(value)).collect(),
+        }
+    }
+
+    /// Returns the 4096 outputs of the table, indexed by input.
+    pub fn as_slice(&self) -> &[U12] {
+        &self.table
+    }
+}
+
+impl Default for Lut12 {
+    fn default() -> Self {
+        Lut12::identity()
+    }
+}
+
+impl Index<U12> for Lut12 {
+    type Output = U12;
+    fn index(&self, value: U12) -> &U12 {
+        &self.table[value.0 as usize]
+    }
+}
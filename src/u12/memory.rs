@@ -0,0 +1,159 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! A 4096-byte memory addressed by `U12`.
+//!
+//! `Memory4K` models the 12-bit address space of machines such as the CHIP-8 and the PDP-8.
+//! Since every `U12` is a valid address, reads and writes cannot go out of bounds, and no
+//! conversion to `usize` is needed at call sites. Bytes can be accessed with `read`/`write`
+//! or by indexing with a `U12`, and ranges of `U12` addresses index into byte slices.
+
+use std::fmt;
+use std::ops::{Index, IndexMut, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+use super::U12;
+
+/// The number of bytes in a `Memory4K`.
+pub const MEMORY_SIZE: usize = 4096;
+
+/// A 4096-byte memory, addressed by `U12`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::memory::Memory4K;
+/// # fn main() {
+/// let mut memory = Memory4K::new();
+/// memory.load_at(u12![0x200], &[0x60, 0x0A, 0x12, 0x00]);
+/// assert_eq!(memory.read(u12![0x201]), 0x0A);
+/// assert_eq!(memory.read_u16(u12![0x202]), 0x1200);
+/// assert_eq!(&memory[u12![0x200]..u12![0x202]], &[0x60, 0x0A]);
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Memory4K([u8; MEMORY_SIZE]);
+
+impl Memory4K {
+    /// Creates a memory with every byte set to zero.
+    pub const fn new() -> Self {
+        Memory4K([0; MEMORY_SIZE])
+    }
+
+    /// Creates a memory holding `bytes`.
+    pub const fn from_bytes(bytes: [u8; MEMORY_SIZE]) -> Self {
+        Memory4K(bytes)
+    }
+
+    /// Returns the byte at `address`.
+    pub const fn read(&self, address: U12) -> u8 {
+        self.0[address.0 as usize]
+    }
+
+    /// Sets the byte at `address` to `value`.
+    pub fn write(&mut self, address: U12, value: u8) {
+        self.0[address.0 as usize] = value;
+    }
+
+    /// Returns the big-endian 16-bit word starting at `address`. The address of the second
+    /// byte wraps around from `0xFFF` to `0x000`.
+    pub const fn read_u16(&self, address: U12) -> u16 {
+        let high = self.read(address) as u16;
+        let low = self.read(address.wrapping_add(U12(1))) as u16;
+        high << 8 | low
+    }
+
+    /// Stores `value` as a big-endian 16-bit word starting at `address`. The address of the
+    /// second byte wraps around from `0xFFF` to `0x000`.
+    pub fn write_u16(&mut self, address: U12, value: u16) {
+        self.write(address, (value >> 8) as u8);
+        self.write(address.wrapping_add(U12(1)), value as u8);
+    }
+
+    /// Copies `bytes` into memory, starting at `address`.
+    ///
+    /// # Panics
+    /// This method will panic if `bytes` extends past the end of memory, i.e. if
+    /// `address + bytes.len() > 4096`.
+    pub fn load_at(&mut self, address: U12, bytes: &[u8]) {
+        let start = address.0 as usize;
+        assert!(
+            bytes.len() <= MEMORY_SIZE - start,
+            "data does not fit in memory"
+        );
+        self.0[start..start + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Returns the whole memory as a byte slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the whole memory as a mutable byte slice.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Default for Memory4K {
+    fn default() -> Self {
+        Memory4K::new()
+    }
+}
+
+/// Formats the memory without its contents, which are too large to be useful in debug output.
+impl fmt::Debug for Memory4K {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Memory4K").finish_non_exhaustive()
+    }
+}
+
+impl Index<U12> for Memory4K {
+    type Output = u8;
+    fn index(&self, address: U12) -> &u8 {
+        &self.0[address.0 as usize]
+    }
+}
+
+impl IndexMut<U12> for Memory4K {
+    fn index_mut(&mut self, address: U12) -> &mut u8 {
+        &mut self.0[address.0 as usize]
+    }
+}
+
+///
+/// Implements Index and IndexMut for a range of `U12` addresses, by mapping the range
+/// bounds to `usize` with `$convert`.
+///
+macro_rules! impl_range_index_for_memory {
+    ($range:ty, |$r:ident| $convert:expr) => {
+        impl Index<$range> for Memory4K {
+            type Output = [u8];
+            fn index(&self, $r: $range) -> &[u8] {
+                &self.0[$convert]
+            }
+        }
+
+        impl IndexMut<$range> for Memory4K {
+            fn index_mut(&mut self, $r: $range) -> &mut [u8] {
+                &mut self.0[$convert]
+            }
+        }
+    };
+}
+
+impl_range_index_for_memory!(Range<U12>, |r| r.start.0 as usize..r.end.0 as usize);
+impl_range_index_for_memory!(RangeFrom<U12>, |r| r.start.0 as usize..);
+impl_range_index_for_memory!(RangeTo<U12>, |r| ..r.end.0 as usize);
+impl_range_index_for_memory!(RangeInclusive<U12>, |r| {
+    r.start().0 as usize..=r.end().0 as usize
+});
+impl_range_index_for_memory!(RangeToInclusive<U12>, |r| ..=r.end.0 as usize);
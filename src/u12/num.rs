@@ -0,0 +1,88 @@
+//! Integration with the `num` ecosystem, enabled by the `num` feature.
+//!
+//! `U12` implements `Zero`, `One` and `Num` from `num-traits`, and `Integer` from
+//! `num-integer`, so that it can be used with generic numeric algorithms such as the
+//! reduction of rationals.
+
+use super::{ParseU12Error, MAX, U12};
+extern crate num_integer;
+extern crate num_traits;
+
+use self::num_integer::Integer;
+use self::num_traits::{Num, One, Zero};
+
+impl Zero for U12 {
+    fn zero() -> Self {
+        U12(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for U12 {
+    fn one() -> Self {
+        U12(1)
+    }
+}
+
+impl Num for U12 {
+    type FromStrRadixErr = ParseU12Error;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseU12Error> {
+        U12::from_str_radix(src, radix)
+    }
+}
+
+/// For unsigned values, floored and truncated division are identical.
+impl Integer for U12 {
+    fn div_floor(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    fn mod_floor(&self, other: &Self) -> Self {
+        *self % *other
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.0, other.0);
+        while b != 0 {
+            let remainder = a % b;
+            a = b;
+            b = remainder;
+        }
+        U12(a)
+    }
+
+    /// Computes the least common multiple of `self` and `other`.
+    ///
+    /// # Panics
+    /// This method will panic if the result does not fit in 12 bits.
+    fn lcm(&self, other: &Self) -> Self {
+        if self.0 == 0 || other.0 == 0 {
+            return U12(0);
+        }
+        let lcm = (self.0 / self.gcd(other).0) as u32 * other.0 as u32;
+        if lcm > MAX.0 as u32 {
+            panic!("arithmetic overflow");
+        }
+        U12(lcm as u16)
+    }
+
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        U12::is_multiple_of(*self, *other)
+    }
+
+    fn is_even(&self) -> bool {
+        U12::is_even(*self)
+    }
+
+    fn is_odd(&self) -> bool {
+        U12::is_odd(*self)
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        U12::div_rem(*self, *other)
+    }
+}
@@ -0,0 +1,461 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Bulk conversion between `U12` slices and densely packed byte buffers.
+//!
+//! Values are packed two at a time into three bytes using the RAW12 layout common to
+//! camera sensors: the first two bytes hold the high eight bits of each value, and the
+//! third byte holds the low nibble of the first value in bits `0...3` and the low nibble
+//! of the second value in bits `4...7`.
+//!
+//! ```text
+//! byte 0: a[11:4]
+//! byte 1: b[11:4]
+//! byte 2: b[3:0] a[3:0]
+//! ```
+//!
+//! A trailing unpaired value occupies two bytes, `a[11:4]` followed by `a[3:0]` in the
+//! low nibble, so `n` values always occupy exactly `packed_len(n)` bytes.
+//!
+//! Fixed groups of values can also be packed into a single integer word, with five values
+//! in a `u64` or ten values in a `u128`, for storage in one field or atomic publication.
+//! `PackedU12Array` stores a fixed number of values inline, without allocating.
+//!
+//! Slowly varying signals compress further with the delta codec in `delta`.
+
+use super::U12;
+
+#[cfg(feature = "bitvec")]
+pub use super::bitvec::{values_from_bitslice, values_to_bitvec};
+#[cfg(feature = "rayon")]
+pub use super::rayon::{par_pack_slice, par_unpack_slice};
+
+/// The number of values processed per iteration of the block kernel.
+const BLOCK_VALUES: usize = 8;
+
+/// The number of packed bytes holding `BLOCK_VALUES` values.
+const BLOCK_BYTES: usize = BLOCK_VALUES / 2 * 3;
+
+/// Returns the number of bytes required to pack `count` values, i.e. `⌈count × 1.5⌉`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```
+/// use twelve_bit::u12::packing::packed_len;
+///
+/// assert_eq!(packed_len(0), 0);
+/// assert_eq!(packed_len(1), 2);
+/// assert_eq!(packed_len(2), 3);
+/// assert_eq!(packed_len(5), 8);
+/// ```
+pub const fn packed_len(count: usize) -> usize {
+    count / 2 * 3 + (count % 2) * 2
+}
+
+/// Packs a pair of values into three bytes.
+#[inline(always)]
+fn pack_pair(a: u16, b: u16, dst: &mut [u8]) {
+    dst[0] = (a >> 4) as u8;
+    dst[1] = (b >> 4) as u8;
+    dst[2] = ((b & 0xF) << 4) as u8 | (a & 0xF) as u8;
+}
+
+/// Unpacks a pair of values from three bytes.
+#[inline(always)]
+fn unpack_pair(src: &[u8]) -> (u16, u16) {
+    let a = (src[0] as u16) << 4 | (src[2] & 0xF) as u16;
+    let b = (src[1] as u16) << 4 | (src[2] >> 4) as u16;
+    (a, b)
+}
+
+// The block kernels treat each group of three bytes as the big-endian 24-bit word
+// `t = a[11:4] b[11:4] b[3:0] a[3:0]`, so that `b` is `t[15:4]` and `a` is `t[23:12]`
+// with its low nibble taken from `t[3:0]`. Eight values are moved through two 64-bit
+// loads or stores, instead of twelve byte accesses.
+
+/// Packs a pair of values into a 24-bit word.
+#[inline(always)]
+fn pair_to_word(a: u16, b: u16) -> u64 {
+    ((a & 0xFF0) as u64) << 12 | (b as u64) << 4 | (a & 0xF) as u64
+}
+
+/// Unpacks a pair of values from a 24-bit word.
+#[inline(always)]
+fn word_to_pair(t: u64) -> (u16, u16) {
+    ((t >> 12 & 0xFF0 | t & 0xF) as u16, (t >> 4 & 0xFFF) as u16)
+}
+
+/// Packs eight values into twelve bytes.
+#[inline(always)]
+fn pack_block(src: &[U12], dst: &mut [u8]) {
+    let t0 = pair_to_word(src[0].0, src[1].0);
+    let t1 = pair_to_word(src[2].0, src[3].0);
+    let t2 = pair_to_word(src[4].0, src[5].0);
+    let t3 = pair_to_word(src[6].0, src[7].0);
+    let head = t0 << 40 | t1 << 16 | t2 >> 8;
+    let tail = (t2 as u32 & 0xFF) << 24 | t3 as u32;
+    dst[..8].copy_from_slice(&head.to_be_bytes());
+    dst[8..12].copy_from_slice(&tail.to_be_bytes());
+}
+
+/// Unpacks eight values from twelve bytes.
+#[inline(always)]
+fn unpack_block(src: &[u8], dst: &mut [U12]) {
+    let mut head = [0; 8];
+    let mut tail = [0; 8];
+    head.copy_from_slice(&src[..8]);
+    tail.copy_from_slice(&src[4..12]);
+    let head = u64::from_be_bytes(head);
+    let tail = u64::from_be_bytes(tail);
+    let words = [head >> 40, head >> 16, tail >> 24, tail];
+    for (out, &t) in dst.chunks_exact_mut(2).zip(words.iter()) {
+        let (a, b) = word_to_pair(t & 0xFF_FFFF);
+        out[0] = U12(a);
+        out[1] = U12(b);
+    }
+}
+
+/// Packs `src` into `dst` using the RAW12 layout described in the module documentation.
+///
+/// # Panics
+/// This function will panic if `dst.len()` is not `packed_len(src.len())`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::packing::pack_slice;
+/// # fn main() {
+/// let mut packed = [0u8; 5];
+/// pack_slice(&[u12![0xABC], u12![0x123], u12![0xFED]], &mut packed);
+/// assert_eq!(packed, [0xAB, 0x12, 0x3C, 0xFE, 0x0D]);
+/// # }
+/// ```
+pub fn pack_slice(src: &[U12], dst: &mut [u8]) {
+    assert_eq!(dst.len(), packed_len(src.len()), "packed length mismatch");
+
+    let mut values = src.chunks_exact(BLOCK_VALUES);
+    let mut bytes = dst.chunks_exact_mut(BLOCK_BYTES);
+    for (block, out) in (&mut values).zip(&mut bytes) {
+        pack_block(block, out);
+    }
+
+    let values = values.remainder();
+    let bytes = bytes.into_remainder();
+    let mut pairs = values.chunks_exact(2);
+    let mut outs = bytes.chunks_exact_mut(3);
+    for (pair, out) in (&mut pairs).zip(&mut outs) {
+        pack_pair(pair[0].0, pair[1].0, out);
+    }
+
+    if let [last] = pairs.remainder() {
+        let out = outs.into_remainder();
+        out[0] = (last.0 >> 4) as u8;
+        out[1] = (last.0 & 0xF) as u8;
+    }
+}
+
+/// Unpacks `src`, in the RAW12 layout described in the module documentation, into `dst`.
+/// Bits of the packed data which do not belong to any value (the high nibble of the final
+/// byte when `dst` has an odd length) are ignored.
+///
+/// # Panics
+/// This function will panic if `src.len()` is not `packed_len(dst.len())`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::packing::unpack_slice;
+/// # fn main() {
+/// let mut values = [U12::min_value(); 3];
+/// unpack_slice(&[0xAB, 0x12, 0x3C, 0xFE, 0x0D], &mut values);
+/// assert_eq!(values, [u12![0xABC], u12![0x123], u12![0xFED]]);
+/// # }
+/// ```
+pub fn unpack_slice(src: &[u8], dst: &mut [U12]) {
+    assert_eq!(src.len(), packed_len(dst.len()), "packed length mismatch");
+
+    let mut bytes = src.chunks_exact(BLOCK_BYTES);
+    let mut values = dst.chunks_exact_mut(BLOCK_VALUES);
+    for (block, out) in (&mut bytes).zip(&mut values) {
+        unpack_block(block, out);
+    }
+
+    let bytes = bytes.remainder();
+    let values = values.into_remainder();
+    let mut triples = bytes.chunks_exact(3);
+    let mut outs = values.chunks_exact_mut(2);
+    for (triple, out) in (&mut triples).zip(&mut outs) {
+        let (a, b) = unpack_pair(triple);
+        out[0] = U12(a);
+        out[1] = U12(b);
+    }
+
+    if let [last] = outs.into_remainder() {
+        let tail = triples.remainder();
+        *last = U12((tail[0] as u16) << 4 | (tail[1] & 0xF) as u16);
+    }
+}
+
+/// Packs five values into a `u64`, with value `i` in bits `12i...12i+11`. The top four
+/// bits of the result are zero.
+///
+/// ```text
+/// bits 63-60: 0
+/// bits 59-48: values[4]
+/// bits 47-36: values[3]
+/// bits 35-24: values[2]
+/// bits 23-12: values[1]
+/// bits 11-0:  values[0]
+/// ```
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::packing::{pack5_into_u64, unpack5_from_u64};
+/// # fn main() {
+/// let values = [u12![0x111], u12![0x222], u12![0x333], u12![0x444], u12![0xFFF]];
+/// assert_eq!(pack5_into_u64(values), 0x0FFF_4443_3322_2111);
+/// assert_eq!(unpack5_from_u64(0x0FFF_4443_3322_2111), values);
+/// # }
+/// ```
+pub const fn pack5_into_u64(values: [U12; 5]) -> u64 {
+    let mut word = 0;
+    let mut index = 0;
+    while index < 5 {
+        word |= (values[index].0 as u64) << (12 * index);
+        index += 1;
+    }
+    word
+}
+
+/// Unpacks five values from a `u64` in the layout of `pack5_into_u64`. The top four bits
+/// of `word` are ignored.
+pub const fn unpack5_from_u64(word: u64) -> [U12; 5] {
+    let mut values = [U12(0); 5];
+    let mut index = 0;
+    while index < 5 {
+        values[index] = U12((word >> (12 * index)) as u16 & 0xFFF);
+        index += 1;
+    }
+    values
+}
+
+/// Packs ten values into a `u128`, with value `i` in bits `12i...12i+11`. The top eight
+/// bits of the result are zero.
+///
+/// ```text
+/// bits 127-120: 0
+/// bits 119-108: values[9]
+/// ...
+/// bits 23-12:   values[1]
+/// bits 11-0:    values[0]
+/// ```
+pub const fn pack10_into_u128(values: [U12; 10]) -> u128 {
+    let mut word = 0;
+    let mut index = 0;
+    while index < 10 {
+        word |= (values[index].0 as u128) << (12 * index);
+        index += 1;
+    }
+    word
+}
+
+/// Unpacks ten values from a `u128` in the layout of `pack10_into_u128`. The top eight
+/// bits of `word` are ignored.
+pub const fn unpack10_from_u128(word: u128) -> [U12; 10] {
+    let mut values = [U12(0); 10];
+    let mut index = 0;
+    while index < 10 {
+        values[index] = U12((word >> (12 * index)) as u16 & 0xFFF);
+        index += 1;
+    }
+    values
+}
+
+/// A fixed-capacity array of `N` values, stored inline in the layout described in the module
+/// documentation. `BYTES` must be `packed_len(N)`; since stable Rust cannot yet compute an
+/// array length from a generic parameter, it is spelled out at the use site, as in
+/// `PackedU12Array<5, { packed_len(5) }>`, and checked at compile time.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::packing::{packed_len, PackedU12Array};
+/// # fn main() {
+/// let mut array = PackedU12Array::<3, { packed_len(3) }>::new();
+/// array.set(0, u12![0xABC]);
+/// array.set(2, u12![0xFED]);
+/// assert_eq!(array.get(0), Some(u12![0xABC]));
+/// assert_eq!(array.get(3), None);
+/// assert_eq!(array.as_bytes(), &[0xAB, 0x00, 0x0C, 0xFE, 0x0D]);
+/// assert_eq!(array.iter().collect::<Vec<_>>(), [u12![0xABC], u12![0], u12![0xFED]]);
+/// # }
+/// ```
+///
+/// The following fails to compile because `BYTES` is not `packed_len(N)`:
+///
+/// ```compile_fail
+/// use twelve_bit::u12::packing::PackedU12Array;
+///
+/// let _ = PackedU12Array::<3, 4>::new();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedU12Array<const N: usize, const BYTES: usize> {
+    bytes: [u8; BYTES],
+}
+
+impl<const N: usize, const BYTES: usize> PackedU12Array<N, BYTES> {
+    /// Fails to compile unless `BYTES` is `packed_len(N)`.
+    const VALID_LENGTH: () = assert!(BYTES == packed_len(N), "BYTES must be packed_len(N)");
+
+    /// Creates an array with every value set to zero.
+    pub const fn new() -> Self {
+        let () = Self::VALID_LENGTH;
+        PackedU12Array { bytes: [0; BYTES] }
+    }
+
+    /// Creates an array holding `values`.
+    pub fn from_values(values: [U12; N]) -> Self {
+        let mut array = Self::new();
+        pack_slice(&values, &mut array.bytes);
+        array
+    }
+
+    /// Creates an array from its packed representation, as returned by `as_bytes()`. Bits
+    /// which do not belong to any value are cleared.
+    pub const fn from_bytes(mut bytes: [u8; BYTES]) -> Self {
+        let () = Self::VALID_LENGTH;
+        if N % 2 == 1 {
+            bytes[BYTES - 1] &= 0xF;
+        }
+        PackedU12Array { bytes }
+    }
+
+    /// Returns the number of values in the array, `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the array holds no values.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the value at `index`, or `None` if `index` is out of bounds.
+    pub const fn get(&self, index: usize) -> Option<U12> {
+        if index >= N {
+            return None;
+        }
+        let offset = index / 2 * 3;
+        let value = if index % 2 == 1 {
+            (self.bytes[offset + 1] as u16) << 4 | (self.bytes[offset + 2] >> 4) as u16
+        } else if index == N - 1 {
+            (self.bytes[offset] as u16) << 4 | (self.bytes[offset + 1] & 0xF) as u16
+        } else {
+            (self.bytes[offset] as u16) << 4 | (self.bytes[offset + 2] & 0xF) as u16
+        };
+        Some(U12(value))
+    }
+
+    /// Sets the value at `index` to `value`.
+    ///
+    /// # Panics
+    /// This method will panic if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: U12) {
+        assert!(index < N, "index out of bounds");
+        let offset = index / 2 * 3;
+        let (high, low) = ((value.0 >> 4) as u8, (value.0 & 0xF) as u8);
+        if index % 2 == 1 {
+            self.bytes[offset + 1] = high;
+            self.bytes[offset + 2] = self.bytes[offset + 2] & 0x0F | low << 4;
+        } else if index == N - 1 {
+            self.bytes[offset] = high;
+            self.bytes[offset + 1] = low;
+        } else {
+            self.bytes[offset] = high;
+            self.bytes[offset + 2] = self.bytes[offset + 2] & 0xF0 | low;
+        }
+    }
+
+    /// Returns an iterator over the values of the array.
+    pub fn iter(&self) -> PackedU12ArrayIter<'_, N, BYTES> {
+        PackedU12ArrayIter {
+            array: self,
+            index: 0,
+        }
+    }
+
+    /// Returns all values of the array, unpacked.
+    pub fn to_values(&self) -> [U12; N] {
+        let mut values = [U12(0); N];
+        unpack_slice(&self.bytes, &mut values);
+        values
+    }
+
+    /// Returns the packed representation of the array.
+    pub const fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.bytes
+    }
+}
+
+impl<const N: usize, const BYTES: usize> Default for PackedU12Array<N, BYTES> {
+    fn default() -> Self {
+        PackedU12Array::new()
+    }
+}
+
+impl<'a, const N: usize, const BYTES: usize> IntoIterator for &'a PackedU12Array<N, BYTES> {
+    type Item = U12;
+    type IntoIter = PackedU12ArrayIter<'a, N, BYTES>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a `PackedU12Array`.
+/// This is created by the `PackedU12Array::iter()` method.
+#[derive(Debug, Clone)]
+pub struct PackedU12ArrayIter<'a, const N: usize, const BYTES: usize> {
+    array: &'a PackedU12Array<N, BYTES>,
+    index: usize,
+}
+
+impl<'a, const N: usize, const BYTES: usize> Iterator for PackedU12ArrayIter<'a, N, BYTES> {
+    type Item = U12;
+
+    fn next(&mut self) -> Option<U12> {
+        let value = self.array.get(self.index)?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = N - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, const N: usize, const BYTES: usize> ExactSizeIterator
+    for PackedU12ArrayIter<'a, N, BYTES>
+{
+}
@@ -0,0 +1,143 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Keyed pseudo-random permutations of the 4096 `U12` values.
+//!
+//! `Permutation12` is a balanced Feistel network over two 6-bit halves. Each round replaces
+//! one half with itself xored with a keyed hash of the other, which is invertible whatever
+//! the hash, so every key yields a bijection without storing a table. The round keys are
+//! derived from a 64-bit key, and the same key always yields the same permutation on every
+//! platform.
+//!
+//! The permutation is suitable for scrambling addresses, shuffling iteration order, and
+//! generating test vectors. It is not a cipher, and offers no cryptographic security.
+
+use super::U12;
+
+/// The number of Feistel rounds.
+const ROUNDS: usize = 6;
+
+/// A keyed bijection from `U12` to `U12`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::permutation::Permutation12;
+/// # fn main() {
+/// let permutation = Permutation12::new(0x5EED);
+/// let scrambled = permutation.apply(u12![42]);
+/// assert_eq!(permutation.invert(scrambled), u12![42]);
+///
+/// let mut shuffled: Vec<U12> = permutation.iter().collect();
+/// shuffled.sort();
+/// assert_eq!(shuffled, (0..4096).map(U12::from_u16).collect::<Vec<_>>());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation12 {
+    round_keys: [u32; ROUNDS],
+}
+
+impl Permutation12 {
+    /// Creates the permutation selected by `key`.
+    pub const fn new(key: u64) -> Self {
+        // Expand the key with SplitMix64, so that similar keys give unrelated round keys.
+        let mut state = key;
+        let mut round_keys = [0; ROUNDS];
+        let mut round = 0;
+        while round < ROUNDS {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            round_keys[round] = (z ^ (z >> 31)) as u32;
+            round += 1;
+        }
+        Permutation12 { round_keys }
+    }
+
+    /// Returns the image of `value` under the permutation.
+    pub const fn apply(&self, value: U12) -> U12 {
+        let mut left = value.0 >> 6;
+        let mut right = value.0 & 0x3F;
+        let mut round = 0;
+        while round < ROUNDS {
+            let next = left ^ round_function(right, self.round_keys[round]);
+            left = right;
+            right = next;
+            round += 1;
+        }
+        U12(left << 6 | right)
+    }
+
+    /// Returns the value whose image under the permutation is `value`, so that
+    /// `permutation.invert(permutation.apply(x)) == x` for every `x`.
+    pub const fn invert(&self, value: U12) -> U12 {
+        let mut left = value.0 >> 6;
+        let mut right = value.0 & 0x3F;
+        let mut round = ROUNDS;
+        while round > 0 {
+            round -= 1;
+            let previous = right ^ round_function(left, self.round_keys[round]);
+            right = left;
+            left = previous;
+        }
+        U12(left << 6 | right)
+    }
+
+    /// Returns an iterator over the images of `0`, `1`, ..., `4095`, which visits every value
+    /// exactly once in shuffled order.
+    pub fn iter(&self) -> PermutationIter<'_> {
+        PermutationIter {
+            permutation: self,
+            next: 0,
+        }
+    }
+}
+
+/// An iterator over every value in the order given by a permutation.
+/// This is created by the `Permutation12::iter()` method.
+#[derive(Debug, Clone)]
+pub struct PermutationIter<'a> {
+    permutation: &'a Permutation12,
+    next: u16,
+}
+
+impl<'a> Iterator for PermutationIter<'a> {
+    type Item = U12;
+
+    fn next(&mut self) -> Option<U12> {
+        if self.next > 0xFFF {
+            None
+        } else {
+            let value = self.permutation.apply(U12(self.next));
+            self.next += 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = 0x1000 - self.next as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for PermutationIter<'a> {}
+
+/// Returns a 6-bit keyed hash of the 6-bit `half`.
+const fn round_function(half: u16, key: u32) -> u16 {
+    let mut x = (half as u32 ^ key).wrapping_mul(0x2C1B_3C6D);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x297A_2D39);
+    x ^= x >> 16;
+    (x & 0x3F) as u16
+}
@@ -0,0 +1,33 @@
+//! Random generation of `U12` values, enabled by the `rand` feature.
+//!
+//! `StandardUniform` samples values uniformly from the whole 12-bit range, and `Fill` allows
+//! filling slices of values efficiently, e.g. `rng.fill(&mut values[..])`.
+
+use super::U12;
+extern crate rand;
+
+use self::rand::distr::{Distribution, StandardUniform};
+use self::rand::{Fill, Rng};
+
+/// The number of 12-bit values drawn from each random 64-bit word.
+const VALUES_PER_WORD: usize = 5;
+
+impl Distribution<U12> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> U12 {
+        U12((rng.next_u32() & 0xFFF) as u16)
+    }
+}
+
+/// Fills slices with values sampled uniformly from the whole 12-bit range. Each random
+/// 64-bit word supplies five values, rather than drawing a word per value.
+impl Fill for U12 {
+    fn fill_slice<R: Rng + ?Sized>(this: &mut [Self], rng: &mut R) {
+        for chunk in this.chunks_mut(VALUES_PER_WORD) {
+            let mut word = rng.next_u64();
+            for value in chunk {
+                *value = U12((word & 0xFFF) as u16);
+                word >>= 12;
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Parallel bulk operations on `U12` buffers, enabled by the `rayon` feature.
+//!
+//! Each routine splits its buffers into large chunks with `par_chunks` and runs the
+//! corresponding sequential routine on every chunk, so results are identical to the
+//! sequential versions. The free functions are re-exported from `packing` and `dither`.
+//! Floyd–Steinberg dithering carries error from row to row, and has no parallel version.
+
+use super::dither::{check_buffers, ordered_row};
+use super::histogram::Histogram12;
+use super::lut::Lut12;
+use super::packing::{pack_slice, packed_len, unpack_slice};
+use super::U12;
+extern crate rayon;
+
+use self::rayon::prelude::*;
+
+/// The number of values processed by each parallel task. This is even, so that chunks of
+/// values always pack into whole chunks of bytes.
+const CHUNK_VALUES: usize = 64 * 1024;
+
+/// Packs `src` into `dst` in parallel, with the same result as `pack_slice`.
+///
+/// # Panics
+/// This function will panic if `dst.len()` is not `packed_len(src.len())`.
+pub fn par_pack_slice(src: &[U12], dst: &mut [u8]) {
+    assert_eq!(dst.len(), packed_len(src.len()), "packed length mismatch");
+    src.par_chunks(CHUNK_VALUES)
+        .zip(dst.par_chunks_mut(packed_len(CHUNK_VALUES)))
+        .for_each(|(values, bytes)| pack_slice(values, bytes));
+}
+
+/// Unpacks `src` into `dst` in parallel, with the same result as `unpack_slice`.
+///
+/// # Panics
+/// This function will panic if `src.len()` is not `packed_len(dst.len())`.
+pub fn par_unpack_slice(src: &[u8], dst: &mut [U12]) {
+    assert_eq!(src.len(), packed_len(dst.len()), "packed length mismatch");
+    src.par_chunks(packed_len(CHUNK_VALUES))
+        .zip(dst.par_chunks_mut(CHUNK_VALUES))
+        .for_each(|(bytes, values)| unpack_slice(bytes, values));
+}
+
+/// Converts the rows in `src` to 8 bits in `dst` in parallel, with the same result as
+/// `ordered`.
+///
+/// # Panics
+/// This function will panic if `width` is zero, if `src` and `dst` have different lengths,
+/// or if their length is not a multiple of `width`.
+pub fn par_ordered(src: &[U12], dst: &mut [u8], width: usize) {
+    check_buffers(src, dst, width);
+    src.par_chunks(width)
+        .zip(dst.par_chunks_mut(width))
+        .enumerate()
+        .for_each(|(y, (src_row, dst_row))| ordered_row(src_row, dst_row, y));
+}
+
+impl Lut12 {
+    /// Replaces every value in `values` with its output in parallel, with the same result
+    /// as `apply_slice`.
+    pub fn par_apply_slice(&self, values: &mut [U12]) {
+        values
+            .par_chunks_mut(CHUNK_VALUES)
+            .for_each(|chunk| self.apply_slice(chunk));
+    }
+}
+
+impl Histogram12 {
+    /// Records one occurrence of each value in `values` in parallel, with the same result
+    /// as `record_slice`.
+    pub fn par_record_slice(&mut self, values: &[U12]) {
+        let partial = values
+            .par_chunks(CHUNK_VALUES)
+            .fold(Histogram12::new, |mut histogram, chunk| {
+                histogram.record_slice(chunk);
+                histogram
+            })
+            .reduce(Histogram12::new, |mut a, b| {
+                a.merge(&b);
+                a
+            });
+        self.merge(&partial);
+    }
+}
@@ -0,0 +1,85 @@
+//! Zero-copy serialization with rkyv, enabled by the `rkyv` feature.
+//!
+//! `U12` archives as `ArchivedU12`, which stores the value in an archived `u16`. Validating
+//! an archive with `bytecheck` rejects any archived value above `0xFFF`, so a validated
+//! `ArchivedU12` can be converted back to a `U12` without further checks.
+
+use std::error::Error;
+use std::fmt;
+
+use super::U12;
+extern crate rkyv;
+
+use self::rkyv::bytecheck::CheckBytes;
+use self::rkyv::primitive::ArchivedU16;
+use self::rkyv::rancor::{fail, Fallible, Source};
+use self::rkyv::traits::NoUndef;
+use self::rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+/// The archived form of a `U12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ArchivedU12(ArchivedU16);
+
+impl ArchivedU12 {
+    /// Returns the archived value as a `U12`.
+    pub fn to_native(self) -> U12 {
+        U12(self.0.to_native())
+    }
+}
+
+// SAFETY: `ArchivedU12` is a transparent wrapper around `ArchivedU16`, which is portable
+// and has no padding bytes.
+unsafe impl Portable for ArchivedU12 {}
+unsafe impl NoUndef for ArchivedU12 {}
+
+/// The error returned when validating an archived value above `0xFFF`.
+#[derive(Debug)]
+struct OutOfRangeError(u16);
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "archived value {:#x} does not fit in 12 bits", self.0)
+    }
+}
+
+impl Error for OutOfRangeError {}
+
+// SAFETY: every bit pattern of the inner `ArchivedU16` is valid, and `check_bytes` only
+// succeeds if the value also lies within the 12-bit range.
+unsafe impl<C> CheckBytes<C> for ArchivedU12
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees that `value` is aligned and points to enough
+        // initialized bytes, and any initialized bytes form a valid `ArchivedU16`.
+        let value = unsafe { (*value).0.to_native() };
+        if value > 0xFFF {
+            fail!(OutOfRangeError(value));
+        }
+        Ok(())
+    }
+}
+
+impl Archive for U12 {
+    type Archived = ArchivedU12;
+    type Resolver = ();
+
+    fn resolve(&self, _: (), out: Place<ArchivedU12>) {
+        out.write(ArchivedU12(ArchivedU16::from_native(self.0)));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for U12 {
+    fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<U12, D> for ArchivedU12 {
+    fn deserialize(&self, _: &mut D) -> Result<U12, D::Error> {
+        Ok(self.to_native())
+    }
+}
@@ -0,0 +1,33 @@
+//! JSON Schema support for `U12`, enabled by the `schemars` feature.
+//!
+//! `U12` is described by an inline integer schema bounded to the 12-bit range, matching the
+//! way it serializes with serde.
+
+use std::borrow::Cow;
+
+use super::U12;
+extern crate schemars;
+
+use self::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+impl JsonSchema for U12 {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("U12")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("twelve_bit::u12::U12")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 4095
+        })
+    }
+}
@@ -1,3 +1,7 @@
+//! Serde support for `U12`, enabled by the `serde` feature.
+//!
+//! `U12` serializes as a `u16` and deserializes from any integer in range.
+
 use super::U12;
 extern crate serde;
 
@@ -112,3 +116,147 @@ impl<'de> serde::Deserialize<'de> for U12 {
         deserializer.deserialize_u16(U12Visitor)
     }
 }
+
+/// Serialization of `U12` as a hexadecimal string in human-readable formats.
+///
+/// Use this module with `#[serde(with = "twelve_bit::serde::hex")]` on a `U12` field. In
+/// human-readable formats such as JSON or TOML the value is written as a string of exactly
+/// three uppercase hexadecimal digits, e.g. `"0A5"`, and read back from a string of one to
+/// three hexadecimal digits with an optional `0x` prefix. Binary formats keep the compact
+/// integer representation used by the `Serialize` and `Deserialize` implementations of `U12`.
+pub mod hex {
+    use super::serde;
+    use super::U12Visitor;
+    use u12::U12;
+
+    /// Serializes `value` as a three-digit hexadecimal string if the format is
+    /// human-readable, or as an integer otherwise.
+    pub fn serialize<S>(value: &U12, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{:03X}", value))
+        } else {
+            serde::Serialize::serialize(value, serializer)
+        }
+    }
+
+    /// Deserializes a value from a hexadecimal string if the format is human-readable,
+    /// or from an integer otherwise.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<U12, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor)
+        } else {
+            deserializer.deserialize_u16(U12Visitor)
+        }
+    }
+
+    struct HexVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for HexVisitor {
+        type Value = U12;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a hexadecimal string between 000 and FFF")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            let digits = value
+                .strip_prefix("0x")
+                .or_else(|| value.strip_prefix("0X"))
+                .unwrap_or(value);
+            if digits.is_empty() || digits.len() > 3 || digits.starts_with('+') {
+                return Err(E::invalid_value(serde::de::Unexpected::Str(value), &self));
+            }
+            U12::from_str_radix(digits, 16)
+                .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+/// Serialization of sequences of `U12` as tightly packed bytes.
+///
+/// Use this module with `#[serde(with = "twelve_bit::serde::packed")]` on a `Vec<U12>`
+/// field. The values are packed into a byte array using the layout of
+/// `twelve_bit::u12::packing`, taking 1.5 bytes per value instead of the two or more
+/// taken by a sequence of integers. The number of values is recovered from the length
+/// of the byte array.
+pub mod packed {
+    use super::serde;
+    use u12::packing::{pack_slice, packed_len, unpack_slice};
+    use u12::U12;
+
+    /// Serializes `values` as a packed byte array.
+    pub fn serialize<S>(values: &[U12], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut bytes = vec![0u8; packed_len(values.len())];
+        pack_slice(values, &mut bytes);
+        serializer.serialize_bytes(&bytes)
+    }
+
+    /// Deserializes values from a packed byte array.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<U12>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(PackedVisitor)
+    }
+
+    /// Returns the number of values packed into `byte_count` bytes, or `None` if no
+    /// number of values packs into exactly that many bytes.
+    fn unpacked_len(byte_count: usize) -> Option<usize> {
+        match byte_count % 3 {
+            0 => Some(byte_count / 3 * 2),
+            2 => Some(byte_count / 3 * 2 + 1),
+            _ => None,
+        }
+    }
+
+    /// The largest number of bytes preallocated from an untrusted sequence length hint.
+    const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+    struct PackedVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for PackedVisitor {
+        type Value = Vec<U12>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a byte array of packed 12-bit values")
+        }
+
+        fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            match unpacked_len(bytes.len()) {
+                Some(count) => {
+                    let mut values = vec![U12::min_value(); count];
+                    unpack_slice(bytes, &mut values);
+                    Ok(values)
+                }
+                None => Err(E::invalid_length(bytes.len(), &self)),
+            }
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOC_BYTES);
+            let mut bytes = Vec::with_capacity(capacity);
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Encoding of `U12` values for seven-segment displays.
+//!
+//! Each digit is a bitmask of its lit segments, with segment `a` in bit 0 through segment
+//! `g` in bit 6. Bit 7, the decimal point, is always clear. Digits are ordered most
+//! significant first, ready to be shifted out to a multiplexed display.
+//!
+//! ```text
+//!  aaa
+//! f   b
+//!  ggg
+//! e   c
+//!  ddd
+//! ```
+//!
+//! A set bit means a lit segment, as on common-cathode displays. Use `Polarity::apply()` to
+//! invert the masks for common-anode displays, whose segments light when driven low.
+
+use super::U12;
+
+/// The segment masks of the hexadecimal digits `0` to `F`, with `B` and `D` in lowercase
+/// so they can be told apart from `8` and `0`.
+pub const HEX_DIGITS: [u8; 16] = [
+    0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F, 0x77, 0x7C, 0x39, 0x5E, 0x79, 0x71,
+];
+
+/// The electrical polarity of a seven-segment display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarity {
+    /// Segments light when driven high. Masks are used unchanged.
+    CommonCathode,
+    /// Segments light when driven low. Masks are inverted.
+    CommonAnode,
+}
+
+impl Polarity {
+    /// Returns `digits` with the levels required to light their segments on a display of
+    /// this polarity.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::seven_segment::Polarity;
+    /// # fn main() {
+    /// let digits = u12![0x1AF].to_seven_segment();
+    /// assert_eq!(Polarity::CommonAnode.apply(digits), [0xF9, 0x88, 0x8E]);
+    /// # }
+    /// ```
+    pub const fn apply<const N: usize>(self, mut digits: [u8; N]) -> [u8; N] {
+        if let Polarity::CommonAnode = self {
+            let mut index = 0;
+            while index < N {
+                digits[index] = !digits[index];
+                index += 1;
+            }
+        }
+        digits
+    }
+}
+
+impl U12 {
+    /// Returns the segment masks of the three hexadecimal digits of `self`, most significant
+    /// first.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![0x1AF].to_seven_segment(), [0x06, 0x77, 0x71]);
+    /// # }
+    /// ```
+    pub const fn to_seven_segment(self) -> [u8; 3] {
+        [
+            HEX_DIGITS[(self.0 >> 8) as usize],
+            HEX_DIGITS[(self.0 >> 4 & 0xF) as usize],
+            HEX_DIGITS[(self.0 & 0xF) as usize],
+        ]
+    }
+
+    /// Returns the segment masks of the four decimal digits of `self`, most significant
+    /// first, including leading zeros.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// # fn main() {
+    /// assert_eq!(u12![407].to_seven_segment_decimal(), [0x3F, 0x66, 0x3F, 0x07]);
+    /// # }
+    /// ```
+    pub const fn to_seven_segment_decimal(self) -> [u8; 4] {
+        [
+            HEX_DIGITS[(self.0 / 1000) as usize],
+            HEX_DIGITS[(self.0 / 100 % 10) as usize],
+            HEX_DIGITS[(self.0 / 10 % 10) as usize],
+            HEX_DIGITS[(self.0 % 10) as usize],
+        ]
+    }
+}
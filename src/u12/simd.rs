@@ -0,0 +1,270 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Fixed-width vectors of `U12` values for explicit SIMD-style kernels.
+//!
+//! `U12x8` and `U12x4` hold eight and four values in aligned `u16` lanes. Every operation is
+//! a branch-free loop over all lanes, which the optimizer compiles to single vector
+//! instructions on targets with 128-bit and 64-bit SIMD registers.
+//!
+//! Comparisons produce a lane mask of `bool`s. There are no masked variants of the other
+//! operations: `select` is the masking primitive, so a masked operation is computed on every
+//! lane and then blended with the original vector.
+//!
+//! ```rust
+//! # #[macro_use] extern crate twelve_bit;
+//! use twelve_bit::u12::*;
+//! use twelve_bit::u12::simd::U12x4;
+//! # fn main() {
+//! let values = U12x4::from_array([u12![10], u12![3000], u12![20], u12![4000]]);
+//!
+//! // Subtract 1000 only from the lanes above 2048.
+//! let mask = values.simd_gt(U12x4::splat(u12![2048]));
+//! let lowered = values.wrapping_sub(U12x4::splat(u12![1000]));
+//! let result = U12x4::select(mask, lowered, values);
+//! assert_eq!(result.to_array(), [u12![10], u12![2000], u12![20], u12![3000]]);
+//! # }
+//! ```
+
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use super::U12;
+
+/// The mask selecting the 12 value bits of the underlying `u16`.
+const LANE_MASK: u16 = 0xFFF;
+
+///
+/// Defines a vector type `$name` of `$lanes` values, aligned to `$align` bytes, along with
+/// its constructors, lane-wise arithmetic, comparisons and reductions.
+///
+macro_rules! define_vector_type {
+    ($(#[$attr:meta])* $name:ident, $lanes:expr, $align:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        #[repr(C, align($align))]
+        pub struct $name([u16; $lanes]);
+
+        impl $name {
+            /// The number of lanes.
+            pub const LANES: usize = $lanes;
+
+            /// Returns a vector with every lane set to `value`.
+            pub const fn splat(value: U12) -> Self {
+                $name([value.0; $lanes])
+            }
+
+            /// Returns a vector holding `values`.
+            pub const fn from_array(values: [U12; $lanes]) -> Self {
+                let mut lanes = [0; $lanes];
+                let mut index = 0;
+                while index < $lanes {
+                    lanes[index] = values[index].0;
+                    index += 1;
+                }
+                $name(lanes)
+            }
+
+            /// Returns the lanes of the vector as an array.
+            pub const fn to_array(self) -> [U12; $lanes] {
+                let mut values = [U12(0); $lanes];
+                let mut index = 0;
+                while index < $lanes {
+                    values[index] = U12(self.0[index]);
+                    index += 1;
+                }
+                values
+            }
+
+            /// Loads a vector from the first `LANES` values of `values`.
+            ///
+            /// # Panics
+            /// This method will panic if `values` holds fewer than `LANES` values.
+            pub fn from_slice(values: &[U12]) -> Self {
+                assert!(values.len() >= $lanes, "slice is shorter than the vector");
+                let mut lanes = [0; $lanes];
+                for (lane, value) in lanes.iter_mut().zip(values) {
+                    *lane = value.0;
+                }
+                $name(lanes)
+            }
+
+            /// Stores the vector into the first `LANES` values of `values`.
+            ///
+            /// # Panics
+            /// This method will panic if `values` holds fewer than `LANES` values.
+            pub fn write_to_slice(self, values: &mut [U12]) {
+                assert!(values.len() >= $lanes, "slice is shorter than the vector");
+                for (value, &lane) in values.iter_mut().zip(self.0.iter()) {
+                    value.0 = lane;
+                }
+            }
+
+            /// Returns the value of lane `index`.
+            ///
+            /// # Panics
+            /// This method will panic if `index` is not less than `LANES`.
+            pub const fn lane(self, index: usize) -> U12 {
+                U12(self.0[index])
+            }
+
+            define_vector_type!(@lane_op
+                /// Lane-wise wrapping (modular) addition.
+                wrapping_add, |a, b| (a + b) & LANE_MASK);
+            define_vector_type!(@lane_op
+                /// Lane-wise wrapping (modular) subtraction.
+                wrapping_sub, |a, b| a.wrapping_sub(b) & LANE_MASK);
+            define_vector_type!(@lane_op
+                /// Lane-wise wrapping (modular) multiplication.
+                wrapping_mul, |a, b| a.wrapping_mul(b) & LANE_MASK);
+            define_vector_type!(@lane_op
+                /// Lane-wise saturating addition.
+                saturating_add, |a, b| (a + b).min(LANE_MASK));
+            define_vector_type!(@lane_op
+                /// Lane-wise saturating subtraction.
+                saturating_sub, |a, b| a.saturating_sub(b));
+            define_vector_type!(@lane_op
+                /// Lane-wise minimum.
+                min, |a, b| a.min(b));
+            define_vector_type!(@lane_op
+                /// Lane-wise maximum.
+                max, |a, b| a.max(b));
+            define_vector_type!(@compare
+                /// Returns a mask of the lanes where `self` equals `other`.
+                simd_eq, ==);
+            define_vector_type!(@compare
+                /// Returns a mask of the lanes where `self` differs from `other`.
+                simd_ne, !=);
+            define_vector_type!(@compare
+                /// Returns a mask of the lanes where `self` is less than `other`.
+                simd_lt, <);
+            define_vector_type!(@compare
+                /// Returns a mask of the lanes where `self` is less than or equal to `other`.
+                simd_le, <=);
+            define_vector_type!(@compare
+                /// Returns a mask of the lanes where `self` is greater than `other`.
+                simd_gt, >);
+            define_vector_type!(@compare
+                /// Returns a mask of the lanes where `self` is greater than or equal to
+                /// `other`.
+                simd_ge, >=);
+
+            /// Returns a vector taking each lane from `if_true` where `mask` is set, and
+            /// from `if_false` elsewhere. This is how a comparison mask is applied to the
+            /// result of any other operation.
+            pub fn select(mask: [bool; $lanes], if_true: Self, if_false: Self) -> Self {
+                let mut lanes = if_false.0;
+                for ((lane, &set), &value) in lanes.iter_mut().zip(mask.iter()).zip(if_true.0.iter()) {
+                    *lane = if set { value } else { *lane };
+                }
+                $name(lanes)
+            }
+
+            /// Returns the sum of all lanes. This cannot overflow.
+            pub fn reduce_sum(self) -> u32 {
+                self.0.iter().map(|&lane| lane as u32).sum()
+            }
+
+            /// Returns the smallest lane.
+            pub fn reduce_min(self) -> U12 {
+                U12(self.0.iter().fold(LANE_MASK, |min, &lane| min.min(lane)))
+            }
+
+            /// Returns the largest lane.
+            pub fn reduce_max(self) -> U12 {
+                U12(self.0.iter().fold(0, |max, &lane| max.max(lane)))
+            }
+        }
+
+        define_vector_type!(@bit_op $name, BitAnd, bitand, &=);
+        define_vector_type!(@bit_op $name, BitOr, bitor, |=);
+        define_vector_type!(@bit_op $name, BitXor, bitxor, ^=);
+
+        impl From<[U12; $lanes]> for $name {
+            fn from(values: [U12; $lanes]) -> Self {
+                $name::from_array(values)
+            }
+        }
+
+        impl From<$name> for [U12; $lanes] {
+            fn from(vector: $name) -> Self {
+                vector.to_array()
+            }
+        }
+    };
+
+    (@lane_op $(#[$attr:meta])* $method:ident, |$a:ident, $b:ident| $lane:expr) => {
+        $(#[$attr])*
+        pub fn $method(self, other: Self) -> Self {
+            let mut lanes = self.0;
+            for (lane, &$b) in lanes.iter_mut().zip(other.0.iter()) {
+                let $a = *lane;
+                *lane = $lane;
+            }
+            Self(lanes)
+        }
+    };
+
+    (@bit_op $name:ident, $trait_name:ident, $method:ident, $assign_op:tt) => {
+        /// Applies the operator lane-wise.
+        impl $trait_name for $name {
+            type Output = $name;
+            fn $method(self, other: $name) -> Self::Output {
+                let mut lanes = self.0;
+                for (lane, &value) in lanes.iter_mut().zip(other.0.iter()) {
+                    *lane $assign_op value;
+                }
+                $name(lanes)
+            }
+        }
+    };
+
+    (@compare $(#[$attr:meta])* $method:ident, $op:tt) => {
+        $(#[$attr])*
+        pub fn $method(self, other: Self) -> [bool; Self::LANES] {
+            let mut mask = [false; Self::LANES];
+            for ((set, &a), &b) in mask.iter_mut().zip(self.0.iter()).zip(other.0.iter()) {
+                *set = a $op b;
+            }
+            mask
+        }
+    };
+}
+
+define_vector_type!(
+    /// A vector of eight `U12` values, in 128 bits of `u16` lanes.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::simd::U12x8;
+    /// # fn main() {
+    /// let samples = [u12![100], u12![4000], u12![7], u12![2048], u12![0], u12![1], u12![2], u12![3]];
+    /// let offset = U12x8::splat(u12![200]);
+    /// let boosted = U12x8::from_slice(&samples).saturating_add(offset);
+    /// assert_eq!(boosted.lane(0), u12![300]);
+    /// assert_eq!(boosted.lane(1), U12::max_value());
+    ///
+    /// let clipped = boosted.simd_eq(U12x8::splat(U12::max_value()));
+    /// assert_eq!(clipped[..2], [false, true]);
+    /// # }
+    /// ```
+    U12x8,
+    8,
+    16
+);
+
+define_vector_type!(
+    /// A vector of four `U12` values, in 64 bits of `u16` lanes.
+    U12x4,
+    4,
+    8
+);
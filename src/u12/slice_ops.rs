@@ -0,0 +1,284 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Bulk arithmetic over slices of `U12` values.
+//!
+//! Every routine here operates directly on the underlying `u16` representation
+//! using branch-free lane operations (add-and-mask, add-and-min), which allows the
+//! optimizer to vectorize the loops. This is considerably faster than applying the
+//! scalar `U12` methods element-by-element, as those re-validate every result.
+
+use super::U12;
+
+/// The mask selecting the 12 value bits of the underlying `u16`.
+const LANE_MASK: u16 = 0xFFF;
+
+// MARK: - Element-Wise Operations
+
+///
+/// Implements an element-wise slice operation of the form `dst[i] = op(dst[i], src[i])`.
+/// The `$lane` expression receives the two underlying `u16` values and must return a value
+/// within the 12-bit range.
+///
+macro_rules! impl_slice_op {
+    ($(#[$attr:meta])* $name:ident, |$a:ident, $b:ident| $lane:expr) => {
+        $(#[$attr])*
+        pub fn $name(dst: &mut [U12], src: &[U12]) {
+            assert_eq!(dst.len(), src.len(), "slice lengths differ");
+            for (d, s) in dst.iter_mut().zip(src.iter()) {
+                let ($a, $b) = (d.0, s.0);
+                d.0 = $lane;
+            }
+        }
+    };
+}
+
+///
+/// Implements a scalar broadcast operation of the form `dst[i] = op(dst[i], scalar)`.
+/// The `$lane` expression receives the two underlying `u16` values and must return a value
+/// within the 12-bit range.
+///
+macro_rules! impl_scalar_op {
+    ($(#[$attr:meta])* $name:ident, |$a:ident, $b:ident| $lane:expr) => {
+        $(#[$attr])*
+        pub fn $name(dst: &mut [U12], scalar: U12) {
+            for d in dst.iter_mut() {
+                let ($a, $b) = (d.0, scalar.0);
+                d.0 = $lane;
+            }
+        }
+    };
+}
+
+impl_slice_op!(
+    /// Wrapping (modular) element-wise addition.
+    /// Computes `dst[i] = dst[i] + src[i]` for every index, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::wrapping_add_slices;
+    /// # fn main() {
+    /// let mut dst = [u12![1], u12![4095]];
+    /// wrapping_add_slices(&mut dst, &[u12![2], u12![3]]);
+    /// assert_eq!(dst, [u12![3], u12![2]]);
+    /// # }
+    /// ```
+    wrapping_add_slices,
+    |a, b| (a + b) & LANE_MASK
+);
+
+impl_slice_op!(
+    /// Wrapping (modular) element-wise subtraction.
+    /// Computes `dst[i] = dst[i] - src[i]` for every index, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::wrapping_sub_slices;
+    /// # fn main() {
+    /// let mut dst = [u12![3], u12![0]];
+    /// wrapping_sub_slices(&mut dst, &[u12![2], u12![5]]);
+    /// assert_eq!(dst, [u12![1], u12![0xFFB]]);
+    /// # }
+    /// ```
+    wrapping_sub_slices,
+    |a, b| a.wrapping_sub(b) & LANE_MASK
+);
+
+impl_slice_op!(
+    /// Saturating element-wise addition.
+    /// Computes `dst[i] = dst[i] + src[i]` for every index, saturating at the numeric
+    /// bounds instead of overflowing.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::saturating_add_slices;
+    /// # fn main() {
+    /// let mut dst = [u12![1], u12![4000]];
+    /// saturating_add_slices(&mut dst, &[u12![2], u12![1000]]);
+    /// assert_eq!(dst, [u12![3], U12::max_value()]);
+    /// # }
+    /// ```
+    saturating_add_slices,
+    |a, b| ::std::cmp::min(a + b, LANE_MASK)
+);
+
+impl_slice_op!(
+    /// Saturating element-wise subtraction.
+    /// Computes `dst[i] = dst[i] - src[i]` for every index, saturating at the numeric
+    /// bounds instead of overflowing.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::saturating_sub_slices;
+    /// # fn main() {
+    /// let mut dst = [u12![3], u12![1]];
+    /// saturating_sub_slices(&mut dst, &[u12![2], u12![5]]);
+    /// assert_eq!(dst, [u12![1], U12::min_value()]);
+    /// # }
+    /// ```
+    saturating_sub_slices,
+    |a, b| a.saturating_sub(b)
+);
+
+impl_slice_op!(
+    /// Element-wise bitwise-and.
+    /// Computes `dst[i] = dst[i] & src[i]` for every index.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    and_slices,
+    |a, b| a & b
+);
+
+impl_slice_op!(
+    /// Element-wise bitwise-or.
+    /// Computes `dst[i] = dst[i] | src[i]` for every index.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    or_slices,
+    |a, b| a | b
+);
+
+impl_slice_op!(
+    /// Element-wise bitwise-xor.
+    /// Computes `dst[i] = dst[i] ^ src[i]` for every index.
+    ///
+    /// # Panics
+    /// This function will panic if `dst` and `src` have different lengths.
+    xor_slices,
+    |a, b| a ^ b
+);
+
+// MARK: - Scalar Operations
+
+impl_scalar_op!(
+    /// Wrapping (modular) scalar addition.
+    /// Computes `dst[i] = dst[i] + scalar` for every index, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::wrapping_add_scalar;
+    /// # fn main() {
+    /// let mut dst = [u12![1], u12![4095]];
+    /// wrapping_add_scalar(&mut dst, u12![2]);
+    /// assert_eq!(dst, [u12![3], u12![1]]);
+    /// # }
+    /// ```
+    wrapping_add_scalar,
+    |a, b| (a + b) & LANE_MASK
+);
+
+impl_scalar_op!(
+    /// Wrapping (modular) scalar subtraction.
+    /// Computes `dst[i] = dst[i] - scalar` for every index, wrapping around at the
+    /// boundary of the type.
+    wrapping_sub_scalar,
+    |a, b| a.wrapping_sub(b) & LANE_MASK
+);
+
+impl_scalar_op!(
+    /// Saturating scalar addition.
+    /// Computes `dst[i] = dst[i] + scalar` for every index, saturating at the numeric
+    /// bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::saturating_add_scalar;
+    /// # fn main() {
+    /// let mut dst = [u12![1], u12![4000]];
+    /// saturating_add_scalar(&mut dst, u12![100]);
+    /// assert_eq!(dst, [u12![101], U12::max_value()]);
+    /// # }
+    /// ```
+    saturating_add_scalar,
+    |a, b| ::std::cmp::min(a + b, LANE_MASK)
+);
+
+impl_scalar_op!(
+    /// Saturating scalar subtraction.
+    /// Computes `dst[i] = dst[i] - scalar` for every index, saturating at the numeric
+    /// bounds instead of overflowing.
+    saturating_sub_scalar,
+    |a, b| a.saturating_sub(b)
+);
+
+impl_scalar_op!(
+    /// Bitwise-and of every element with `mask`.
+    /// Computes `dst[i] = dst[i] & mask` for every index.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::slice_ops::and_mask_slice;
+    /// # fn main() {
+    /// let mut dst = [u12![0xABC], u12![0x123]];
+    /// and_mask_slice(&mut dst, u12![0x0F0]);
+    /// assert_eq!(dst, [u12![0x0B0], u12![0x020]]);
+    /// # }
+    /// ```
+    and_mask_slice,
+    |a, b| a & b
+);
+
+impl_scalar_op!(
+    /// Bitwise-or of every element with `mask`.
+    /// Computes `dst[i] = dst[i] | mask` for every index.
+    or_mask_slice,
+    |a, b| a | b
+);
+
+impl_scalar_op!(
+    /// Bitwise-xor of every element with `mask`.
+    /// Computes `dst[i] = dst[i] ^ mask` for every index.
+    xor_mask_slice,
+    |a, b| a ^ b
+);
@@ -0,0 +1,113 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Summary statistics over slices of `U12` values.
+//!
+//! Sums are accumulated in a `u64`, which cannot overflow for any slice that fits in memory,
+//! so capture buffers can be summarized in place without widening them first. The variance
+//! is only computed on request, since it needs a second accumulator of squared values.
+
+use super::U12;
+
+/// Summary statistics of a non-empty slice of values, as returned by `summarize()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// The number of values.
+    pub count: usize,
+    /// The smallest value.
+    pub min: U12,
+    /// The largest value.
+    pub max: U12,
+    /// The sum of all values.
+    pub sum: u64,
+    /// The arithmetic mean of all values.
+    pub mean: f64,
+    /// The population variance of all values, if requested with
+    /// `summarize_with_variance()`.
+    pub variance: Option<f64>,
+}
+
+impl Stats {
+    /// Returns the population standard deviation, if the variance was computed.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance.map(f64::sqrt)
+    }
+}
+
+/// Returns the count, minimum, maximum, sum and mean of `values`, or `None` if `values` is
+/// empty. The variance is not computed.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::stats::summarize;
+/// # fn main() {
+/// let stats = summarize(&[u12![10], u12![20], u12![4000], u12![30]]).unwrap();
+/// assert_eq!((stats.min, stats.max), (u12![10], u12![4000]));
+/// assert_eq!(stats.sum, 4060);
+/// assert_eq!(stats.mean, 1015.0);
+/// assert_eq!(stats.variance, None);
+/// assert_eq!(summarize(&[]), None);
+/// # }
+/// ```
+pub fn summarize(values: &[U12]) -> Option<Stats> {
+    let (&first, _) = values.split_first()?;
+    let mut min = first.0;
+    let mut max = first.0;
+    let mut sum = 0u64;
+    for value in values {
+        min = min.min(value.0);
+        max = max.max(value.0);
+        sum += value.0 as u64;
+    }
+    Some(Stats {
+        count: values.len(),
+        min: U12(min),
+        max: U12(max),
+        sum,
+        mean: sum as f64 / values.len() as f64,
+        variance: None,
+    })
+}
+
+/// Returns the same statistics as `summarize()`, together with the population variance.
+///
+/// The variance is computed exactly from integer sums of values and their squares, and is
+/// only rounded once when converted to `f64`.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::stats::summarize_with_variance;
+/// # fn main() {
+/// let values = [2, 4, 4, 4, 5, 5, 7, 9].map(U12::from_u16);
+/// let stats = summarize_with_variance(&values).unwrap();
+/// assert_eq!(stats.mean, 5.0);
+/// assert_eq!(stats.variance, Some(4.0));
+/// assert_eq!(stats.std_dev(), Some(2.0));
+/// # }
+/// ```
+pub fn summarize_with_variance(values: &[U12]) -> Option<Stats> {
+    let mut stats = summarize(values)?;
+    let sum_of_squares: u128 = values
+        .iter()
+        .map(|value| value.0 as u128 * value.0 as u128)
+        .sum();
+    // n * sum(x^2) - sum(x)^2 is exact in integers and never negative.
+    let count = values.len() as u128;
+    let scaled = count * sum_of_squares - stats.sum as u128 * stats.sum as u128;
+    stats.variance = Some(scaled as f64 / (count * count) as f64);
+    Some(stats)
+}
@@ -0,0 +1,32 @@
+//! Constant-time operations on `U12` values, enabled by the `subtle` feature.
+//!
+//! `U12` implements `ConstantTimeEq`, `ConstantTimeGreater`, `ConstantTimeLess` and
+//! `ConditionallySelectable`, each delegating to the constant-time implementation for the
+//! underlying `u16`.
+
+use super::U12;
+extern crate subtle;
+
+use self::subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+impl ConstantTimeEq for U12 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeGreater for U12 {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.0.ct_gt(&other.0)
+    }
+}
+
+impl ConstantTimeLess for U12 {}
+
+impl ConditionallySelectable for U12 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        U12(u16::conditional_select(&a.0, &b.0, choice))
+    }
+}
@@ -0,0 +1,19 @@
+//! Conversions to and from `ux::u12`, enabled by the `ux` feature.
+//!
+//! Both types represent exactly the range `0...4095`, so the conversions are lossless in
+//! both directions.
+
+use super::U12;
+extern crate ux;
+
+impl From<ux::u12> for U12 {
+    fn from(value: ux::u12) -> Self {
+        U12(u16::from(value))
+    }
+}
+
+impl From<U12> for ux::u12 {
+    fn from(value: U12) -> Self {
+        ux::u12::new(value.0)
+    }
+}
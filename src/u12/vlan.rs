@@ -0,0 +1,129 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! The IEEE 802.1Q tag control information (TCI) field of VLAN-tagged Ethernet frames.
+//!
+//! The TCI is a big-endian 16-bit word holding a 3-bit priority code point (PCP), a 1-bit
+//! drop eligible indicator (DEI), and the 12-bit VLAN identifier (VID).
+//!
+//! ```text
+//! bits 15...13: PCP
+//! bit  12:      DEI
+//! bits 11...0:  VID
+//! ```
+
+use super::U12;
+
+/// The VID of a priority-tagged frame, which belongs to no VLAN.
+pub const VID_NONE: U12 = U12(0x000);
+
+/// The default VID of untagged ports on most switches.
+pub const VID_DEFAULT: U12 = U12(0x001);
+
+/// The VID reserved for implementation use, which may not be configured or transmitted.
+pub const VID_RESERVED: U12 = U12(0xFFF);
+
+/// An 802.1Q tag control information field.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::vlan::VlanTci;
+/// # fn main() {
+/// let mut tci = VlanTci::from_be_bytes([0xA0, 0x64]);
+/// assert_eq!(tci.pcp(), 5);
+/// assert!(!tci.dei());
+/// assert_eq!(tci.vid(), u12![100]);
+///
+/// tci.set_vid(u12![200]);
+/// assert_eq!(tci.to_be_bytes(), [0xA0, 0xC8]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VlanTci(u16);
+
+impl VlanTci {
+    /// Creates a TCI from its fields.
+    ///
+    /// # Panics
+    /// This function will panic if `pcp` is greater than `7`.
+    pub const fn new(pcp: u8, dei: bool, vid: U12) -> Self {
+        assert!(pcp <= 7, "priority code point out of range");
+        VlanTci((pcp as u16) << 13 | (dei as u16) << 12 | vid.0)
+    }
+
+    /// Creates a TCI from its raw 16-bit representation.
+    pub const fn from_bits(bits: u16) -> Self {
+        VlanTci(bits)
+    }
+
+    /// Returns the raw 16-bit representation of `self`.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Creates a TCI from its representation in a frame, in network byte order.
+    pub const fn from_be_bytes(bytes: [u8; 2]) -> Self {
+        VlanTci(u16::from_be_bytes(bytes))
+    }
+
+    /// Returns the representation of `self` in a frame, in network byte order.
+    pub const fn to_be_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the VLAN identifier.
+    pub const fn vid(self) -> U12 {
+        U12(self.0 & 0xFFF)
+    }
+
+    /// Sets the VLAN identifier, leaving the other fields untouched.
+    pub fn set_vid(&mut self, vid: U12) {
+        self.0 = self.0 & !0xFFF | vid.0;
+    }
+
+    /// Returns the priority code point, from `0` to `7`.
+    pub const fn pcp(self) -> u8 {
+        (self.0 >> 13) as u8
+    }
+
+    /// Sets the priority code point, leaving the other fields untouched.
+    ///
+    /// # Panics
+    /// This method will panic if `pcp` is greater than `7`.
+    pub fn set_pcp(&mut self, pcp: u8) {
+        assert!(pcp <= 7, "priority code point out of range");
+        self.0 = self.0 & 0x1FFF | (pcp as u16) << 13;
+    }
+
+    /// Returns the drop eligible indicator.
+    pub const fn dei(self) -> bool {
+        self.0 & 0x1000 != 0
+    }
+
+    /// Sets the drop eligible indicator, leaving the other fields untouched.
+    pub fn set_dei(&mut self, dei: bool) {
+        self.0 = self.0 & !0x1000 | (dei as u16) << 12;
+    }
+}
+
+impl From<u16> for VlanTci {
+    fn from(bits: u16) -> Self {
+        VlanTci(bits)
+    }
+}
+
+impl From<VlanTci> for u16 {
+    fn from(tci: VlanTci) -> Self {
+        tci.0
+    }
+}
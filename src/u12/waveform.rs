@@ -0,0 +1,179 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+//! Waveform tables for driving 12-bit DACs, such as by direct digital synthesis.
+//!
+//! Every generator is a `const fn`, so tables can be computed at compile time and placed in
+//! read-only memory, or filled at run time when their length is not known in advance. Each
+//! waveform spans from `offset` to `offset + amplitude`, and the full-range shorthands use the
+//! whole range from `0` to `4095`. Samples are rounded to the nearest value.
+
+use std::f64::consts::PI;
+
+use super::{MAX, MIN, U12};
+
+/// The shape of a periodic waveform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    /// A sine wave, starting at the midpoint and rising.
+    Sine,
+    /// A triangle wave, rising from the minimum to the maximum over the first half of the
+    /// period and falling back over the second.
+    Triangle,
+    /// A sawtooth wave, rising from the minimum over the whole period. The maximum is reached
+    /// at the start of the next period, so the table never contains it.
+    Sawtooth,
+    /// A single linear sweep from the minimum to the maximum, both included.
+    Ramp,
+}
+
+impl Waveform {
+    /// Returns sample `index` of a table of `len` samples spanning from `offset` to
+    /// `offset + amplitude`.
+    ///
+    /// # Panics
+    /// This method will panic if `index` is not less than `len`, or if `offset + amplitude`
+    /// exceeds `4095`.
+    pub const fn sample(self, index: usize, len: usize, offset: U12, amplitude: U12) -> U12 {
+        assert!(index < len, "index out of bounds");
+        assert!(
+            offset.0 + amplitude.0 <= MAX.0,
+            "waveform exceeds the 12-bit range"
+        );
+        let amplitude = amplitude.0 as u64;
+        let scaled = match self {
+            Waveform::Sine => {
+                let phase = 2.0 * PI * index as f64 / len as f64;
+                let scaled = ((1.0 + sin(phase)) / 2.0 * amplitude as f64 + 0.5) as u64;
+                if scaled > amplitude {
+                    amplitude
+                } else {
+                    scaled
+                }
+            }
+            Waveform::Triangle => {
+                let rise = if 2 * index <= len {
+                    2 * index
+                } else {
+                    2 * (len - index)
+                };
+                scale(amplitude, rise, len)
+            }
+            Waveform::Sawtooth => scale(amplitude, index, len),
+            Waveform::Ramp => {
+                if len == 1 {
+                    0
+                } else {
+                    scale(amplitude, index, len - 1)
+                }
+            }
+        };
+        U12(offset.0 + scaled as u16)
+    }
+
+    /// Returns a table of `N` samples spanning from `offset` to `offset + amplitude`.
+    ///
+    /// # Panics
+    /// This method will panic if `N` is zero, or if `offset + amplitude` exceeds `4095`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate twelve_bit;
+    /// use twelve_bit::u12::*;
+    /// use twelve_bit::u12::waveform::Waveform;
+    /// # fn main() {
+    /// const TRIANGLE: [U12; 4] = Waveform::Triangle.table(u12![1000], u12![2000]);
+    /// assert_eq!(TRIANGLE, [u12![1000], u12![2000], u12![3000], u12![2000]]);
+    /// # }
+    /// ```
+    pub const fn table<const N: usize>(self, offset: U12, amplitude: U12) -> [U12; N] {
+        assert!(N > 0, "waveform table must not be empty");
+        let mut table = [MIN; N];
+        let mut index = 0;
+        while index < N {
+            table[index] = self.sample(index, N, offset, amplitude);
+            index += 1;
+        }
+        table
+    }
+
+    /// Fills `table` with one period of the waveform, spanning from `offset` to
+    /// `offset + amplitude`.
+    ///
+    /// # Panics
+    /// This method will panic if `offset + amplitude` exceeds `4095`.
+    pub fn fill(self, table: &mut [U12], offset: U12, amplitude: U12) {
+        let len = table.len();
+        for (index, sample) in table.iter_mut().enumerate() {
+            *sample = self.sample(index, len, offset, amplitude);
+        }
+    }
+}
+
+/// Returns a full-range sine table of `N` samples.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// # #[macro_use] extern crate twelve_bit;
+/// use twelve_bit::u12::*;
+/// use twelve_bit::u12::waveform::sine_table;
+/// # fn main() {
+/// static SINE: [U12; 4] = sine_table();
+/// assert_eq!(SINE, [u12![2048], u12![4095], u12![2048], u12![0]]);
+/// # }
+/// ```
+pub const fn sine_table<const N: usize>() -> [U12; N] {
+    Waveform::Sine.table(MIN, MAX)
+}
+
+/// Returns a full-range triangle table of `N` samples.
+pub const fn triangle_table<const N: usize>() -> [U12; N] {
+    Waveform::Triangle.table(MIN, MAX)
+}
+
+/// Returns a full-range sawtooth table of `N` samples.
+pub const fn sawtooth_table<const N: usize>() -> [U12; N] {
+    Waveform::Sawtooth.table(MIN, MAX)
+}
+
+/// Returns a full-range ramp table of `N` samples.
+pub const fn ramp_table<const N: usize>() -> [U12; N] {
+    Waveform::Ramp.table(MIN, MAX)
+}
+
+/// Returns `amplitude * numerator / denominator`, rounded to nearest.
+const fn scale(amplitude: u64, numerator: usize, denominator: usize) -> u64 {
+    (amplitude * numerator as u64 + denominator as u64 / 2) / denominator as u64
+}
+
+/// Returns the sine of `x`, for `x` in `0...2π`. This is a `const` replacement for
+/// `f64::sin`, accurate to well within the resolution of a 12-bit sample.
+const fn sin(x: f64) -> f64 {
+    // Reduce to -π/2...π/2, where the Taylor series converges quickly.
+    let mut x = if x > PI { x - 2.0 * PI } else { x };
+    if x > PI / 2.0 {
+        x = PI - x;
+    } else if x < -PI / 2.0 {
+        x = -PI - x;
+    }
+    let square = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1.0;
+    while n < 16.0 {
+        term = -term * square / ((n + 1.0) * (n + 2.0));
+        sum += term;
+        n += 2.0;
+    }
+    sum
+}
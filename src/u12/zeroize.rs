@@ -0,0 +1,26 @@
+//! Secure wiping of `U12` values, enabled by the `zeroize` feature.
+//!
+//! `U12` implements `DefaultIsZeroes`, which provides `Zeroize` for single values as well as
+//! for slices, arrays and vectors of values. `Image12` and `Memory4K` implement `Zeroize`
+//! by wiping their contents, leaving the dimensions of an image intact.
+
+use super::image::Image12;
+use super::memory::Memory4K;
+use super::U12;
+extern crate zeroize;
+
+use self::zeroize::{DefaultIsZeroes, Zeroize};
+
+impl DefaultIsZeroes for U12 {}
+
+impl Zeroize for Image12 {
+    fn zeroize(&mut self) {
+        self.data.as_mut_slice().zeroize();
+    }
+}
+
+impl Zeroize for Memory4K {
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+    }
+}
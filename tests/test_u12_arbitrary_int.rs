@@ -0,0 +1,38 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "arbitrary-int")]
+
+extern crate arbitrary_int;
+#[macro_use]
+extern crate twelve_bit;
+
+use arbitrary_int::prelude::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Conversions
+
+#[test]
+fn test_arbitrary_int_conversions() {
+    assert_eq!(U12::from(arbitrary_int::u12::new(0xABC)), u12![0xABC]);
+    assert_eq!(arbitrary_int::u12::from(u12![0xABC]).value(), 0xABC);
+    assert_eq!(
+        arbitrary_int::u12::from(U12::max_value()),
+        arbitrary_int::u12::MAX
+    );
+    assert_eq!(U12::from(arbitrary_int::u12::MIN), U12::min_value());
+}
+
+#[test]
+fn test_arbitrary_int_round_trip() {
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        assert_eq!(U12::from(arbitrary_int::u12::from(value)), value);
+    }
+}
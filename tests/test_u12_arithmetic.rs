@@ -25,10 +25,20 @@ fn test_add_operator() {
 
 #[test]
 #[should_panic]
+#[cfg(not(all(feature = "primitive-overflow", not(debug_assertions))))]
 fn test_add_operator_overflow() {
     let _ = U12::max_value() + u12![1];
 }
 
+#[test]
+#[cfg(all(feature = "primitive-overflow", not(debug_assertions)))]
+fn test_add_operator_overflow_wraps() {
+    let mut value = U12::max_value();
+    value += u12![2];
+    assert_eq!(U12::max_value() + u12![1], u12![0]);
+    assert_eq!(value, u12![1]);
+}
+
 #[test]
 fn test_checked_add() {
     assert_eq!(u12![0].checked_add(u12![0]), Some(u12![0]));
@@ -95,6 +105,70 @@ fn test_overflowing_add() {
     );
 }
 
+#[test]
+fn test_unchecked_add() {
+    unsafe {
+        assert_eq!(u12![0].unchecked_add(u12![0]), u12![0]);
+        assert_eq!(u12![1].unchecked_add(u12![2]), u12![3]);
+        assert_eq!(u12![0].unchecked_add(U12::max_value()), U12::max_value());
+        assert_eq!(u12![0xFFE].unchecked_add(u12![1]), U12::max_value());
+    }
+}
+
+#[test]
+fn test_strict_add() {
+    assert_eq!(u12![0].strict_add(u12![0]), u12![0]);
+    assert_eq!(u12![1].strict_add(u12![2]), u12![3]);
+    assert_eq!(u12![0xFFE].strict_add(u12![1]), U12::max_value());
+}
+
+#[test]
+#[should_panic]
+fn test_strict_add_overflow() {
+    let _ = U12::max_value().strict_add(u12![1]);
+}
+
+#[test]
+fn test_checked_add_signed() {
+    assert_eq!(u12![0].checked_add_signed(0), Some(u12![0]));
+    assert_eq!(u12![5].checked_add_signed(-5), Some(u12![0]));
+    assert_eq!(u12![5].checked_add_signed(-6), None);
+    assert_eq!(u12![0xFFE].checked_add_signed(1), Some(U12::max_value()));
+    assert_eq!(u12![0xFFE].checked_add_signed(2), None);
+    assert_eq!(U12::max_value().checked_add_signed(i16::MIN), None);
+    assert_eq!(u12![0].checked_add_signed(i16::MAX), None);
+}
+
+#[test]
+fn test_saturating_add_signed() {
+    assert_eq!(u12![5].saturating_add_signed(-3), u12![2]);
+    assert_eq!(u12![5].saturating_add_signed(-6), U12::min_value());
+    assert_eq!(u12![5].saturating_add_signed(i16::MIN), U12::min_value());
+    assert_eq!(u12![0xFFE].saturating_add_signed(2), U12::max_value());
+    assert_eq!(u12![0].saturating_add_signed(i16::MAX), U12::max_value());
+}
+
+#[test]
+fn test_wrapping_add_signed() {
+    assert_eq!(u12![5].wrapping_add_signed(-3), u12![2]);
+    assert_eq!(u12![0].wrapping_add_signed(-1), U12::max_value());
+    assert_eq!(u12![0].wrapping_add_signed(-4096), u12![0]);
+    assert_eq!(u12![0].wrapping_add_signed(i16::MIN), u12![0]);
+    assert_eq!(u12![0].wrapping_add_signed(i16::MAX), U12::max_value());
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        assert_eq!(value.wrapping_add_signed(1), value.wrapping_add(u12![1]));
+        assert_eq!(value.wrapping_add_signed(-1), value.wrapping_sub(u12![1]));
+    }
+}
+
+#[test]
+fn test_overflowing_add_signed() {
+    assert_eq!(u12![5].overflowing_add_signed(-3), (u12![2], false));
+    assert_eq!(u12![0].overflowing_add_signed(-1), (U12::max_value(), true));
+    assert_eq!(U12::max_value().overflowing_add_signed(1), (u12![0], true));
+}
+
 // MARK: - Tests - Subtraction
 
 #[test]
@@ -107,10 +181,20 @@ fn test_sub_operator() {
 
 #[test]
 #[should_panic]
+#[cfg(not(all(feature = "primitive-overflow", not(debug_assertions))))]
 fn test_sub_operator_underflow() {
     let _ = U12::min_value() - u12![1];
 }
 
+#[test]
+#[cfg(all(feature = "primitive-overflow", not(debug_assertions)))]
+fn test_sub_operator_underflow_wraps() {
+    let mut value = U12::min_value();
+    value -= u12![2];
+    assert_eq!(U12::min_value() - u12![1], U12::max_value());
+    assert_eq!(value, u12![0xFFE]);
+}
+
 #[test]
 fn test_checked_sub() {
     assert_eq!(u12![0].checked_sub(u12![0]), Some(u12![0]));
@@ -177,6 +261,71 @@ fn test_overflowing_sub() {
     );
 }
 
+#[test]
+fn test_unchecked_sub() {
+    unsafe {
+        assert_eq!(u12![0].unchecked_sub(u12![0]), u12![0]);
+        assert_eq!(u12![3].unchecked_sub(u12![2]), u12![1]);
+        assert_eq!(U12::max_value().unchecked_sub(U12::max_value()), u12![0]);
+        assert_eq!(U12::max_value().unchecked_sub(u12![1]), u12![0xFFE]);
+    }
+}
+
+#[test]
+fn test_strict_sub() {
+    assert_eq!(u12![0].strict_sub(u12![0]), u12![0]);
+    assert_eq!(u12![3].strict_sub(u12![2]), u12![1]);
+    assert_eq!(U12::max_value().strict_sub(u12![1]), u12![0xFFE]);
+}
+
+#[test]
+#[should_panic]
+fn test_strict_sub_underflow() {
+    let _ = U12::min_value().strict_sub(u12![1]);
+}
+
+#[test]
+fn test_checked_signed_diff() {
+    assert_eq!(u12![0].checked_signed_diff(u12![0]), Some(0));
+    assert_eq!(u12![5].checked_signed_diff(u12![3]), Some(2));
+    assert_eq!(u12![3].checked_signed_diff(u12![5]), Some(-2));
+    assert_eq!(
+        U12::max_value().checked_signed_diff(U12::min_value()),
+        Some(4095)
+    );
+    assert_eq!(
+        U12::min_value().checked_signed_diff(U12::max_value()),
+        Some(-4095)
+    );
+}
+
+#[test]
+fn test_checked_signed_diff_inverts_add_signed() {
+    for &(a, b) in &[(0u16, 4095u16), (4095, 0), (0x200, 0x1FE), (1234, 1234)] {
+        let (a, b) = (U12::from_u16(a), U12::from_u16(b));
+        let diff = a.checked_signed_diff(b).unwrap();
+        assert_eq!(b.checked_add_signed(diff), Some(a));
+    }
+}
+
+// MARK: - Tests - Successor and Predecessor
+
+#[test]
+fn test_checked_next() {
+    assert_eq!(u12![0].checked_next(), Some(u12![1]));
+    assert_eq!(u12![0x1FF].checked_next(), Some(u12![0x200]));
+    assert_eq!(u12![0xFFE].checked_next(), Some(U12::max_value()));
+    assert_eq!(U12::max_value().checked_next(), None);
+}
+
+#[test]
+fn test_checked_prev() {
+    assert_eq!(U12::max_value().checked_prev(), Some(u12![0xFFE]));
+    assert_eq!(u12![0x200].checked_prev(), Some(u12![0x1FF]));
+    assert_eq!(u12![1].checked_prev(), Some(u12![0]));
+    assert_eq!(U12::min_value().checked_prev(), None);
+}
+
 // MARK: - Tests - Multiplication
 
 #[test]
@@ -189,10 +338,20 @@ fn test_mul_operator() {
 
 #[test]
 #[should_panic]
+#[cfg(not(all(feature = "primitive-overflow", not(debug_assertions))))]
 fn test_mul_operator_overflow() {
     let _ = u12![2] * u12![2048];
 }
 
+#[test]
+#[cfg(all(feature = "primitive-overflow", not(debug_assertions)))]
+fn test_mul_operator_overflow_wraps() {
+    let mut value = u12![2];
+    value *= u12![4095];
+    assert_eq!(u12![2] * u12![2048], u12![0]);
+    assert_eq!(value, u12![0xFFE]);
+}
+
 #[test]
 fn test_checked_mul() {
     assert_eq!(u12![2].checked_mul(u12![0]), Some(U12::min_value()));
@@ -232,6 +391,28 @@ fn test_overflowing_mul() {
     assert_eq!(u12![2].overflowing_mul(u12![4095]), (u12![0xFFE], true));
 }
 
+#[test]
+fn test_unchecked_mul() {
+    unsafe {
+        assert_eq!(u12![0].unchecked_mul(U12::max_value()), u12![0]);
+        assert_eq!(u12![1].unchecked_mul(U12::max_value()), U12::max_value());
+        assert_eq!(u12![64].unchecked_mul(u12![63]), u12![4032]);
+        assert_eq!(u12![2].unchecked_mul(u12![2047]), u12![4094]);
+    }
+}
+
+#[test]
+fn test_strict_mul() {
+    assert_eq!(u12![0].strict_mul(U12::max_value()), u12![0]);
+    assert_eq!(u12![2].strict_mul(u12![2047]), u12![4094]);
+}
+
+#[test]
+#[should_panic]
+fn test_strict_mul_overflow() {
+    let _ = u12![2].strict_mul(u12![2048]);
+}
+
 // MARK: - Tests - Division
 
 #[test]
@@ -290,6 +471,35 @@ fn test_overflowing_div_overflow() {
     let _ = u12![255].overflowing_div(u12![0]);
 }
 
+#[test]
+fn test_saturating_div() {
+    assert_eq!(u12![0].saturating_div(u12![1]), u12![0]);
+    assert_eq!(u12![5].saturating_div(u12![2]), u12![2]);
+    assert_eq!(U12::max_value().saturating_div(u12![1]), U12::max_value());
+    assert_eq!(U12::max_value().saturating_div(U12::max_value()), u12![1]);
+}
+
+#[test]
+#[should_panic]
+fn test_saturating_div_divide_by_zero() {
+    let _ = u12![1].saturating_div(u12![0]);
+}
+
+#[test]
+fn test_div_euclid() {
+    assert_eq!(u12![7].div_euclid(u12![4]), u12![1]);
+    assert_eq!(u12![8].div_euclid(u12![4]), u12![2]);
+    assert_eq!(U12::max_value().div_euclid(u12![2]), u12![2047]);
+    assert_eq!(u12![7].checked_div_euclid(u12![4]), Some(u12![1]));
+    assert_eq!(u12![7].checked_div_euclid(u12![0]), None);
+}
+
+#[test]
+#[should_panic]
+fn test_div_euclid_divide_by_zero() {
+    let _ = u12![1].div_euclid(u12![0]);
+}
+
 // MARK: - Tests - Remainder
 
 #[test]
@@ -331,6 +541,107 @@ fn test_wrapping_rem_divide_by_zero() {
     let _ = u12![255].wrapping_rem(u12![0]);
 }
 
+#[test]
+fn test_rem_euclid() {
+    assert_eq!(u12![7].rem_euclid(u12![4]), u12![3]);
+    assert_eq!(u12![8].rem_euclid(u12![4]), u12![0]);
+    assert_eq!(U12::max_value().rem_euclid(u12![2]), u12![1]);
+    assert_eq!(u12![7].checked_rem_euclid(u12![4]), Some(u12![3]));
+    assert_eq!(u12![7].checked_rem_euclid(u12![0]), None);
+}
+
+#[test]
+#[should_panic]
+fn test_rem_euclid_divide_by_zero() {
+    let _ = u12![1].rem_euclid(u12![0]);
+}
+
+#[test]
+fn test_div_rem() {
+    assert_eq!(u12![0].div_rem(u12![7]), (u12![0], u12![0]));
+    assert_eq!(u12![7].div_rem(u12![4]), (u12![1], u12![3]));
+    assert_eq!(u12![4095].div_rem(u12![10]), (u12![409], u12![5]));
+    assert_eq!(u12![4095].div_rem(u12![4095]), (u12![1], u12![0]));
+    for value in 0u16..4096 {
+        let value: U12 = value.unchecked_into();
+        let divisor = u12![37];
+        assert_eq!(
+            value.checked_div_rem(divisor),
+            Some((value / divisor, value % divisor))
+        );
+    }
+    assert_eq!(u12![7].checked_div_rem(u12![0]), None);
+}
+
+#[test]
+#[should_panic]
+fn test_div_rem_divide_by_zero() {
+    let _ = u12![1].div_rem(u12![0]);
+}
+
+// MARK: - Tests - Square Root
+
+#[test]
+fn test_isqrt() {
+    assert_eq!(u12![0].isqrt(), u12![0]);
+    assert_eq!(u12![1].isqrt(), u12![1]);
+    assert_eq!(u12![3].isqrt(), u12![1]);
+    assert_eq!(u12![4].isqrt(), u12![2]);
+    assert_eq!(u12![4095].isqrt(), u12![63]);
+    for value in 0..4096u16 {
+        let root = u16::from(U12::from_u16(value).isqrt());
+        assert!(root * root <= value);
+        assert!((root + 1) * (root + 1) > value);
+    }
+}
+
+// MARK: - Tests - Multiples
+
+#[test]
+fn test_is_multiple_of() {
+    assert!(u12![0].is_multiple_of(u12![0]));
+    assert!(u12![0].is_multiple_of(u12![7]));
+    assert!(!u12![7].is_multiple_of(u12![0]));
+    assert!(u12![4095].is_multiple_of(u12![5]));
+    assert!(!u12![4095].is_multiple_of(u12![2]));
+}
+
+#[test]
+fn test_next_multiple_of() {
+    assert_eq!(u12![0].next_multiple_of(u12![16]), u12![0]);
+    assert_eq!(u12![1].next_multiple_of(u12![16]), u12![16]);
+    assert_eq!(u12![16].next_multiple_of(u12![16]), u12![16]);
+    assert_eq!(u12![4080].next_multiple_of(u12![16]), u12![4080]);
+    assert_eq!(
+        u12![4000].next_multiple_of(U12::max_value()),
+        U12::max_value()
+    );
+    assert_eq!(u12![4081].checked_next_multiple_of(u12![16]), None);
+    assert_eq!(u12![1].checked_next_multiple_of(u12![0]), None);
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        match value.checked_next_multiple_of(u12![100]) {
+            Some(result) => {
+                assert!(result >= value && result.is_multiple_of(u12![100]));
+                assert!(u16::from(result) - u16::from(value) < 100);
+            }
+            None => assert!(value > u12![4000]),
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_next_multiple_of_overflow() {
+    u12![4081].next_multiple_of(u12![16]);
+}
+
+#[test]
+#[should_panic]
+fn test_next_multiple_of_zero() {
+    u12![1].next_multiple_of(u12![0]);
+}
+
 // MARK: - Tests - Negation
 
 #[test]
@@ -356,3 +667,344 @@ fn test_overflowing_neg() {
     assert_eq!(u12![2].overflowing_neg(), (u12![0xFFE], true));
     assert_eq!(u12![255].overflowing_neg(), (u12![0xF01], true));
 }
+
+// MARK: - Tests - Primitive Operands
+
+#[test]
+fn test_arithmetic_with_unsuffixed_literal_operand() {
+    assert_eq!(u12![0x200] + 2, u12![0x202]);
+    assert_eq!(u12![0x200] - 2, u12![0x1FE]);
+    assert_eq!(u12![0x200] * 2, u12![0x400]);
+    assert_eq!(u12![0x200] / 2, u12![0x100]);
+    assert_eq!(u12![0x201] % 2, u12![1]);
+    let (lhs, rhs) = (&u12![0x200], &2u16);
+    assert_eq!(lhs + 2, u12![0x202]);
+    assert_eq!(lhs + rhs, u12![0x202]);
+
+    let mut pc = u12![0x200];
+    pc += 2;
+    pc += 1;
+    pc -= 1;
+    pc *= 2;
+    pc /= 3;
+    pc %= 255;
+    assert_eq!(pc, u12![0x156 % 255]);
+}
+
+#[test]
+fn test_arithmetic_with_u16_operand() {
+    assert_eq!(u12![0x200] + 0x300u16, u12![0x500]);
+    assert_eq!(u12![0x200] - 0x100u16, u12![0x100]);
+    assert_eq!(u12![2] * 0x400u16, u12![0x800]);
+    assert_eq!(u12![0x800] / 0x400u16, u12![2]);
+    assert_eq!(u12![0x801] % 0x400u16, u12![1]);
+
+    let mut value = u12![0x200];
+    value += 0x300u16;
+    assert_eq!(value, u12![0x500]);
+}
+
+#[test]
+#[should_panic]
+fn test_arithmetic_with_u16_operand_too_large() {
+    let _ = u12![0] + 0x1000u16;
+}
+
+#[test]
+#[should_panic]
+#[cfg(not(all(feature = "primitive-overflow", not(debug_assertions))))]
+fn test_arithmetic_with_primitive_operand_overflow() {
+    let _ = U12::max_value() + 1;
+}
+
+// MARK: - Tests - Interpolation
+
+#[test]
+fn test_lerp_endpoints() {
+    for &(a, b) in [
+        (u12![0], U12::max_value()),
+        (U12::max_value(), u12![0]),
+        (u12![7], u12![7]),
+    ]
+    .iter()
+    {
+        assert_eq!(a.lerp(b, U12::min_value()), a);
+        assert_eq!(a.lerp(b, U12::max_value()), b);
+    }
+}
+
+#[test]
+fn test_lerp_is_monotonic_and_bounded() {
+    let (a, b) = (u12![1000], u12![3000]);
+    let mut previous = a;
+    for t in 0u16..4096 {
+        let value = a.lerp(b, t.unchecked_into());
+        assert!(value >= previous && value <= b);
+        previous = value;
+    }
+}
+
+#[test]
+fn test_lerp_full_range() {
+    for t in 0u16..4096 {
+        let t: U12 = t.unchecked_into();
+        assert_eq!(U12::min_value().lerp(U12::max_value(), t), t);
+        assert_eq!(U12::max_value().lerp(U12::min_value(), t), !t);
+    }
+}
+
+#[test]
+fn test_lerp_midpoint() {
+    assert_eq!(u12![100].lerp(u12![200], u12![2048]), u12![150]);
+    assert_eq!(u12![200].lerp(u12![100], u12![2048]), u12![150]);
+    assert_eq!(u12![0].lerp(u12![1], u12![2047]), u12![0]);
+    assert_eq!(u12![0].lerp(u12![1], u12![2048]), u12![1]);
+}
+
+// MARK: - Tests - Scaling
+
+#[test]
+fn test_mul_div() {
+    assert_eq!(u12![0].mul_div(U12::max_value(), u12![1]), Some(u12![0]));
+    assert_eq!(
+        U12::max_value().mul_div(U12::max_value(), U12::max_value()),
+        Some(U12::max_value())
+    );
+    assert_eq!(
+        u12![2048].mul_div(u12![3300], U12::max_value()),
+        Some(u12![1650])
+    );
+    assert_eq!(u12![10].mul_div(u12![1], u12![3]), Some(u12![3]));
+    assert_eq!(u12![2047].mul_div(u12![2], u12![1]), Some(u12![4094]));
+    assert_eq!(u12![2048].mul_div(u12![2], u12![1]), None);
+    assert_eq!(u12![1].mul_div(u12![1], u12![0]), None);
+}
+
+#[test]
+fn test_mul_div_matches_wide_arithmetic() {
+    for value in (0u32..4096).step_by(7) {
+        for &(num, den) in [(3u32, 7u32), (4095, 4095), (1000, 4095), (4095, 1000)].iter() {
+            let expected = value * num / den;
+            let result = U12::from_u16(value as u16)
+                .mul_div(U12::from_u16(num as u16), U12::from_u16(den as u16));
+            if expected > 4095 {
+                assert_eq!(result, None);
+            } else {
+                assert_eq!(result, Some(U12::from_u16(expected as u16)));
+            }
+        }
+    }
+}
+
+// MARK: - Tests - Modular Arithmetic
+
+#[test]
+fn test_add_sub_mul_mod_exhaustive_for_small_modulus() {
+    let modulus = u12![37];
+    for a in (0..4096u32).step_by(7) {
+        for b in (0..4096u32).step_by(11) {
+            let (x, y) = (U12::from_u16(a as u16), U12::from_u16(b as u16));
+            assert_eq!(u16::from(x.add_mod(y, modulus)) as u32, (a + b) % 37);
+            assert_eq!(
+                u16::from(x.sub_mod(y, modulus)) as u32,
+                (a % 37 + 37 - b % 37) % 37
+            );
+            assert_eq!(u16::from(x.mul_mod(y, modulus)) as u32, a * b % 37);
+        }
+    }
+}
+
+#[test]
+fn test_mod_with_extreme_modulus() {
+    let max = U12::max_value();
+    assert_eq!(max.add_mod(max, max), u12![0]);
+    assert_eq!(max.mul_mod(u12![4094], max), u12![0]);
+    assert_eq!(u12![4094].mul_mod(u12![4094], max), u12![1]);
+    assert_eq!(u12![0].sub_mod(u12![1], max), u12![4094]);
+    assert_eq!(max.add_mod(max, u12![1]), u12![0]);
+    assert_eq!(max.sub_mod(u12![3], u12![1]), u12![0]);
+}
+
+#[test]
+fn test_pow_mod() {
+    for base in 0..20u16 {
+        let mut expected = 1u32;
+        for exp in 0..30u32 {
+            assert_eq!(
+                u12![base].pow_mod(exp, u12![1009]),
+                U12::from_u16((expected % 1009) as u16)
+            );
+            expected = expected * base as u32 % 1009;
+        }
+    }
+    assert_eq!(u12![4094].pow_mod(u32::MAX, U12::max_value()), u12![4094]);
+    assert_eq!(u12![0].pow_mod(0, u12![7]), u12![1]);
+}
+
+#[test]
+#[should_panic]
+fn test_add_mod_zero_modulus() {
+    u12![1].add_mod(u12![1], u12![0]);
+}
+
+#[test]
+#[should_panic]
+fn test_pow_mod_zero_modulus() {
+    u12![1].pow_mod(1, u12![0]);
+}
+
+// MARK: - Tests - Checked Reductions
+
+#[test]
+fn test_checked_sum_u12() {
+    let empty: [U12; 0] = [];
+    assert_eq!(empty.iter().checked_sum_u12(), Some(U12::min_value()));
+    assert_eq!(
+        vec![u12![4094], u12![1]].into_iter().checked_sum_u12(),
+        Some(U12::max_value())
+    );
+    assert_eq!([u12![4095], u12![1]].iter().checked_sum_u12(), None);
+    assert_eq!(
+        [u12![4095], u12![1], u12![0]].iter().checked_sum_u12(),
+        None
+    );
+}
+
+#[test]
+fn test_checked_sum_u12_short_circuits() {
+    let mut visited = 0;
+    let sum = [u12![4095], u12![1], u12![1], u12![1]]
+        .iter()
+        .inspect(|_| visited += 1)
+        .checked_sum_u12();
+    assert_eq!(sum, None);
+    assert_eq!(visited, 2);
+}
+
+#[test]
+fn test_checked_product_u12() {
+    let empty: [U12; 0] = [];
+    assert_eq!(empty.iter().checked_product_u12(), Some(u12![1]));
+    assert_eq!(
+        [u12![5], u12![819]].iter().checked_product_u12(),
+        Some(U12::max_value())
+    );
+    assert_eq!([u12![5], u12![820]].iter().checked_product_u12(), None);
+    assert_eq!(
+        [u12![0], u12![4095]].iter().checked_product_u12(),
+        Some(u12![0])
+    );
+    assert_eq!(
+        [u12![64], u12![64], u12![0]].iter().checked_product_u12(),
+        None
+    );
+}
+
+// MARK: - Tests - Serial Number Arithmetic
+
+#[test]
+fn test_wrapping_distance() {
+    assert_eq!(u12![0].wrapping_distance(u12![0]), u12![0]);
+    assert_eq!(
+        u12![0].wrapping_distance(U12::max_value()),
+        U12::max_value()
+    );
+    assert_eq!(U12::max_value().wrapping_distance(u12![0]), u12![1]);
+    assert_eq!(u12![15].wrapping_distance(u12![10]), u12![4091]);
+}
+
+#[test]
+fn test_seq_lt_and_seq_gt() {
+    assert!(!u12![5].seq_lt(u12![5]));
+    assert!(!u12![5].seq_gt(u12![5]));
+    assert!(u12![0].seq_lt(u12![2047]));
+    assert!(u12![2047].seq_gt(u12![0]));
+    assert!(!u12![0].seq_lt(u12![2048]));
+    assert!(!u12![0].seq_gt(u12![2048]));
+    assert!(u12![0].seq_gt(u12![2049]));
+    assert!(U12::max_value().seq_lt(u12![0]));
+    assert!(u12![0].seq_gt(U12::max_value()));
+}
+
+#[test]
+fn test_seq_comparisons_are_antisymmetric() {
+    for offset in 0..4096u16 {
+        let (a, b) = (u12![100], u12![100].wrapping_add(U12::from_u16(offset)));
+        assert_eq!(a.seq_lt(b), b.seq_gt(a));
+        assert!(!(a.seq_lt(b) && a.seq_gt(b)));
+        assert_eq!(a.seq_lt(b) || a.seq_gt(b), offset != 0 && offset != 2048);
+    }
+}
+
+// MARK: - Tests - Flagged Arithmetic
+
+#[test]
+fn test_flag_add_matches_reference() {
+    for a in (0..4096u16).step_by(13) {
+        for b in (0..4096u16).step_by(17) {
+            for &carry in &[false, true] {
+                let (x, y) = (U12::from_u16(a), U12::from_u16(b));
+                let (result, flags) = x.flag_add_with_carry(y, carry);
+                let sum = a as u32 + b as u32 + carry as u32;
+                let signed =
+                    x.sign_extend_to_i16() as i32 + y.sign_extend_to_i16() as i32 + carry as i32;
+                assert_eq!(u16::from(result) as u32, sum % 4096);
+                assert_eq!(flags.carry, sum > 4095);
+                assert_eq!(flags.zero, sum.is_multiple_of(4096));
+                assert_eq!(flags.negative, result.sign_extend_to_i16() < 0);
+                assert_eq!(flags.overflow, !(-2048..=2047).contains(&signed));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flag_sub_matches_reference() {
+    for a in (0..4096u16).step_by(13) {
+        for b in (0..4096u16).step_by(17) {
+            for &borrow in &[false, true] {
+                let (x, y) = (U12::from_u16(a), U12::from_u16(b));
+                let (result, flags) = x.flag_sub_with_borrow(y, borrow);
+                let difference = a as i32 - b as i32 - borrow as i32;
+                let signed =
+                    x.sign_extend_to_i16() as i32 - y.sign_extend_to_i16() as i32 - borrow as i32;
+                assert_eq!(u16::from(result) as i32, difference.rem_euclid(4096));
+                assert_eq!(flags.carry, difference < 0);
+                assert_eq!(flags.zero, difference.rem_euclid(4096) == 0);
+                assert_eq!(flags.negative, result.sign_extend_to_i16() < 0);
+                assert_eq!(flags.overflow, !(-2048..=2047).contains(&signed));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flag_add_and_sub_without_carry() {
+    assert_eq!(
+        u12![1].flag_add(u12![2]),
+        u12![1].flag_add_with_carry(u12![2], false)
+    );
+    assert_eq!(
+        u12![1].flag_sub(u12![2]),
+        u12![1].flag_sub_with_borrow(u12![2], false)
+    );
+    assert_eq!(
+        U12::min_value().flag_sub(u12![1]),
+        (
+            U12::max_value(),
+            Flags {
+                carry: true,
+                zero: false,
+                negative: true,
+                overflow: false
+            }
+        )
+    );
+    assert_eq!(
+        U12::min_value().flag_add(U12::min_value()).1,
+        Flags {
+            zero: true,
+            ..Flags::default()
+        }
+    );
+}
@@ -0,0 +1,94 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::base64::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Single Values
+
+#[test]
+fn test_encode_values() {
+    assert_eq!(&encode(u12![0x000]), b"AA");
+    assert_eq!(&encode(u12![0x040]), b"BA");
+    assert_eq!(&encode(u12![0x03F]), b"A_");
+    assert_eq!(&encode(u12![0xF80]), b"-A");
+    assert_eq!(&encode(u12![0xD1A]), b"0a");
+}
+
+#[test]
+fn test_encode_decode_round_trip() {
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        let chars = encode(value);
+        assert!(chars
+            .iter()
+            .all(|c| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'_'));
+        assert_eq!(decode(chars), Some(value));
+    }
+}
+
+#[test]
+fn test_decode_invalid_characters() {
+    assert_eq!(decode(*b"A="), None);
+    assert_eq!(decode(*b"+A"), None);
+    assert_eq!(decode(*b"A/"), None);
+}
+
+// MARK: - Tests - Strings
+
+#[test]
+fn test_encode_slice() {
+    assert_eq!(encode_slice(&[]), "");
+    assert_eq!(encode_slice(&[u12![0xFFF], u12![0]]), "__AA");
+}
+
+#[test]
+fn test_decode_str() {
+    assert_eq!(decode_str(""), Ok(vec![]));
+    assert_eq!(decode_str("__AA"), Ok(vec![u12![0xFFF], u12![0]]));
+    assert_eq!(decode_str("__A"), Err(DecodeError::TruncatedInput));
+    assert_eq!(decode_str("__A!"), Err(DecodeError::InvalidCharacter(3)));
+    assert_eq!(decode_str("__*A"), Err(DecodeError::InvalidCharacter(2)));
+    assert_eq!(decode_str("é_"), Err(DecodeError::InvalidCharacter(0)));
+}
+
+#[test]
+fn test_decode_error_display() {
+    assert_eq!(
+        DecodeError::InvalidCharacter(5).to_string(),
+        "invalid character at offset 5"
+    );
+    assert_eq!(
+        DecodeError::TruncatedInput.to_string(),
+        "text ends with half of a value"
+    );
+}
+
+// MARK: - Tests - Iterators
+
+#[test]
+fn test_encode_iter() {
+    let mut chars = encode_iter(vec![u12![0x123], u12![0x040]]);
+    assert_eq!(chars.size_hint(), (4, Some(4)));
+    assert_eq!(chars.next(), Some('E'));
+    assert_eq!(chars.size_hint(), (3, Some(3)));
+    assert_eq!(chars.collect::<String>(), "jBA");
+}
+
+#[test]
+fn test_decode_iter_stops_after_error() {
+    let results: Vec<_> = decode_iter("AB!!CD").collect();
+    assert_eq!(
+        results,
+        [Ok(u12![0x001]), Err(DecodeError::InvalidCharacter(2))]
+    );
+}
@@ -0,0 +1,91 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::*;
+
+u12_bitfield! {
+    /// Three equal nibbles.
+    struct Nibbles {
+        high, set_high: 4,
+        middle, set_middle: 4,
+        low, set_low: 4,
+    }
+}
+
+u12_bitfield! {
+    /// Uneven fields, including single bits and a field spanning byte boundaries.
+    pub struct Status {
+        /// The enable flag.
+        enabled, set_enabled: 1,
+        mode, set_mode: 2,
+        count, set_count: 7,
+        error, set_error: 1,
+        busy, set_busy: 1
+    }
+}
+
+u12_bitfield! {
+    struct Whole {
+        value, set_value: 12,
+    }
+}
+
+// MARK: - Tests - Getters
+
+#[test]
+fn test_getters() {
+    let nibbles = Nibbles::from_bits(u12![0xABC]);
+    assert_eq!(nibbles.high(), 0xA);
+    assert_eq!(nibbles.middle(), 0xB);
+    assert_eq!(nibbles.low(), 0xC);
+
+    let status = Status::from(u12![0xD55]);
+    assert_eq!(status.enabled(), 1);
+    assert_eq!(status.mode(), 0b10);
+    assert_eq!(status.count(), 0b1010101);
+    assert_eq!(status.error(), 0);
+    assert_eq!(status.busy(), 1);
+
+    assert_eq!(Whole::from_bits(u12![0xFED]).value(), 0xFED);
+}
+
+// MARK: - Tests - Setters
+
+#[test]
+fn test_setters_preserve_other_fields() {
+    let mut status = Status::from_bits(U12::max_value());
+    status.set_count(0);
+    assert_eq!(status.to_bits(), u12![0xE03]);
+    status.set_enabled(0);
+    status.set_busy(0);
+    assert_eq!(U12::from(status), u12![0x602]);
+
+    let mut nibbles = Nibbles::default();
+    nibbles.set_middle(0xF);
+    assert_eq!(nibbles.to_bits(), u12![0x0F0]);
+
+    let mut whole = Whole::default();
+    whole.set_value(0xFFF);
+    assert_eq!(whole.to_bits(), U12::max_value());
+}
+
+#[test]
+#[should_panic]
+fn test_setter_out_of_range() {
+    Nibbles::default().set_low(0x10);
+}
+
+#[test]
+#[should_panic]
+fn test_single_bit_setter_out_of_range() {
+    Status::default().set_error(2);
+}
@@ -0,0 +1,87 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "bitvec")]
+
+#[macro_use]
+extern crate twelve_bit;
+extern crate bitvec;
+
+use bitvec::prelude::*;
+use twelve_bit::u12::packing::{values_from_bitslice, values_to_bitvec};
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Single Values
+
+#[test]
+fn test_from_bitslice_msb0() {
+    let bytes = [0x12u8, 0x34];
+    let bits = bytes.view_bits::<Msb0>();
+    assert_eq!(U12::from_bitslice(&bits[..12]), Some(u12![0x123]));
+    assert_eq!(U12::from_bitslice(&bits[4..]), Some(u12![0x234]));
+    assert_eq!(U12::from_bitslice(&bits[..13]), None);
+}
+
+#[test]
+fn test_from_bitslice_lsb0() {
+    // With Lsb0 ordering, bit index 0 is the least significant bit of the first byte.
+    let bytes = [0b0000_0001u8, 0];
+    let bits = bytes.view_bits::<Lsb0>();
+    assert_eq!(U12::from_bitslice(&bits[..12]), Some(u12![0x800]));
+}
+
+#[test]
+fn test_store_bitslice() {
+    let mut bytes = [0xFFu8, 0xFF];
+    u12![0x5A5].store_bitslice(&mut bytes.view_bits_mut::<Msb0>()[2..14]);
+    assert_eq!(bytes, [0xD6, 0x97]);
+
+    let mut words = [0u16];
+    u12![0xABC].store_bitslice(&mut words.view_bits_mut::<Lsb0>()[..12]);
+    assert_eq!(
+        U12::from_bitslice(&words.view_bits::<Lsb0>()[..12]),
+        Some(u12![0xABC])
+    );
+}
+
+#[test]
+#[should_panic(expected = "bit slice must hold exactly 12 bits")]
+fn test_store_bitslice_wrong_length() {
+    let mut bytes = [0u8; 2];
+    u12![1].store_bitslice(bytes.view_bits_mut::<Msb0>());
+}
+
+// MARK: - Tests - Slices
+
+#[test]
+fn test_values_to_bitvec() {
+    let bits: BitVec<u8, Msb0> = values_to_bitvec(&[u12![0xFFF], u12![0x000], u12![0x801]]);
+    assert_eq!(bits.len(), 36);
+    assert_eq!(bits.as_raw_slice(), &[0xFF, 0xF0, 0x00, 0x80, 0x10]);
+}
+
+#[test]
+fn test_values_bitvec_round_trip() {
+    let values: Vec<U12> = (0..4096u16).step_by(7).map(U12::from_u16).collect();
+    let msb: BitVec<u8, Msb0> = values_to_bitvec(&values);
+    assert_eq!(values_from_bitslice(&msb), Some(values.clone()));
+    let lsb: BitVec<u32, Lsb0> = values_to_bitvec(&values);
+    assert_eq!(values_from_bitslice(&lsb), Some(values));
+}
+
+#[test]
+fn test_values_from_bitslice_partial_value() {
+    let bits = bitvec![u8, Msb0; 1; 13];
+    assert_eq!(values_from_bitslice(&bits), None);
+    assert_eq!(
+        values_from_bitslice(&bits[..12]),
+        Some(vec![U12::max_value()])
+    );
+    assert_eq!(values_from_bitslice(&bits[..0]), Some(vec![]));
+}
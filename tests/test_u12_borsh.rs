@@ -0,0 +1,56 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "borsh")]
+
+#[macro_use]
+extern crate twelve_bit;
+extern crate borsh;
+
+use borsh::io::ErrorKind;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Serialization
+
+#[test]
+fn test_serialize() {
+    assert_eq!(borsh::to_vec(&u12![0xABC]).unwrap(), vec![0xBC, 0x0A]);
+    assert_eq!(borsh::to_vec(&U12::max_value()).unwrap(), vec![0xFF, 0x0F]);
+}
+
+#[test]
+fn test_round_trip() {
+    for value in 0..4096 {
+        let value = U12::from_u16(value);
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(borsh::from_slice::<U12>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_round_trip_collection() {
+    let values = vec![u12![1], u12![2048], U12::max_value()];
+    let bytes = borsh::to_vec(&values).unwrap();
+    assert_eq!(bytes.len(), 4 + 2 * values.len());
+    assert_eq!(borsh::from_slice::<Vec<U12>>(&bytes).unwrap(), values);
+}
+
+// MARK: - Tests - Deserialization Errors
+
+#[test]
+fn test_deserialize_out_of_range() {
+    let error = borsh::from_slice::<U12>(&[0x00, 0x10]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(borsh::from_slice::<U12>(&[0xFF, 0xFF]).is_err());
+}
+
+#[test]
+fn test_deserialize_truncated() {
+    assert!(borsh::from_slice::<U12>(&[0x01]).is_err());
+}
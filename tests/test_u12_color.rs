@@ -0,0 +1,58 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::color::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Channels
+
+#[test]
+fn test_new() {
+    let color = Rgb444::new(0x1, 0x2, 0x3).unwrap();
+    assert_eq!(color.r(), 0x1);
+    assert_eq!(color.g(), 0x2);
+    assert_eq!(color.b(), 0x3);
+    assert_eq!(U12::from(color), u12![0x123]);
+    assert_eq!(Rgb444::new(0x10, 0, 0), None);
+    assert_eq!(Rgb444::new(0, 0x10, 0), None);
+    assert_eq!(Rgb444::new(0, 0, 0x10), None);
+}
+
+#[test]
+fn test_bits_round_trip() {
+    for bits in 0..4096u16 {
+        let bits = U12::from_u16(bits);
+        let color = Rgb444::from(bits);
+        assert_eq!(color.to_bits(), bits);
+        assert_eq!(Rgb444::new(color.r(), color.g(), color.b()), Some(color));
+    }
+}
+
+// MARK: - Tests - 8-Bit Conversions
+
+#[test]
+fn test_from_rgb8_rounds() {
+    assert_eq!(Rgb444::from_rgb8(0, 0, 0).to_bits(), u12![0x000]);
+    assert_eq!(Rgb444::from_rgb8(255, 255, 255).to_bits(), u12![0xFFF]);
+    assert_eq!(Rgb444::from_rgb8(8, 9, 34).to_bits(), u12![0x012]);
+    assert_eq!(Rgb444::from_rgb8(127, 128, 136).to_bits(), u12![0x788]);
+}
+
+#[test]
+fn test_rgb8_round_trip() {
+    for channel in 0..16u8 {
+        let color = Rgb444::new(channel, 15 - channel, channel).unwrap();
+        let [r, g, b] = color.to_rgb8();
+        assert_eq!(Rgb444::from_rgb8(r, g, b), color);
+    }
+    assert_eq!(Rgb444::new(0xF, 0x0, 0x8).unwrap().to_rgb8(), [255, 0, 136]);
+}
@@ -10,6 +10,8 @@
 #[macro_use]
 extern crate twelve_bit;
 
+use std::convert::TryFrom;
+use std::num::IntErrorKind;
 use twelve_bit::u12::*;
 
 // MARK: - Tests - Non-Failable Conversions - From Smaller Types
@@ -46,6 +48,34 @@ fn test_into_larger_types() {
     assert_eq!(into_usize_min, 0usize);
 }
 
+#[test]
+fn test_into_signed_and_wide_types() {
+    let into_i16_max: i16 = U12::max_value().into();
+    let into_i16_min: i16 = U12::min_value().into();
+    assert_eq!(into_i16_max, 4095i16);
+    assert_eq!(into_i16_min, 0i16);
+
+    let into_i32_max: i32 = U12::max_value().into();
+    let into_i32_min: i32 = U12::min_value().into();
+    assert_eq!(into_i32_max, 4095i32);
+    assert_eq!(into_i32_min, 0i32);
+
+    let into_i64_max: i64 = U12::max_value().into();
+    let into_i64_min: i64 = U12::min_value().into();
+    assert_eq!(into_i64_max, 4095i64);
+    assert_eq!(into_i64_min, 0i64);
+
+    let into_i128_max: i128 = U12::max_value().into();
+    let into_i128_min: i128 = U12::min_value().into();
+    assert_eq!(into_i128_max, 4095i128);
+    assert_eq!(into_i128_min, 0i128);
+
+    let into_u128_max: u128 = U12::max_value().into();
+    let into_u128_min: u128 = U12::min_value().into();
+    assert_eq!(into_u128_max, 4095u128);
+    assert_eq!(into_u128_min, 0u128);
+}
+
 // MARK: - Tests - Failable Conversions - From Larger Types
 
 #[test]
@@ -55,7 +85,7 @@ fn test_failable_into_with_u16() {
     assert_eq!(15u16.failable_into(), Some(U12::from(15)));
     assert_eq!(15u16.unchecked_into(), U12::from(15));
     assert_eq!(4096u16.failable_into(), None);
-    assert_eq!(u16::max_value().failable_into(), None);
+    assert_eq!(u16::MAX.failable_into(), None);
 }
 
 #[test]
@@ -71,7 +101,7 @@ fn test_failable_into_with_u32() {
     assert_eq!(15u32.failable_into(), Some(U12::from(15u8)));
     assert_eq!(15u32.unchecked_into(), U12::from(15));
     assert_eq!(4096u32.failable_into(), None);
-    assert_eq!(u32::max_value().failable_into(), None);
+    assert_eq!(u32::MAX.failable_into(), None);
 }
 
 #[test]
@@ -87,7 +117,7 @@ fn test_failable_into_with_u64() {
     assert_eq!(15u64.failable_into(), Some(U12::from(15u8)));
     assert_eq!(15u64.unchecked_into(), U12::from(15));
     assert_eq!(4096u64.failable_into(), None);
-    assert_eq!(u64::max_value().failable_into(), None);
+    assert_eq!(u64::MAX.failable_into(), None);
 }
 
 #[test]
@@ -103,7 +133,7 @@ fn test_failable_into_with_usize() {
     assert_eq!(15usize.failable_into(), Some(U12::from(15u8)));
     assert_eq!(15usize.unchecked_into(), U12::from(15));
     assert_eq!(4096usize.failable_into(), None);
-    assert_eq!(usize::max_value().failable_into(), None);
+    assert_eq!(usize::MAX.failable_into(), None);
 }
 
 #[test]
@@ -112,6 +142,264 @@ fn test_unchecked_into_with_usize() {
     let _ = 4096usize.unchecked_into();
 }
 
+// MARK: - Tests - Failable Conversions - From Signed Types
+
+#[test]
+fn test_failable_into_with_i8() {
+    assert_eq!(0i8.failable_into(), Some(U12::min_value()));
+    assert_eq!(0i8.unchecked_into(), U12::min_value());
+    assert_eq!(15i8.failable_into(), Some(U12::from(15u8)));
+    assert_eq!(i8::MAX.failable_into(), Some(U12::from(127u8)));
+    assert_eq!((-1i8).failable_into(), None);
+    assert_eq!(i8::MIN.failable_into(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_unchecked_into_with_i8() {
+    let _ = (-1i8).unchecked_into();
+}
+
+#[test]
+fn test_failable_into_with_i16() {
+    assert_eq!(0i16.failable_into(), Some(U12::min_value()));
+    assert_eq!(0i16.unchecked_into(), U12::min_value());
+    assert_eq!(15i16.failable_into(), Some(U12::from(15u8)));
+    assert_eq!(i16::MAX.failable_into(), None);
+    assert_eq!(4095i16.failable_into(), Some(U12::max_value()));
+    assert_eq!(4096i16.failable_into(), None);
+    assert_eq!((-1i16).failable_into(), None);
+    assert_eq!(i16::MIN.failable_into(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_unchecked_into_with_i16() {
+    let _ = (-1i16).unchecked_into();
+}
+
+#[test]
+fn test_failable_into_with_i32() {
+    assert_eq!(0i32.failable_into(), Some(U12::min_value()));
+    assert_eq!(0i32.unchecked_into(), U12::min_value());
+    assert_eq!(15i32.failable_into(), Some(U12::from(15u8)));
+    assert_eq!(i32::MAX.failable_into(), None);
+    assert_eq!(4095i32.failable_into(), Some(U12::max_value()));
+    assert_eq!(4096i32.failable_into(), None);
+    assert_eq!((-1i32).failable_into(), None);
+    assert_eq!(i32::MIN.failable_into(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_unchecked_into_with_i32() {
+    let _ = (-1i32).unchecked_into();
+}
+
+#[test]
+fn test_failable_into_with_i64() {
+    assert_eq!(0i64.failable_into(), Some(U12::min_value()));
+    assert_eq!(0i64.unchecked_into(), U12::min_value());
+    assert_eq!(15i64.failable_into(), Some(U12::from(15u8)));
+    assert_eq!(i64::MAX.failable_into(), None);
+    assert_eq!(4095i64.failable_into(), Some(U12::max_value()));
+    assert_eq!(4096i64.failable_into(), None);
+    assert_eq!((-1i64).failable_into(), None);
+    assert_eq!(i64::MIN.failable_into(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_unchecked_into_with_i64() {
+    let _ = (-1i64).unchecked_into();
+}
+
+#[test]
+fn test_failable_into_with_isize() {
+    assert_eq!(0isize.failable_into(), Some(U12::min_value()));
+    assert_eq!(0isize.unchecked_into(), U12::min_value());
+    assert_eq!(15isize.failable_into(), Some(U12::from(15u8)));
+    assert_eq!(isize::MAX.failable_into(), None);
+    assert_eq!(4095isize.failable_into(), Some(U12::max_value()));
+    assert_eq!(4096isize.failable_into(), None);
+    assert_eq!((-1isize).failable_into(), None);
+    assert_eq!(isize::MIN.failable_into(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_unchecked_into_with_isize() {
+    let _ = (-1isize).unchecked_into();
+}
+
+// MARK: - Tests - Saturating Conversions - From Larger Types
+
+#[test]
+fn test_saturating_from() {
+    assert_eq!(U12::saturating_from(0u16), U12::min_value());
+    assert_eq!(U12::saturating_from(4095u16), U12::max_value());
+    assert_eq!(U12::saturating_from(4096u16), U12::max_value());
+    assert_eq!(U12::saturating_from(u16::MAX), U12::max_value());
+    assert_eq!(U12::saturating_from(1234u32), u12![1234]);
+    assert_eq!(U12::saturating_from(u32::MAX), U12::max_value());
+    assert_eq!(U12::saturating_from(4095u64), U12::max_value());
+    assert_eq!(U12::saturating_from(u64::MAX), U12::max_value());
+    assert_eq!(U12::saturating_from(7usize), u12![7]);
+    assert_eq!(U12::saturating_from(usize::MAX), U12::max_value());
+    assert_eq!(U12::saturating_from(u128::MAX), U12::max_value());
+}
+
+#[test]
+fn test_saturating_from_agrees_with_failable_into() {
+    for value in (0..70000u32).step_by(13) {
+        assert_eq!(
+            U12::saturating_from(value),
+            value.failable_into().unwrap_or(U12::max_value())
+        );
+    }
+}
+
+// MARK: - Tests - Truncating Conversions - From Larger Types
+
+#[test]
+fn test_truncate_from() {
+    assert_eq!(U12::truncate_from(0u16), U12::min_value());
+    assert_eq!(U12::truncate_from(4095u16), U12::max_value());
+    assert_eq!(U12::truncate_from(4096u16), U12::min_value());
+    assert_eq!(U12::truncate_from(0x1234u16), u12![0x234]);
+    assert_eq!(U12::truncate_from(0xABCD_E123u32), u12![0x123]);
+    assert_eq!(U12::truncate_from(u64::MAX), U12::max_value());
+    assert_eq!(U12::truncate_from(0x1FFFusize), U12::max_value());
+    assert_eq!(U12::truncate_from(u128::MAX - 0xFFF), U12::min_value());
+}
+
+#[test]
+fn test_truncate_from_wraps_program_counter() {
+    let pc = 0xFFEu32;
+    assert_eq!(U12::truncate_from(pc + 2), U12::min_value());
+    assert_eq!(U12::truncate_from(pc + 4), u12![2]);
+}
+
+// MARK: - Tests - Narrowing Conversions - Into Smaller Types
+
+#[test]
+fn test_try_to_u8() {
+    assert_eq!(U12::min_value().try_to_u8(), Some(0));
+    assert_eq!(u12![0xFF].try_to_u8(), Some(0xFF));
+    assert_eq!(u12![0x100].try_to_u8(), None);
+    assert_eq!(U12::max_value().try_to_u8(), None);
+}
+
+#[test]
+fn test_to_u8_saturating() {
+    assert_eq!(U12::min_value().to_u8_saturating(), 0);
+    assert_eq!(u12![0xFF].to_u8_saturating(), 0xFF);
+    assert_eq!(u12![0x100].to_u8_saturating(), 0xFF);
+    assert_eq!(U12::max_value().to_u8_saturating(), 0xFF);
+}
+
+#[test]
+fn test_to_u8_truncating() {
+    assert_eq!(u12![0xFF].to_u8_truncating(), 0xFF);
+    assert_eq!(u12![0x100].to_u8_truncating(), 0);
+    assert_eq!(u12![0xABC].to_u8_truncating(), 0xBC);
+}
+
+#[test]
+fn test_u8_narrowing_policies_agree_in_range() {
+    for value in 0..=0xFFu8 {
+        let value = U12::from(value);
+        assert_eq!(value.try_to_u8(), Some(value.to_u8_truncating()));
+        assert_eq!(value.to_u8_saturating(), value.to_u8_truncating());
+    }
+}
+
+// MARK: - Tests - Character Conversions
+
+#[test]
+fn test_char_from_u12() {
+    assert_eq!(char::from(U12::min_value()), '\0');
+    assert_eq!(char::from(u12![0x7A]), 'z');
+    assert_eq!(char::from(U12::max_value()), '\u{FFF}');
+}
+
+#[test]
+fn test_u12_try_from_char() {
+    assert_eq!(U12::try_from('\0'), Ok(U12::min_value()));
+    assert_eq!(U12::try_from('\u{FFF}'), Ok(U12::max_value()));
+    assert!(U12::try_from('\u{1000}').is_err());
+}
+
+#[test]
+fn test_char_round_trip() {
+    for value in 0..4096 {
+        let value = U12::from_u16(value);
+        assert_eq!(U12::try_from(char::from(value)), Ok(value));
+    }
+}
+
+#[test]
+fn test_try_from_char_error_message() {
+    let error = U12::try_from('\u{10FFFF}').unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "character code point out of range for U12"
+    );
+}
+
+// MARK: - Tests - Companding
+
+#[test]
+fn test_compand_endpoints() {
+    assert_eq!(U12::min_value().compand_to_u8(), 0);
+    assert_eq!(U12::max_value().compand_to_u8(), 0xFF);
+    assert_eq!(U12::expand_from_u8(0), U12::min_value());
+    assert_eq!(U12::expand_from_u8(0xFF), u12![4064]);
+}
+
+#[test]
+fn test_compand_exact_below_64() {
+    for value in 0..64 {
+        let value = U12::from_u16(value);
+        assert_eq!(value.compand_to_u8(), u16::from(value) as u8);
+        assert_eq!(U12::expand_from_u8(value.compand_to_u8()), value);
+    }
+}
+
+#[test]
+fn test_compand_segment_boundaries() {
+    assert_eq!(u12![63].compand_to_u8(), 0x3F);
+    assert_eq!(u12![64].compand_to_u8(), 0x40);
+    assert_eq!(u12![2047].compand_to_u8(), 0xDF);
+    assert_eq!(u12![2048].compand_to_u8(), 0xE0);
+}
+
+#[test]
+fn test_compand_round_trip_error_is_bounded() {
+    for value in 0..4096u16 {
+        let expanded = u16::from(U12::expand_from_u8(U12::from_u16(value).compand_to_u8()));
+        assert!(
+            64 * value.abs_diff(expanded) <= value,
+            "{} -> {}",
+            value,
+            expanded
+        );
+    }
+}
+
+#[test]
+fn test_compand_is_monotonic_and_expand_inverts() {
+    let mut previous = 0;
+    for value in 0..4096 {
+        let code = U12::from_u16(value).compand_to_u8();
+        assert!(code >= previous);
+        previous = code;
+    }
+    for code in 0..=0xFFu8 {
+        assert_eq!(U12::expand_from_u8(code).compand_to_u8(), code);
+    }
+}
+
 // MARK: - Tests - Convenience Macro
 
 #[test]
@@ -135,3 +423,279 @@ fn test_convenience_macro_panics_on_large_value() {
 fn test_default() {
     assert_eq!(U12::default(), U12::min_value());
 }
+
+// MARK: - Tests - Parsing
+
+#[test]
+fn test_from_str() {
+    assert_eq!("0".parse::<U12>(), Ok(U12::min_value()));
+    assert_eq!("4095".parse::<U12>(), Ok(U12::max_value()));
+    assert_eq!(
+        "4096".parse::<U12>().unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+    assert_eq!("".parse::<U12>().unwrap_err().kind(), &IntErrorKind::Empty);
+    assert_eq!(
+        "0x1".parse::<U12>().unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+}
+
+#[test]
+fn test_from_str_radix() {
+    assert_eq!(U12::from_str_radix("fff", 16), Ok(U12::max_value()));
+    assert_eq!(U12::from_str_radix("FFF", 16), Ok(U12::max_value()));
+    assert_eq!(U12::from_str_radix("7777", 8), Ok(U12::max_value()));
+    assert_eq!(U12::from_str_radix("111111111111", 2), Ok(U12::max_value()));
+    assert_eq!(U12::from_str_radix("zz", 36), Ok(u12![1295]));
+    assert_eq!(
+        U12::from_str_radix("1000", 16).unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        U12::from_str_radix("10000", 16).unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        U12::from_str_radix("8", 8).unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+}
+
+#[test]
+fn test_from_str_any() {
+    assert_eq!(U12::from_str_any("0"), Ok(u12![0]));
+    assert_eq!(U12::from_str_any("4_095"), Ok(u12![4095]));
+    assert_eq!(U12::from_str_any("0xabc"), Ok(u12![0xABC]));
+    assert_eq!(U12::from_str_any("0XA_BC"), Ok(u12![0xABC]));
+    assert_eq!(U12::from_str_any("0x_ABC"), Ok(u12![0xABC]));
+    assert_eq!(U12::from_str_any("0o17"), Ok(u12![0o17]));
+    assert_eq!(U12::from_str_any("0O1_7"), Ok(u12![0o17]));
+    assert_eq!(U12::from_str_any("0b1111_0000"), Ok(u12![0b11110000]));
+    assert_eq!(U12::from_str_any("0B1"), Ok(u12![1]));
+    assert_eq!(U12::from_str_any("010"), Ok(u12![10]));
+}
+
+#[test]
+fn test_from_str_any_errors() {
+    assert_eq!(
+        U12::from_str_any("").unwrap_err().kind(),
+        &IntErrorKind::Empty
+    );
+    assert_eq!(
+        U12::from_str_any("0x").unwrap_err().kind(),
+        &IntErrorKind::Empty
+    );
+    assert_eq!(
+        U12::from_str_any("0x__").unwrap_err().kind(),
+        &IntErrorKind::Empty
+    );
+    assert_eq!(
+        U12::from_str_any("0x1000").unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        U12::from_str_any("0b2").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        U12::from_str_any("0x+1").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        U12::from_str_any("0x_+F").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        U12::from_str_any("0b_+1").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        U12::from_str_any("_1").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        U12::from_str_any("0h12").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+}
+
+// MARK: - Tests - Proportional Conversions
+
+#[test]
+fn test_from_percent() {
+    assert_eq!(U12::from_percent(0), Some(U12::min_value()));
+    assert_eq!(U12::from_percent(1), Some(u12![41]));
+    assert_eq!(U12::from_percent(25), Some(u12![1024]));
+    assert_eq!(U12::from_percent(50), Some(u12![2048]));
+    assert_eq!(U12::from_percent(99), Some(u12![4054]));
+    assert_eq!(U12::from_percent(100), Some(U12::max_value()));
+    assert_eq!(U12::from_percent(101), None);
+    assert_eq!(U12::from_percent(255), None);
+}
+
+#[test]
+fn test_from_permille() {
+    assert_eq!(U12::from_permille(0), Some(U12::min_value()));
+    assert_eq!(U12::from_permille(1), Some(u12![4]));
+    assert_eq!(U12::from_permille(500), Some(u12![2048]));
+    assert_eq!(U12::from_permille(1000), Some(U12::max_value()));
+    assert_eq!(U12::from_permille(1001), None);
+    assert_eq!(U12::from_permille(u16::MAX), None);
+}
+
+#[test]
+fn test_to_percent_and_permille() {
+    assert_eq!(U12::min_value().to_percent(), 0);
+    assert_eq!(u12![20].to_percent(), 0);
+    assert_eq!(u12![21].to_percent(), 1);
+    assert_eq!(u12![2047].to_percent(), 50);
+    assert_eq!(U12::max_value().to_percent(), 100);
+    assert_eq!(U12::min_value().to_permille(), 0);
+    assert_eq!(u12![2].to_permille(), 0);
+    assert_eq!(u12![3].to_permille(), 1);
+    assert_eq!(U12::max_value().to_permille(), 1000);
+}
+
+#[test]
+fn test_percent_round_trip() {
+    for percent in 0..=100 {
+        assert_eq!(U12::from_percent(percent).unwrap().to_percent(), percent);
+    }
+    for permille in 0..=1000 {
+        assert_eq!(
+            U12::from_permille(permille).unwrap().to_permille(),
+            permille
+        );
+    }
+}
+
+#[test]
+fn test_to_millivolts() {
+    assert_eq!(U12::min_value().to_millivolts(3300), 0);
+    assert_eq!(u12![1].to_millivolts(3300), 1);
+    assert_eq!(u12![2047].to_millivolts(5000), 2499);
+    assert_eq!(U12::max_value().to_millivolts(3300), 3300);
+    assert_eq!(U12::max_value().to_millivolts(u32::MAX), u32::MAX);
+    assert_eq!(U12::max_value().to_millivolts(0), 0);
+}
+
+#[test]
+fn test_from_millivolts() {
+    assert_eq!(U12::from_millivolts(0, 3300), U12::min_value());
+    assert_eq!(U12::from_millivolts(1, 3300), u12![1]);
+    assert_eq!(U12::from_millivolts(2500, 5000), u12![2048]);
+    assert_eq!(U12::from_millivolts(3299, 3300), u12![4094]);
+    assert_eq!(U12::from_millivolts(3300, 3300), U12::max_value());
+    assert_eq!(U12::from_millivolts(u32::MAX, 3300), U12::max_value());
+    assert_eq!(U12::from_millivolts(0, 0), U12::max_value());
+}
+
+#[test]
+fn test_millivolts_round_trip() {
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        assert_eq!(
+            U12::from_millivolts(value.to_millivolts(40950), 40950),
+            value
+        );
+    }
+}
+
+// MARK: - Tests - Floating-Point Conversions
+
+#[test]
+fn test_from_f32_rounding_modes() {
+    assert_eq!(U12::from_f32(1.5, Rounding::Nearest), Some(u12![2]));
+    assert_eq!(U12::from_f32(1.49, Rounding::Nearest), Some(u12![1]));
+    assert_eq!(U12::from_f32(1.99, Rounding::Floor), Some(u12![1]));
+    assert_eq!(U12::from_f32(1.01, Rounding::Ceil), Some(u12![2]));
+    assert_eq!(U12::from_f32(7.0, Rounding::Floor), Some(u12![7]));
+    assert_eq!(U12::from_f32(7.0, Rounding::Ceil), Some(u12![7]));
+}
+
+#[test]
+fn test_from_f32_bounds() {
+    assert_eq!(U12::from_f32(0.0, Rounding::Nearest), Some(u12![0]));
+    assert_eq!(U12::from_f32(-0.0, Rounding::Nearest), Some(u12![0]));
+    assert_eq!(U12::from_f32(-0.5, Rounding::Nearest), None);
+    assert_eq!(U12::from_f32(-0.5, Rounding::Ceil), Some(u12![0]));
+    assert_eq!(U12::from_f32(-0.5, Rounding::Floor), None);
+    assert_eq!(
+        U12::from_f32(4095.0, Rounding::Nearest),
+        Some(U12::max_value())
+    );
+    assert_eq!(
+        U12::from_f32(4095.4, Rounding::Floor),
+        Some(U12::max_value())
+    );
+    assert_eq!(U12::from_f32(4095.4, Rounding::Ceil), None);
+    assert_eq!(U12::from_f32(f32::INFINITY, Rounding::Nearest), None);
+    assert_eq!(U12::from_f32(f32::NEG_INFINITY, Rounding::Nearest), None);
+    assert_eq!(U12::from_f32(f32::NAN, Rounding::Floor), None);
+}
+
+#[test]
+fn test_saturating_from_f32() {
+    assert_eq!(U12::saturating_from_f32(100.7, Rounding::Floor), u12![100]);
+    assert_eq!(U12::saturating_from_f32(100.2, Rounding::Ceil), u12![101]);
+    assert_eq!(
+        U12::saturating_from_f32(4095.4, Rounding::Ceil),
+        U12::max_value()
+    );
+    assert_eq!(
+        U12::saturating_from_f32(-0.5, Rounding::Floor),
+        U12::min_value()
+    );
+    assert_eq!(
+        U12::saturating_from_f32(f32::INFINITY, Rounding::Nearest),
+        U12::max_value()
+    );
+    assert_eq!(
+        U12::saturating_from_f32(f32::NEG_INFINITY, Rounding::Nearest),
+        U12::min_value()
+    );
+    assert_eq!(
+        U12::saturating_from_f32(f32::NAN, Rounding::Ceil),
+        U12::min_value()
+    );
+}
+
+#[test]
+fn test_from_f32_round_trip() {
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        let float = u16::from(value) as f32;
+        assert_eq!(U12::from_f32(float, Rounding::Nearest), Some(value));
+        assert_eq!(U12::saturating_from_f32(float, Rounding::Floor), value);
+    }
+}
+
+// MARK: - Tests - Sign Extension
+
+#[test]
+fn test_sign_extend_to_i16() {
+    assert_eq!(U12::min_value().sign_extend_to_i16(), 0);
+    assert_eq!(u12![0x001].sign_extend_to_i16(), 1);
+    assert_eq!(u12![0x7FF].sign_extend_to_i16(), 2047);
+    assert_eq!(u12![0x800].sign_extend_to_i16(), -2048);
+    assert_eq!(u12![0xFFE].sign_extend_to_i16(), -2);
+    assert_eq!(U12::max_value().sign_extend_to_i16(), -1);
+}
+
+#[test]
+fn test_from_i16_truncated() {
+    assert_eq!(U12::from_i16_truncated(0), U12::min_value());
+    assert_eq!(U12::from_i16_truncated(2047), u12![0x7FF]);
+    assert_eq!(U12::from_i16_truncated(-2048), u12![0x800]);
+    assert_eq!(U12::from_i16_truncated(2048), u12![0x800]);
+    assert_eq!(U12::from_i16_truncated(i16::MIN), U12::min_value());
+    assert_eq!(U12::from_i16_truncated(i16::MAX), U12::max_value());
+}
+
+#[test]
+fn test_sign_extension_round_trip() {
+    for value in -2048..2048i16 {
+        assert_eq!(U12::from_i16_truncated(value).sign_extend_to_i16(), value);
+    }
+}
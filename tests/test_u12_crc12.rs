@@ -0,0 +1,69 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::crc12::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Check Values
+
+#[test]
+fn test_check_values() {
+    assert_eq!(CRC_12_UMTS.checksum(b"123456789"), u12![0xDAF]);
+    assert_eq!(CRC_12_DECT.checksum(b"123456789"), u12![0xF5B]);
+    assert_eq!(CRC_12_CDMA2000.checksum(b"123456789"), u12![0xD4D]);
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(CRC_12_UMTS.checksum(&[]), u12![0x000]);
+    assert_eq!(CRC_12_DECT.checksum(&[]), u12![0x000]);
+    assert_eq!(CRC_12_CDMA2000.checksum(&[]), u12![0xFFF]);
+}
+
+// MARK: - Tests - Incremental Computation
+
+#[test]
+fn test_digest_matches_checksum() {
+    let data = b"The quick brown fox jumps over the lazy dog";
+    for algorithm in &[CRC_12_UMTS, CRC_12_DECT, CRC_12_CDMA2000] {
+        for split in 0..data.len() {
+            let mut digest = algorithm.digest();
+            digest.update(&data[..split]);
+            digest.update(&data[split..]);
+            assert_eq!(digest.finalize(), algorithm.checksum(data));
+        }
+    }
+}
+
+#[test]
+fn test_values_match_bytes() {
+    // Three bytes hold exactly two 12-bit values, most significant bit first.
+    let bytes = [0xAB, 0xC1, 0x23, 0x45, 0x67, 0x89];
+    let values = [u12![0xABC], u12![0x123], u12![0x456], u12![0x789]];
+    for algorithm in &[CRC_12_UMTS, CRC_12_DECT, CRC_12_CDMA2000] {
+        assert_eq!(
+            algorithm.checksum_values(values.iter().cloned()),
+            algorithm.checksum(&bytes)
+        );
+    }
+}
+
+#[test]
+fn test_detects_single_bit_errors() {
+    let data = *b"123456789";
+    let expected = CRC_12_CDMA2000.checksum(&data);
+    for index in 0..data.len() * 8 {
+        let mut corrupted = data;
+        corrupted[index / 8] ^= 1 << (index % 8);
+        assert_ne!(CRC_12_CDMA2000.checksum(&corrupted), expected);
+    }
+}
@@ -0,0 +1,132 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::delta::*;
+use twelve_bit::u12::*;
+
+/// Returns a slowly varying signal, as from an ADC sampling a sine wave.
+fn trace(len: usize) -> Vec<U12> {
+    (0..len)
+        .map(|i| U12::from_u16((2048.0 + 1500.0 * (i as f64 / 40.0).sin()) as u16))
+        .collect()
+}
+
+// MARK: - Tests - Compression
+
+#[test]
+fn test_empty() {
+    assert_eq!(compress(&[]), Vec::<u8>::new());
+    assert_eq!(decompress(&[]), Ok(vec![]));
+}
+
+#[test]
+fn test_single_value() {
+    assert_eq!(compress(&[u12![0xABC]]), vec![1, 0, 0x0A, 0xBC]);
+    assert_eq!(decompress(&[1, 0, 0x0A, 0xBC]), Ok(vec![u12![0xABC]]));
+}
+
+#[test]
+fn test_constant_signal() {
+    let values = vec![u12![1234]; 200];
+    let compressed = compress(&values);
+    assert_eq!(compressed.len(), 4 * 4);
+    assert_eq!(decompress(&compressed), Ok(values));
+}
+
+#[test]
+fn test_small_steps() {
+    let values = [u12![100], u12![101], u12![99], u12![98]];
+    // Residuals 1, -2 and -1 zigzag to 2, 3 and 1, which need two bits each.
+    assert_eq!(compress(&values), vec![4, 2, 0x00, 100, 0b1011_0100]);
+}
+
+#[test]
+fn test_round_trip_trace() {
+    let values = trace(1000);
+    let compressed = compress(&values);
+    assert!(compressed.len() < values.len());
+    assert_eq!(decompress(&compressed), Ok(values));
+}
+
+#[test]
+fn test_round_trip_wrapping_extremes() {
+    let values: Vec<U12> = (0..300)
+        .map(|i| {
+            if i % 2 == 0 {
+                u12![0]
+            } else {
+                U12::max_value()
+            }
+        })
+        .chain((0..100).map(|i| U12::from_u16((i % 2) * 0x800)))
+        .chain((0..4096).map(|i| U12::from_u16((i as u32 * 2731 % 4096) as u16)))
+        .collect();
+    assert_eq!(decompress(&compress(&values)), Ok(values));
+}
+
+// MARK: - Tests - Streaming
+
+#[test]
+fn test_decompress_iter() {
+    let values = trace(150);
+    let compressed = compress(&values);
+    let mut iter = decompress_iter(&compressed);
+    for &value in &values {
+        assert_eq!(iter.next(), Some(Ok(value)));
+    }
+    assert_eq!(iter.next(), None);
+}
+
+// MARK: - Tests - Errors
+
+#[test]
+fn test_truncated_input() {
+    let compressed = compress(&trace(100));
+    for len in [1, 3, 5, compressed.len() - 1] {
+        assert_eq!(
+            decompress(&compressed[..len]),
+            Err(DecompressError::TruncatedInput)
+        );
+    }
+}
+
+#[test]
+fn test_invalid_header() {
+    assert_eq!(
+        decompress(&[0, 0, 0, 0]),
+        Err(DecompressError::InvalidHeader(0))
+    );
+    assert_eq!(
+        decompress(&[65, 0, 0, 0]),
+        Err(DecompressError::InvalidHeader(0))
+    );
+    assert_eq!(
+        decompress(&[2, 13, 0, 0, 0, 0]),
+        Err(DecompressError::InvalidHeader(0))
+    );
+    assert_eq!(
+        decompress(&[1, 0, 0, 0, 1, 0, 0x10, 0x00]),
+        Err(DecompressError::InvalidHeader(4))
+    );
+}
+
+#[test]
+fn test_iter_stops_after_error() {
+    let mut iter = decompress_iter(&[1, 0, 0, 7, 0, 0, 0, 0]);
+    assert_eq!(iter.next(), Some(Ok(u12![7])));
+    assert_eq!(iter.next(), Some(Err(DecompressError::InvalidHeader(4))));
+    assert_eq!(iter.next(), None);
+    assert_eq!(
+        DecompressError::InvalidHeader(4).to_string(),
+        "invalid block header at offset 4"
+    );
+}
@@ -0,0 +1,113 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::dither::*;
+use twelve_bit::u12::*;
+
+/// Returns the mean of `levels`, scaled back to the 12-bit range.
+fn mean_as_u12(levels: &[u8]) -> f64 {
+    levels.iter().map(|&level| level as f64).sum::<f64>() / levels.len() as f64 * 4095.0 / 255.0
+}
+
+// MARK: - Tests - Ordered Dithering
+
+#[test]
+fn test_ordered_endpoints() {
+    let src = [u12![0], u12![4095], u12![0], u12![4095]];
+    let mut dst = [1; 4];
+    ordered(&src, &mut dst, 2);
+    assert_eq!(dst, [0, 255, 0, 255]);
+}
+
+#[test]
+fn test_ordered_preserves_mean() {
+    for value in (0..4096u16).step_by(37) {
+        let src = vec![U12::from_u16(value); 16];
+        let mut dst = vec![0; 16];
+        ordered(&src, &mut dst, 4);
+        assert!((mean_as_u12(&dst) - value as f64).abs() <= 16.06 / 2.0 + 1.0);
+        assert!(dst
+            .iter()
+            .all(|&level| level as u32 * 4095 / 255 <= value as u32 + 17));
+    }
+}
+
+#[test]
+fn test_ordered_exact_levels_are_not_dithered() {
+    // Multiples of 273 map exactly onto 8-bit levels, which are multiples of 17.
+    let mut dst = vec![0; 16];
+    for multiple in 0..16u16 {
+        let src = vec![U12::from_u16(multiple * 273); 16];
+        ordered(&src, &mut dst, 4);
+        assert!(dst.iter().all(|&level| level as u16 == multiple * 17));
+    }
+}
+
+// MARK: - Tests - Error Diffusion
+
+#[test]
+fn test_floyd_steinberg_endpoints() {
+    let src = [u12![0], u12![4095], u12![4095], u12![0]];
+    let mut dst = [1; 4];
+    floyd_steinberg(&src, &mut dst, 2);
+    assert_eq!(dst, [0, 255, 255, 0]);
+}
+
+#[test]
+fn test_floyd_steinberg_preserves_mean() {
+    for value in (0..4096u16).step_by(37) {
+        let src = vec![U12::from_u16(value); 64 * 64];
+        let mut dst = vec![0; 64 * 64];
+        floyd_steinberg(&src, &mut dst, 64);
+        assert!((mean_as_u12(&dst) - value as f64).abs() < 1.0);
+    }
+}
+
+#[test]
+fn test_floyd_steinberg_gradient_is_monotonic_on_average() {
+    let width = 256;
+    let src: Vec<U12> = (0..width * 16)
+        .map(|index| U12::from_u16((index % width * 16) as u16))
+        .collect();
+    let mut dst = vec![0; width * 16];
+    floyd_steinberg(&src, &mut dst, width);
+    let column_means: Vec<f64> = (0..width / 16)
+        .map(|block| {
+            let levels: Vec<u8> = (0..16)
+                .flat_map(|y| (0..16).map(move |x| (y, block * 16 + x)))
+                .map(|(y, x)| dst[y * width + x])
+                .collect();
+            mean_as_u12(&levels)
+        })
+        .collect();
+    assert!(column_means.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+// MARK: - Tests - Buffer Validation
+
+#[test]
+#[should_panic(expected = "buffer lengths differ")]
+fn test_dither_mismatched_lengths() {
+    ordered(&[u12![0]; 4], &mut [0; 3], 2);
+}
+
+#[test]
+#[should_panic(expected = "buffer is not a whole number of rows")]
+fn test_dither_partial_row() {
+    floyd_steinberg(&[u12![0]; 5], &mut [0; 5], 2);
+}
+
+#[test]
+#[should_panic(expected = "buffer is not a whole number of rows")]
+fn test_dither_zero_width() {
+    ordered(&[], &mut [], 0);
+}
@@ -0,0 +1,86 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::dump::*;
+use twelve_bit::u12::packing::{pack_slice, packed_len};
+use twelve_bit::u12::*;
+
+/// The expected dump of the values `0...17`.
+const COUNTING_DUMP: &str = "\
+00000000: 000 001 002 003 004 005 006 007  008 009 00a 00b 00c 00d 00e 00f
+00000010: 010 011
+";
+
+/// Returns the values `0...count - 1`.
+fn counting(count: u16) -> Vec<U12> {
+    (0..count).map(U12::from_u16).collect()
+}
+
+// MARK: - Tests - Values
+
+#[test]
+fn test_dump_values_empty() {
+    assert_eq!(dump_values(&[]), "");
+}
+
+#[test]
+fn test_dump_values_lines() {
+    assert_eq!(dump_values(&counting(18)), COUNTING_DUMP);
+    assert_eq!(dump_values(&counting(16)).lines().count(), 1);
+    assert_eq!(dump_values(&counting(17)).lines().count(), 2);
+}
+
+#[test]
+fn test_dump_values_offsets() {
+    let text = dump_values(&vec![U12::max_value(); 16 * 300]);
+    let last = text.lines().last().unwrap();
+    assert!(last.starts_with("000012b0: fff fff"));
+}
+
+#[test]
+fn test_write_values_to_formatter() {
+    struct Dump(Vec<U12>);
+    impl std::fmt::Display for Dump {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write_values(f, &self.0)
+        }
+    }
+    assert_eq!(Dump(counting(18)).to_string(), COUNTING_DUMP);
+}
+
+// MARK: - Tests - Packed Buffers
+
+#[test]
+fn test_dump_packed_matches_values() {
+    for count in 0..40u16 {
+        let values = counting(count);
+        let mut packed = vec![0; packed_len(values.len())];
+        pack_slice(&values, &mut packed);
+        assert_eq!(dump_packed(&packed), dump_values(&values));
+    }
+}
+
+#[test]
+fn test_dump_packed_odd_count() {
+    assert_eq!(dump_packed(&[0xAB, 0x0C]), "00000000: abc\n");
+}
+
+#[test]
+#[should_panic(expected = "invalid packed length")]
+fn test_dump_packed_invalid_length() {
+    let _ = dump_packed(&[0xAB, 0x12, 0x3C, 0xFE]);
+}
+
+#[test]
+fn test_dump_single_value() {
+    assert_eq!(dump_values(&[u12![0xA]]), "00000000: 00a\n");
+}
@@ -0,0 +1,82 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::ecc::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Encoding
+
+#[test]
+fn test_encode_layout() {
+    assert_eq!(encode(0x00), u12![0]);
+    // d0 lands at position 3 and is covered by p1 and p2.
+    assert_eq!(encode(0x01), u12![0b0000_0000_0111]);
+    // d7 lands at position 12 and is covered by p4 and p8.
+    assert_eq!(encode(0x80), u12![0b1000_1000_1000]);
+}
+
+#[test]
+fn test_encode_is_injective_with_minimum_distance_three() {
+    let codewords: Vec<U12> = (0..=255u8).map(encode).collect();
+    for (i, &a) in codewords.iter().enumerate() {
+        for &b in &codewords[i + 1..] {
+            assert!(a.hamming_distance(b) >= 3);
+        }
+    }
+}
+
+// MARK: - Tests - Decoding
+
+#[test]
+fn test_decode_round_trip() {
+    for data in 0..=255u8 {
+        assert_eq!(decode(encode(data)), Ok(data));
+    }
+}
+
+#[test]
+fn test_decode_corrects_single_bit_errors() {
+    for data in 0..=255u8 {
+        for bit in 0..12 {
+            let corrupted = encode(data) ^ U12::from_u16(1 << bit);
+            assert_eq!(decode(corrupted), Ok(data));
+        }
+    }
+}
+
+#[test]
+fn test_decode_never_accepts_double_bit_errors_unchanged() {
+    for data in 0..=255u8 {
+        for a in 0..12 {
+            for b in a + 1..12 {
+                let corrupted = encode(data) ^ U12::from_u16(1 << a | 1 << b);
+                assert_ne!(decode(corrupted), Ok(data));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_decode_miscorrects_in_range_double_bit_errors() {
+    // Positions 1 and 2 give syndrome 3, the position of data bit 0.
+    let corrupted = encode(0x3C) ^ u12![0b0000_0000_0011];
+    assert_eq!(decode(corrupted), Ok(0x3D));
+}
+
+#[test]
+fn test_decode_detects_out_of_range_syndromes() {
+    // Positions 5 and 8 give syndrome 13, which points outside the codeword.
+    let corrupted = encode(0x3C) ^ u12![0b0000_1001_0000];
+    let error = decode(corrupted).unwrap_err();
+    assert_eq!(error.syndrome(), 13);
+    assert_eq!(error.to_string(), "uncorrectable error (syndrome 13)");
+}
@@ -0,0 +1,98 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "embedded-hal")]
+
+#[macro_use]
+extern crate twelve_bit;
+extern crate embedded_hal;
+
+use embedded_hal::pwm::{ErrorType, SetDutyCycle};
+use twelve_bit::u12::*;
+
+/// A PWM output that records the last duty cycle it was given.
+struct FakePwm {
+    max: u16,
+    duty: Option<u16>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Disabled;
+
+impl embedded_hal::pwm::Error for Disabled {
+    fn kind(&self) -> embedded_hal::pwm::ErrorKind {
+        embedded_hal::pwm::ErrorKind::Other
+    }
+}
+
+impl ErrorType for FakePwm {
+    type Error = Disabled;
+}
+
+impl SetDutyCycle for FakePwm {
+    fn max_duty_cycle(&self) -> u16 {
+        self.max
+    }
+
+    fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Disabled> {
+        assert!(duty <= self.max);
+        if self.max == 0 {
+            return Err(Disabled);
+        }
+        self.duty = Some(duty);
+        Ok(())
+    }
+}
+
+// MARK: - Tests - Duty Cycle
+
+#[test]
+fn test_to_duty_cycle_endpoints() {
+    for &max in &[1, 100, 255, 4095, 4096, 65535] {
+        assert_eq!(U12::min_value().to_duty_cycle(max), 0);
+        assert_eq!(U12::max_value().to_duty_cycle(max), max);
+    }
+}
+
+#[test]
+fn test_to_duty_cycle_identity_at_full_scale() {
+    for value in 0..4096 {
+        let value = U12::from_u16(value);
+        assert_eq!(value.to_duty_cycle(4095), u16::from(value));
+    }
+}
+
+#[test]
+fn test_to_duty_cycle_is_monotonic() {
+    let mut previous = 0;
+    for value in 0..4096 {
+        let duty = U12::from_u16(value).to_duty_cycle(u16::MAX);
+        assert!(duty >= previous);
+        previous = duty;
+    }
+}
+
+#[test]
+fn test_set_duty_cycle_u12() {
+    let mut pwm = FakePwm {
+        max: 1000,
+        duty: None,
+    };
+    assert_eq!(pwm.set_duty_cycle_u12(u12![1024]), Ok(()));
+    assert_eq!(pwm.duty, Some(250));
+    pwm.set_duty_cycle_u12(U12::max_value()).unwrap();
+    assert_eq!(pwm.duty, Some(1000));
+}
+
+#[test]
+fn test_set_duty_cycle_u12_propagates_errors() {
+    let mut pwm = FakePwm { max: 0, duty: None };
+    assert_eq!(pwm.set_duty_cycle_u12(u12![1]), Err(Disabled));
+    assert_eq!(pwm.duty, None);
+}
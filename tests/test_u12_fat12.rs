@@ -0,0 +1,159 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::fat12::*;
+use twelve_bit::u12::*;
+
+/// The first bytes of the FAT of a freshly formatted 1.44 MB floppy holding one file in
+/// clusters 2, 3 and 4.
+const FLOPPY_FAT: [u8; 9] = [0xF0, 0xFF, 0xFF, 0x03, 0x40, 0x00, 0xFF, 0x0F, 0x00];
+
+// MARK: - Tests - Entries
+
+#[test]
+fn test_entry_count() {
+    assert_eq!(entry_count(0), 0);
+    assert_eq!(entry_count(1), 0);
+    assert_eq!(entry_count(2), 1);
+    assert_eq!(entry_count(3), 2);
+    assert_eq!(entry_count(4), 2);
+    assert_eq!(entry_count(5), 3);
+    assert_eq!(entry_count(512 * 9), 3072);
+}
+
+#[test]
+fn test_get_entry() {
+    let entries: Vec<Option<U12>> = (0..7).map(|i| get_entry(&FLOPPY_FAT, i)).collect();
+    assert_eq!(
+        entries,
+        [
+            Some(u12![0xFF0]),
+            Some(u12![0xFFF]),
+            Some(u12![0x003]),
+            Some(u12![0x004]),
+            Some(u12![0xFFF]),
+            Some(u12![0x000]),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_get_entry_huge_index() {
+    assert_eq!(get_entry(&FLOPPY_FAT, usize::MAX), None);
+    assert_eq!(get_entry(&FLOPPY_FAT, usize::MAX / 3 * 2), None);
+}
+
+#[test]
+fn test_get_entry_matches_entry_count() {
+    for len in 0..20 {
+        let table = vec![0; len];
+        for index in 0..20 {
+            assert_eq!(get_entry(&table, index).is_some(), index < entry_count(len));
+        }
+    }
+}
+
+#[test]
+fn test_set_entry_round_trip() {
+    let mut table = [0; 9];
+    for index in 0..6 {
+        set_entry(
+            &mut table,
+            index,
+            U12::from_u16(0xA01 + index as u16 * 0x111),
+        );
+    }
+    assert_eq!(&table[..3], &[0x01, 0x2A, 0xB1]);
+    for index in 0..6 {
+        assert_eq!(
+            get_entry(&table, index),
+            Some(U12::from_u16(0xA01 + index as u16 * 0x111))
+        );
+    }
+}
+
+#[test]
+fn test_set_entry_leaves_neighbors_untouched() {
+    for index in 0..6 {
+        let mut table = [0xFF; 9];
+        set_entry(&mut table, index, U12::min_value());
+        for other in 0..6 {
+            let expected = if other == index { 0 } else { 0xFFF };
+            assert_eq!(get_entry(&table, other), Some(U12::from_u16(expected)));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_set_entry_out_of_bounds() {
+    set_entry(&mut [0; 4], 2, u12![1]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_set_entry_huge_index() {
+    set_entry(&mut [0; 4], usize::MAX, u12![1]);
+}
+
+// MARK: - Tests - Cluster Chains
+
+#[test]
+fn test_chain() {
+    let clusters: Vec<U12> = chain(&FLOPPY_FAT, u12![2]).map(Result::unwrap).collect();
+    assert_eq!(clusters, [u12![2], u12![3], u12![4]]);
+}
+
+#[test]
+fn test_chain_empty_file() {
+    assert_eq!(chain(&FLOPPY_FAT, FREE).next(), None);
+}
+
+#[test]
+fn test_chain_invalid_start() {
+    let mut iter = chain(&FLOPPY_FAT, u12![1]);
+    assert_eq!(iter.next(), Some(Err(ChainError::InvalidCluster(u12![1]))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_chain_invalid_links() {
+    let mut table = [0; 12];
+    set_entry(&mut table, 2, u12![3]);
+    set_entry(&mut table, 3, FREE);
+    let result: Result<Vec<U12>, ChainError> = chain(&table, u12![2]).collect();
+    assert_eq!(result, Err(ChainError::InvalidCluster(FREE)));
+
+    set_entry(&mut table, 3, BAD_CLUSTER);
+    let result: Result<Vec<U12>, ChainError> = chain(&table, u12![2]).collect();
+    assert_eq!(result, Err(ChainError::InvalidCluster(BAD_CLUSTER)));
+
+    set_entry(&mut table, 3, u12![100]);
+    let result: Result<Vec<U12>, ChainError> = chain(&table, u12![2]).collect();
+    assert_eq!(result, Err(ChainError::InvalidCluster(u12![100])));
+}
+
+#[test]
+fn test_chain_cycle() {
+    let mut table = [0; 12];
+    set_entry(&mut table, 2, u12![3]);
+    set_entry(&mut table, 3, u12![2]);
+    let items: Vec<Result<U12, ChainError>> = chain(&table, u12![2]).collect();
+    assert_eq!(items.len(), 9);
+    assert_eq!(items.last(), Some(&Err(ChainError::Cycle)));
+    assert_eq!(ChainError::Cycle.to_string(), "chain contains a cycle");
+    assert_eq!(
+        ChainError::InvalidCluster(u12![0xFF7]).to_string(),
+        "chain links to invalid cluster 0xff7"
+    );
+}
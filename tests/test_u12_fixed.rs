@@ -0,0 +1,121 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::fixed::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Float Conversions
+
+#[test]
+fn test_uq0_12_float_conversions() {
+    assert_eq!(UQ0_12::from_f32(0.0), Some(UQ0_12::MIN));
+    assert_eq!(UQ0_12::from_f32(0.5), Some(UQ0_12::from_bits(u12![0x800])));
+    assert_eq!(UQ0_12::from_f64(4095.0 / 4096.0), Some(UQ0_12::MAX));
+    assert_eq!(
+        UQ0_12::from_f64(0.25 + 0.4 / 4096.0).unwrap().to_bits(),
+        u12![0x400]
+    );
+    assert_eq!(
+        UQ0_12::from_f64(0.25 + 0.6 / 4096.0).unwrap().to_bits(),
+        u12![0x401]
+    );
+    assert_eq!(UQ0_12::from_f32(1.0), None);
+    assert_eq!(UQ0_12::from_f32(-0.001), None);
+    assert_eq!(UQ0_12::from_f32(f32::NAN), None);
+    assert_eq!(UQ0_12::from_bits(u12![0x400]).to_f32(), 0.25);
+    assert_eq!(UQ0_12::MAX.to_f64(), 4095.0 / 4096.0);
+}
+
+#[test]
+fn test_uq4_8_float_conversions() {
+    assert_eq!(UQ4_8::from_f32(1.0), Some(UQ4_8::ONE));
+    assert_eq!(UQ4_8::from_f32(1.5), Some(UQ4_8::from_bits(u12![0x180])));
+    assert_eq!(UQ4_8::from_f64(4095.0 / 256.0), Some(UQ4_8::MAX));
+    assert_eq!(UQ4_8::from_f32(16.0), None);
+    assert_eq!(UQ4_8::from_f32(f32::INFINITY), None);
+    assert_eq!(UQ4_8::ONE.to_f32(), 1.0);
+    assert_eq!(UQ4_8::EPSILON.to_f64(), 1.0 / 256.0);
+}
+
+#[test]
+fn test_float_round_trip() {
+    for bits in 0u16..4096 {
+        let bits: U12 = bits.unchecked_into();
+        assert_eq!(
+            UQ0_12::from_f32(UQ0_12::from_bits(bits).to_f32())
+                .unwrap()
+                .to_bits(),
+            bits
+        );
+        assert_eq!(
+            UQ4_8::from_f32(UQ4_8::from_bits(bits).to_f32())
+                .unwrap()
+                .to_bits(),
+            bits
+        );
+    }
+}
+
+// MARK: - Tests - Arithmetic
+
+#[test]
+fn test_addition_and_subtraction() {
+    let a = UQ4_8::from_f32(1.5).unwrap();
+    let b = UQ4_8::from_f32(2.25).unwrap();
+    assert_eq!((a + b).to_f32(), 3.75);
+    assert_eq!((b - a).to_f32(), 0.75);
+    assert_eq!(a.checked_sub(b), None);
+    assert_eq!(a.saturating_sub(b), UQ4_8::MIN);
+    assert_eq!(UQ4_8::MAX.checked_add(UQ4_8::EPSILON), None);
+    assert_eq!(UQ4_8::MAX.saturating_add(a), UQ4_8::MAX);
+}
+
+#[test]
+fn test_multiplication_is_scaled() {
+    let half = UQ0_12::from_f32(0.5).unwrap();
+    let quarter = UQ0_12::from_f32(0.25).unwrap();
+    assert_eq!(half * half, quarter);
+    assert_eq!(UQ0_12::MAX * UQ0_12::MAX, UQ0_12::from_bits(u12![0xFFE]));
+    assert_eq!(UQ0_12::EPSILON * half, UQ0_12::EPSILON);
+    assert_eq!(UQ0_12::EPSILON * quarter, UQ0_12::MIN);
+
+    let one_and_half = UQ4_8::from_f32(1.5).unwrap();
+    assert_eq!((one_and_half * one_and_half).to_f32(), 2.25);
+    assert_eq!(UQ4_8::ONE * UQ4_8::MAX, UQ4_8::MAX);
+    assert_eq!(
+        UQ4_8::from_f32(4.0)
+            .unwrap()
+            .checked_mul(UQ4_8::from_f32(4.0).unwrap()),
+        None
+    );
+    assert_eq!(
+        UQ4_8::from_f32(4.0)
+            .unwrap()
+            .saturating_mul(UQ4_8::from_f32(4.0).unwrap()),
+        UQ4_8::MAX
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_multiplication_overflow() {
+    let _ = UQ4_8::from_f32(8.0).unwrap() * UQ4_8::from_f32(2.0).unwrap();
+}
+
+// MARK: - Tests - Formatting
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", UQ4_8::from_f32(1.5).unwrap()), "1.5");
+    assert_eq!(format!("{:.3}", UQ0_12::from_f32(0.5).unwrap()), "0.500");
+    assert_eq!(format!("{:>6.2}", UQ4_8::ONE), "  1.00");
+}
@@ -0,0 +1,150 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::float::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Constants
+
+#[test]
+fn test_constants() {
+    assert_eq!(F12::ZERO.to_f32(), 0.0);
+    assert_eq!(F12::MAX.to_f32(), 65024.0);
+    assert_eq!(F12::MIN.to_f32(), -65024.0);
+    assert_eq!(F12::MIN_POSITIVE.to_f32(), 2f32.powi(-14));
+    assert_eq!(F12::INFINITY.to_f32(), f32::INFINITY);
+    assert_eq!(F12::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+    assert!(F12::NAN.to_f32().is_nan());
+    assert_eq!(F12E4M7::MAX.to_f32(), 255.0);
+    assert_eq!(F12E4M7::MIN_POSITIVE.to_f32(), 2f32.powi(-6));
+}
+
+#[test]
+fn test_bit_layout() {
+    // Sign 0, exponent 01111, mantissa 000000.
+    assert_eq!(F12::from_f32(1.0).to_bits(), u12![0x3C0]);
+    // Sign 1, exponent 10000, mantissa 000000.
+    assert_eq!(F12::from_f32(-2.0).to_bits(), u12![0xC00]);
+    // Sign 0, exponent 01111, mantissa 100000.
+    assert_eq!(F12::from_f32(1.5).to_bits(), u12![0x3E0]);
+    // Sign 0, exponent 0111, mantissa 0000000.
+    assert_eq!(F12E4M7::from_f32(1.0).to_bits(), u12![0x380]);
+}
+
+// MARK: - Tests - Conversions
+
+#[test]
+fn test_round_trip_all_values() {
+    for bits in 0..4096u16 {
+        let value = F12::from_bits(U12::from_u16(bits));
+        if value.is_nan() {
+            assert!(value.to_f32().is_nan());
+        } else {
+            assert_eq!(F12::from_f32(value.to_f32()).to_bits(), value.to_bits());
+        }
+        let value = F12E4M7::from_bits(U12::from_u16(bits));
+        if !value.is_nan() {
+            assert_eq!(F12E4M7::from_f32(value.to_f32()).to_bits(), value.to_bits());
+        }
+    }
+}
+
+#[test]
+fn test_from_f32_rounds_to_nearest_even() {
+    // Between 1.0 and 2.0 the spacing of F12 values is 1/64.
+    let step = 1.0 / 64.0;
+    assert_eq!(F12::from_f32(1.0 + step * 0.4).to_f32(), 1.0);
+    assert_eq!(F12::from_f32(1.0 + step * 0.6).to_f32(), 1.0 + step);
+    assert_eq!(F12::from_f32(1.0 + step * 0.5).to_f32(), 1.0);
+    assert_eq!(F12::from_f32(1.0 + step * 1.5).to_f32(), 1.0 + step * 2.0);
+    // Rounding up the largest mantissa carries into the exponent.
+    assert_eq!(F12::from_f32(2.0 - step * 0.25).to_f32(), 2.0);
+}
+
+#[test]
+fn test_from_f32_subnormals() {
+    let smallest = 2f32.powi(-20);
+    assert_eq!(F12::from_f32(smallest).to_bits(), u12![1]);
+    assert_eq!(F12::from_f32(smallest * 0.4).to_bits(), u12![0]);
+    assert_eq!(F12::from_f32(smallest * 0.6).to_bits(), u12![1]);
+    assert_eq!(F12::from_f32(-smallest * 0.4).to_bits(), u12![0x800]);
+    assert_eq!(
+        F12::from_f32(2f32.powi(-14) - smallest * 0.4),
+        F12::MIN_POSITIVE
+    );
+}
+
+#[test]
+fn test_from_f32_special_values() {
+    assert!(F12::from_f32(f32::NAN).is_nan());
+    assert_eq!(
+        F12::from_f32(f32::INFINITY).to_bits(),
+        F12::INFINITY.to_bits()
+    );
+    assert_eq!(
+        F12::from_f32(f32::NEG_INFINITY).to_bits(),
+        F12::NEG_INFINITY.to_bits()
+    );
+    assert_eq!(F12::from_f32(65024.0).to_bits(), F12::MAX.to_bits());
+    assert_eq!(F12::from_f32(65535.0).to_bits(), F12::INFINITY.to_bits());
+    assert_eq!(F12::from_f32(-1e30).to_bits(), F12::NEG_INFINITY.to_bits());
+    assert_eq!(F12::from_f32(-0.0).to_bits(), u12![0x800]);
+    assert!(F12::from_f32(-0.0).is_sign_negative());
+}
+
+// MARK: - Tests - Classification
+
+#[test]
+fn test_classification() {
+    assert!(F12::NAN.is_nan() && !F12::NAN.is_finite() && !F12::NAN.is_infinite());
+    assert!(F12::INFINITY.is_infinite() && !F12::INFINITY.is_nan());
+    assert!(F12::NEG_INFINITY.is_infinite() && F12::NEG_INFINITY.is_sign_negative());
+    assert!(F12::MAX.is_finite() && !F12::MAX.is_sign_negative());
+}
+
+// MARK: - Tests - Comparison
+
+#[test]
+fn test_comparison() {
+    assert_eq!(F12::from_f32(0.0), F12::from_f32(-0.0));
+    assert_ne!(F12::NAN, F12::NAN);
+    assert!(F12::from_f32(1.0) < F12::from_f32(2.0));
+    assert!(F12::MIN < F12::MAX);
+    assert!(F12::NEG_INFINITY < F12::MIN);
+    assert_eq!(F12::NAN.partial_cmp(&F12::ZERO), None);
+}
+
+// MARK: - Tests - Arithmetic
+
+#[test]
+fn test_arithmetic() {
+    let (a, b) = (F12::from_f32(1.5), F12::from_f32(0.25));
+    assert_eq!((a + b).to_f32(), 1.75);
+    assert_eq!((a - b).to_f32(), 1.25);
+    assert_eq!((a * b).to_f32(), 0.375);
+    assert_eq!((a / b).to_f32(), 6.0);
+    assert_eq!((-a).to_f32(), -1.5);
+    assert!((F12::MAX + F12::MAX).is_infinite());
+    assert!((F12::ZERO / F12::ZERO).is_nan());
+    // 1 + 1/128 is not representable, so the sum rounds back to 1.
+    assert_eq!(
+        (F12::from_f32(1.0) + F12::from_f32(1.0 / 128.0)).to_f32(),
+        1.0
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", F12::from_f32(1.5)), "1.5");
+    assert_eq!(format!("{:.2}", F12E4M7::from_f32(0.5)), "0.50");
+    assert_eq!(format!("{}", F12::NEG_INFINITY), "-inf");
+}
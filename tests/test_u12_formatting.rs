@@ -0,0 +1,185 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Display
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", u12![0]), "0");
+    assert_eq!(format!("{}", u12![4095]), "4095");
+    assert_eq!(format!("{:5}", u12![42]), "   42");
+    assert_eq!(format!("{:<5}|", u12![42]), "42   |");
+    assert_eq!(format!("{:^6}|", u12![42]), "  42  |");
+    assert_eq!(format!("{:*>5}", u12![42]), "***42");
+    assert_eq!(format!("{:05}", u12![42]), "00042");
+    assert_eq!(format!("{:+}", u12![42]), "+42");
+}
+
+// MARK: - Tests - Debug Formatting
+
+#[test]
+fn test_debug() {
+    assert_eq!(format!("{:?}", U12::min_value()), "U12(0x000)");
+    assert_eq!(format!("{:?}", u12![0xABC]), "U12(0xABC)");
+    assert_eq!(
+        format!("{:?}", [u12![1], u12![0x10]]),
+        "[U12(0x001), U12(0x010)]"
+    );
+}
+
+#[test]
+fn test_debug_alternate() {
+    assert_eq!(format!("{:#?}", U12::max_value()), "U12(0xFFF = 4095)");
+    assert_eq!(
+        format!("{:#?}", Some(u12![7])),
+        "Some(\n    U12(0x007 = 7),\n)"
+    );
+}
+
+// MARK: - Tests - Radix Formatting
+
+#[test]
+fn test_hex() {
+    assert_eq!(format!("{:x}", u12![0xABC]), "abc");
+    assert_eq!(format!("{:X}", u12![0xABC]), "ABC");
+    assert_eq!(format!("{:03X}", u12![0xA]), "00A");
+    assert_eq!(format!("{:#x}", u12![0xABC]), "0xabc");
+    assert_eq!(format!("{:#06x}", u12![0xA]), "0x000a");
+    assert_eq!(format!("{:>#06x}", u12![0xA]), format!("{:>#06x}", 0xAu16));
+    assert_eq!(format!("{:>#6x}", u12![0xA]), "   0xa");
+    assert_eq!(format!("{:<#6X}|", u12![0xA]), "0xA   |");
+}
+
+#[test]
+fn test_octal() {
+    assert_eq!(format!("{:o}", U12::max_value()), "7777");
+    assert_eq!(format!("{:#o}", u12![8]), "0o10");
+    assert_eq!(format!("{:04o}", u12![8]), "0010");
+}
+
+#[test]
+fn test_binary() {
+    assert_eq!(format!("{:b}", u12![5]), "101");
+    assert_eq!(format!("{:012b}", u12![5]), "000000000101");
+    assert_eq!(format!("{:#014b}", u12![5]), "0b000000000101");
+    assert_eq!(format!("{:>6b}", u12![5]), "   101");
+}
+
+// MARK: - Tests - Radix Strings
+
+#[test]
+fn test_to_string_radix() {
+    assert_eq!(u12![0].to_string_radix(2), "0");
+    assert_eq!(u12![0xFFF].to_string_radix(2), "111111111111");
+    assert_eq!(u12![0xFFF].to_string_radix(8), "7777");
+    assert_eq!(u12![0xFFF].to_string_radix(10), "4095");
+    assert_eq!(u12![0xFFF].to_string_radix(16), "fff");
+    assert_eq!(u12![0xFFF].to_string_radix(36), "35r");
+}
+
+#[test]
+fn test_to_string_radix_round_trip() {
+    for radix in 2..=36 {
+        for value in 0..4096u16 {
+            let value = U12::from_u16(value);
+            assert_eq!(
+                U12::from_str_radix(&value.to_string_radix(radix), radix),
+                Ok(value)
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2 to 36")]
+fn test_to_string_radix_invalid_radix() {
+    let _ = u12![1].to_string_radix(37);
+}
+
+#[test]
+fn test_digits() {
+    assert_eq!(u12![0].digits(10).collect::<Vec<u8>>(), [0]);
+    assert_eq!(u12![9].digits(10).collect::<Vec<u8>>(), [9]);
+    assert_eq!(u12![10].digits(10).collect::<Vec<u8>>(), [1, 0]);
+    assert_eq!(u12![4095].digits(10).collect::<Vec<u8>>(), [4, 0, 9, 5]);
+    assert_eq!(u12![0x1A0].digits(16).collect::<Vec<u8>>(), [1, 10, 0]);
+}
+
+#[test]
+fn test_digits_exact_size() {
+    let mut digits = u12![0b1000].digits(2);
+    assert_eq!(digits.len(), 4);
+    digits.next();
+    assert_eq!(digits.len(), 3);
+    assert_eq!(digits.by_ref().count(), 3);
+    assert_eq!(digits.len(), 0);
+    assert_eq!(digits.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2 to 36")]
+fn test_digits_invalid_radix() {
+    let _ = u12![1].digits(1);
+}
+
+// MARK: - Tests - Buffer Formatting
+
+#[test]
+fn test_write_dec() {
+    let mut buf = [0xFF; 4];
+    assert_eq!(u12![0].write_dec(&mut buf), "0");
+    assert_eq!(u12![42].write_dec(&mut buf), "42");
+    assert_eq!(u12![4095].write_dec(&mut buf), "4095");
+}
+
+#[test]
+fn test_write_hex() {
+    let mut buf = [0; 3];
+    assert_eq!(u12![0].write_hex(&mut buf), "0");
+    assert_eq!(u12![0xF].write_hex(&mut buf), "f");
+    assert_eq!(u12![0xFFF].write_hex(&mut buf), "fff");
+}
+
+#[test]
+fn test_write_oct() {
+    let mut buf = [0; 4];
+    assert_eq!(u12![0].write_oct(&mut buf), "0");
+    assert_eq!(u12![0o10].write_oct(&mut buf), "10");
+    assert_eq!(u12![0o7777].write_oct(&mut buf), "7777");
+}
+
+#[test]
+fn test_write_matches_fmt() {
+    let mut buf = [0; 8];
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        assert_eq!(value.write_dec(&mut buf), format!("{}", value));
+        assert_eq!(value.write_hex(&mut buf), format!("{:x}", value));
+        assert_eq!(value.write_oct(&mut buf), format!("{:o}", value));
+    }
+}
+
+#[test]
+fn test_write_into_larger_buffer_leaves_rest_untouched() {
+    let mut buf = [b'#'; 6];
+    assert_eq!(u12![123].write_dec(&mut buf), "123");
+    assert_eq!(&buf[3..], b"###");
+}
+
+#[test]
+#[should_panic(expected = "buffer too small")]
+fn test_write_dec_buffer_too_small() {
+    let mut buf = [0; 3];
+    let _ = u12![1000].write_dec(&mut buf);
+}
@@ -0,0 +1,74 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::frames::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - MCP4921
+
+#[test]
+fn test_mcp4921_frame_config_bits() {
+    let base = DacConfig::default();
+    assert_eq!(mcp4921_frame(U12::min_value(), base), 0x3000);
+    let channel_b = DacConfig {
+        channel: DacChannel::B,
+        ..base
+    };
+    assert_eq!(mcp4921_frame(U12::min_value(), channel_b), 0xB000);
+    let buffered = DacConfig {
+        buffered: true,
+        ..base
+    };
+    assert_eq!(mcp4921_frame(U12::min_value(), buffered), 0x7000);
+    let double_gain = DacConfig {
+        gain: DacGain::X2,
+        ..base
+    };
+    assert_eq!(mcp4921_frame(U12::min_value(), double_gain), 0x1000);
+    let shutdown = DacConfig {
+        shutdown: true,
+        ..base
+    };
+    assert_eq!(mcp4921_frame(U12::min_value(), shutdown), 0x2000);
+}
+
+#[test]
+fn test_mcp4921_frame_value_bits() {
+    for value in 0..4096 {
+        let frame = mcp4921_frame(U12::from_u16(value), DacConfig::default());
+        assert_eq!(frame & 0xFFF, value);
+        assert_eq!(frame >> 12, 0x3);
+    }
+}
+
+// MARK: - Tests - MCP3208
+
+#[test]
+fn test_mcp3208_command() {
+    assert_eq!(mcp3208_command(0, true), [0x06, 0x00, 0x00]);
+    assert_eq!(mcp3208_command(3, true), [0x06, 0xC0, 0x00]);
+    assert_eq!(mcp3208_command(4, true), [0x07, 0x00, 0x00]);
+    assert_eq!(mcp3208_command(7, false), [0x05, 0xC0, 0x00]);
+}
+
+#[test]
+#[should_panic(expected = "channel out of range")]
+fn test_mcp3208_command_invalid_channel() {
+    let _ = mcp3208_command(8, true);
+}
+
+#[test]
+fn test_mcp3208_parse() {
+    assert_eq!(mcp3208_parse([0x00, 0x00, 0x00]), U12::min_value());
+    assert_eq!(mcp3208_parse([0x00, 0x0F, 0xFF]), U12::max_value());
+    assert_eq!(mcp3208_parse([0xFF, 0xF1, 0x23]), u12![0x123]);
+}
@@ -0,0 +1,113 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::histogram::Histogram12;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Recording
+
+#[test]
+fn test_histogram_new_is_empty() {
+    let histogram = Histogram12::new();
+    assert!(histogram.is_empty());
+    assert_eq!(histogram.total(), 0);
+    assert_eq!(histogram.counts().len(), 4096);
+    assert_eq!(histogram.min(), None);
+    assert_eq!(histogram.max(), None);
+    assert_eq!(histogram.median(), None);
+    assert_eq!(histogram, Histogram12::default());
+}
+
+#[test]
+fn test_histogram_record() {
+    let mut histogram = Histogram12::new();
+    histogram.record(u12![0]);
+    histogram.record(u12![4095]);
+    histogram.record(u12![4095]);
+    assert_eq!(histogram.count(u12![0]), 1);
+    assert_eq!(histogram.count(u12![4095]), 2);
+    assert_eq!(histogram.count(u12![1]), 0);
+    assert_eq!(histogram.total(), 3);
+    assert_eq!(histogram.min(), Some(u12![0]));
+    assert_eq!(histogram.max(), Some(u12![4095]));
+}
+
+#[test]
+fn test_histogram_record_slice_matches_record() {
+    let values: Vec<U12> = (0..10000u32)
+        .map(|i| U12::from_u16((i * 7 % 4096) as u16))
+        .collect();
+    let mut bulk = Histogram12::new();
+    bulk.record_slice(&values);
+    let mut single = Histogram12::new();
+    for &value in &values {
+        single.record(value);
+    }
+    assert_eq!(bulk, single);
+
+    let mut extended = Histogram12::new();
+    extended.extend(values.iter().cloned());
+    assert_eq!(extended, single);
+}
+
+// MARK: - Tests - Percentiles
+
+#[test]
+fn test_histogram_percentile() {
+    let mut histogram = Histogram12::new();
+    let values: Vec<U12> = (1..=100).map(U12::from_u16).collect();
+    histogram.record_slice(&values);
+    assert_eq!(histogram.percentile(0.0), Some(u12![1]));
+    assert_eq!(histogram.percentile(1.0), Some(u12![1]));
+    assert_eq!(histogram.percentile(1.5), Some(u12![2]));
+    assert_eq!(histogram.percentile(90.0), Some(u12![90]));
+    assert_eq!(histogram.percentile(100.0), Some(u12![100]));
+}
+
+#[test]
+fn test_histogram_median() {
+    let mut histogram = Histogram12::new();
+    histogram.record_slice(&[u12![5], u12![1], u12![3]]);
+    assert_eq!(histogram.median(), Some(u12![3]));
+    histogram.record(u12![4]);
+    assert_eq!(histogram.median(), Some(u12![3]));
+}
+
+#[test]
+#[should_panic(expected = "percentile must be in the range 0 to 100")]
+fn test_histogram_percentile_out_of_range() {
+    let _ = Histogram12::new().percentile(100.5);
+}
+
+#[test]
+#[should_panic(expected = "percentile must be in the range 0 to 100")]
+fn test_histogram_percentile_nan() {
+    let _ = Histogram12::new().percentile(f64::NAN);
+}
+
+// MARK: - Tests - Merging
+
+#[test]
+fn test_histogram_merge() {
+    let mut a = Histogram12::new();
+    a.record_slice(&[u12![1], u12![2]]);
+    let mut b = Histogram12::new();
+    b.record_slice(&[u12![2], u12![3]]);
+    a.merge(&b);
+    assert_eq!(a.total(), 4);
+    assert_eq!(a.count(u12![2]), 2);
+    assert_eq!(a.count(u12![3]), 1);
+
+    a.clear();
+    assert!(a.is_empty());
+    assert_eq!(a, Histogram12::new());
+}
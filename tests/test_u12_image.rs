@@ -0,0 +1,138 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::image::*;
+use twelve_bit::u12::packing::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Construction
+
+#[test]
+fn test_new_is_zeroed() {
+    let image = Image12::new(5, 3);
+    assert_eq!(image.width(), 5);
+    assert_eq!(image.height(), 3);
+    assert_eq!(image.as_packed().len(), packed_len(5) * 3);
+    assert!(image.to_u16_samples().iter().all(|&sample| sample == 0));
+}
+
+#[test]
+fn test_from_packed() {
+    let image = Image12::from_packed(3, 1, vec![0xAB, 0x12, 0x3C, 0xFE, 0x0D]).unwrap();
+    assert_eq!(image.get_pixel(0, 0), u12![0xABC]);
+    assert_eq!(image.get_pixel(1, 0), u12![0x123]);
+    assert_eq!(image.get_pixel(2, 0), u12![0xFED]);
+    assert_eq!(Image12::from_packed(3, 1, vec![0; 4]), None);
+}
+
+// MARK: - Tests - Pixel Access
+
+#[test]
+fn test_set_pixel_matches_packing() {
+    for width in 1..8 {
+        let mut image = Image12::new(width, 2);
+        let row: Vec<U12> = (0..width).map(|x| U12::from(x as u8 * 37)).collect();
+        for (x, &value) in row.iter().enumerate() {
+            image.set_pixel(x, 1, value);
+        }
+        let mut expected = vec![0; packed_len(width)];
+        pack_slice(&row, &mut expected);
+        assert_eq!(image.packed_row(1), &expected[..]);
+        assert!(image.packed_row(0).iter().all(|&byte| byte == 0));
+        for (x, &value) in row.iter().enumerate() {
+            assert_eq!(image.get_pixel(x, 1), value);
+        }
+    }
+}
+
+#[test]
+fn test_set_pixel_preserves_neighbours() {
+    let mut image = Image12::new(2, 1);
+    image.set_pixel(0, 0, u12![0xFFF]);
+    image.set_pixel(1, 0, u12![0xFFF]);
+    image.set_pixel(0, 0, u12![0x000]);
+    assert_eq!(image.get_pixel(1, 0), u12![0xFFF]);
+    image.set_pixel(1, 0, u12![0x000]);
+    assert_eq!(image.as_packed(), &[0, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_get_pixel_out_of_bounds() {
+    Image12::new(2, 2).get_pixel(2, 0);
+}
+
+#[test]
+#[should_panic]
+fn test_set_pixel_out_of_bounds() {
+    Image12::new(2, 2).set_pixel(0, 2, u12![1]);
+}
+
+// MARK: - Tests - Rows
+
+#[test]
+fn test_rows() {
+    let samples: Vec<u16> = (0..15).map(|sample| sample * 273).collect();
+    let image = Image12::from_u16_samples(5, 3, &samples).unwrap();
+    let rows = image.rows();
+    assert_eq!(rows.len(), 3);
+    for (y, row) in rows.enumerate() {
+        let expected: Vec<U12> = samples[y * 5..(y + 1) * 5]
+            .iter()
+            .map(|&sample| U12::from_u16(sample))
+            .collect();
+        assert_eq!(row, expected);
+    }
+}
+
+#[test]
+fn test_set_row() {
+    let mut image = Image12::new(3, 2);
+    image.set_row(1, &[u12![1], u12![2], u12![3]]);
+    let mut row = [U12::min_value(); 3];
+    image.read_row(1, &mut row);
+    assert_eq!(row, [u12![1], u12![2], u12![3]]);
+    assert_eq!(image.to_u16_samples(), vec![0, 0, 0, 1, 2, 3]);
+}
+
+// MARK: - Tests - Sample Conversions
+
+#[test]
+fn test_u16_samples() {
+    let samples = [0x000, 0x123, 0xFFF, 0x800];
+    let image = Image12::from_u16_samples(2, 2, &samples).unwrap();
+    assert_eq!(image.to_u16_samples(), samples.to_vec());
+    assert_eq!(Image12::from_u16_samples(2, 2, &samples[..3]), None);
+    assert_eq!(Image12::from_u16_samples(2, 2, &[0, 0, 0, 0x1000]), None);
+}
+
+#[test]
+fn test_u8_samples() {
+    let image = Image12::from_u8_samples(3, 1, &[0x00, 0x80, 0xFF]).unwrap();
+    assert_eq!(image.to_u16_samples(), vec![0x000, 0x808, 0xFFF]);
+    assert_eq!(image.to_u8_samples(), vec![0x00, 0x80, 0xFF]);
+    assert_eq!(Image12::from_u8_samples(3, 1, &[0; 2]), None);
+}
+
+#[test]
+fn test_u8_samples_round_trip() {
+    let samples: Vec<u8> = (0..=255).collect();
+    let image = Image12::from_u8_samples(16, 16, &samples).unwrap();
+    assert_eq!(image.to_u8_samples(), samples);
+}
+
+#[test]
+fn test_empty_image() {
+    let image = Image12::from_u16_samples(0, 4, &[]).unwrap();
+    assert_eq!(image.rows().count(), 4);
+    assert!(image.to_u16_samples().is_empty());
+}
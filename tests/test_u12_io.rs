@@ -0,0 +1,200 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use std::io::ErrorKind;
+use twelve_bit::u12::io::*;
+use twelve_bit::u12::packing::pack_slice;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Single Values
+
+#[test]
+fn test_read_u12() {
+    let mut reader: &[u8] = &[0x0F, 0xFF, 0xFF, 0x0F, 0x12, 0x03];
+    assert_eq!(reader.read_u12_be().unwrap(), U12::max_value());
+    assert_eq!(reader.read_u12_le().unwrap(), U12::max_value());
+    assert_eq!(reader.read_u12_le().unwrap(), u12![0x312]);
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn test_read_u12_errors() {
+    let mut reader: &[u8] = &[0x10, 0x00];
+    assert_eq!(
+        reader.read_u12_be().unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    let mut reader: &[u8] = &[0x00, 0x10];
+    assert_eq!(
+        reader.read_u12_le().unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    let mut reader: &[u8] = &[0x00];
+    assert_eq!(
+        reader.read_u12_be().unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_write_u12() {
+    let mut writer = Vec::new();
+    writer.write_u12_be(u12![0x123]).unwrap();
+    writer.write_u12_le(u12![0x123]).unwrap();
+    assert_eq!(writer, [0x01, 0x23, 0x23, 0x01]);
+}
+
+// MARK: - Tests - Packed Pairs
+
+#[test]
+fn test_pair_packed_matches_packing() {
+    let values = [u12![0xABC], u12![0x123], u12![0xFFF], u12![0x000]];
+    let mut expected = [0u8; 6];
+    pack_slice(&values, &mut expected);
+
+    let mut writer = Vec::new();
+    for pair in values.chunks(2) {
+        writer.write_u12_pair_packed(pair[0], pair[1]).unwrap();
+    }
+    assert_eq!(writer, expected);
+
+    let mut reader: &[u8] = &expected;
+    assert_eq!(
+        reader.read_u12_pair_packed().unwrap(),
+        (values[0], values[1])
+    );
+    assert_eq!(
+        reader.read_u12_pair_packed().unwrap(),
+        (values[2], values[3])
+    );
+    assert_eq!(
+        reader.read_u12_pair_packed().unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let mut writer = Vec::new();
+    for value in 0..4096u16 {
+        writer.write_u12_be(U12::from_u16(value)).unwrap();
+        writer.write_u12_le(U12::from_u16(value)).unwrap();
+    }
+    let mut reader: &[u8] = &writer;
+    for value in 0..4096u16 {
+        assert_eq!(reader.read_u12_be().unwrap(), U12::from_u16(value));
+        assert_eq!(reader.read_u12_le().unwrap(), U12::from_u16(value));
+    }
+}
+
+// MARK: - Tests - Variable-Width Codes
+
+/// Returns a sequence of codes with the widths an LZW encoder would use, growing from 9 bits
+/// to 12 bits as the code values grow.
+fn lzw_like_codes() -> Vec<(U12, u32)> {
+    (0..3200u32)
+        .map(|i| {
+            let width = 9 + i / 800;
+            let code = U12::from_u16(((i * 37 + 256) % (1 << width)) as u16);
+            (code, width)
+        })
+        .collect()
+}
+
+#[test]
+fn test_code_writer_lsb_first() {
+    let mut writer = CodeWriter::new(Vec::new(), CodeOrder::LsbFirst, 9);
+    writer.write_code(u12![0x100]).unwrap();
+    writer.write_code(u12![0x041]).unwrap();
+    assert_eq!(writer.finish().unwrap(), [0x00, 0x83, 0x00]);
+}
+
+#[test]
+fn test_code_writer_msb_first() {
+    let mut writer = CodeWriter::new(Vec::new(), CodeOrder::MsbFirst, 9);
+    writer.write_code(u12![0x100]).unwrap();
+    writer.write_code(u12![0x041]).unwrap();
+    assert_eq!(writer.finish().unwrap(), [0x80, 0x10, 0x40]);
+}
+
+#[test]
+fn test_code_round_trip_growing_width() {
+    for &order in &[CodeOrder::LsbFirst, CodeOrder::MsbFirst] {
+        let codes = lzw_like_codes();
+        let mut writer = CodeWriter::new(Vec::new(), order, 9);
+        for &(code, width) in &codes {
+            writer.set_width(width);
+            writer.write_code(code).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+        let total_bits: u32 = codes.iter().map(|&(_, width)| width).sum();
+        assert_eq!(bytes.len() as u32, total_bits.div_ceil(8));
+
+        let mut reader = CodeReader::new(&bytes[..], order, 9);
+        for &(code, width) in &codes {
+            reader.set_width(width);
+            assert_eq!(reader.width(), width);
+            assert_eq!(reader.read_code().unwrap(), Some(code));
+        }
+        assert_eq!(reader.read_code().unwrap(), None);
+    }
+}
+
+#[test]
+fn test_code_round_trip_every_width() {
+    for &order in &[CodeOrder::LsbFirst, CodeOrder::MsbFirst] {
+        for width in 1..=12 {
+            let codes: Vec<U12> = (0..100u16)
+                .map(|i| U12::from_u16(i * 41 % (1 << width)))
+                .collect();
+            let mut writer = CodeWriter::new(Vec::new(), order, width);
+            for &code in &codes {
+                writer.write_code(code).unwrap();
+            }
+            let bytes = writer.finish().unwrap();
+            let mut reader = CodeReader::new(&bytes[..], order, width);
+            for &code in &codes {
+                assert_eq!(reader.read_code().unwrap(), Some(code));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_code_writer_rejects_wide_code() {
+    let mut writer = CodeWriter::new(Vec::new(), CodeOrder::LsbFirst, 9);
+    let error = writer.write_code(u12![0x200]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    assert_eq!(writer.width(), 9);
+    assert_eq!(writer.finish().unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn test_code_reader_end_of_stream() {
+    let mut reader = CodeReader::new(&[0xFF][..], CodeOrder::MsbFirst, 9);
+    assert_eq!(reader.read_code().unwrap(), None);
+    let mut reader = CodeReader::new(&[][..], CodeOrder::LsbFirst, 1);
+    assert_eq!(reader.read_code().unwrap(), None);
+}
+
+#[test]
+#[should_panic(expected = "code width must be in the range 1 to 12")]
+fn test_code_writer_invalid_width() {
+    let _ = CodeWriter::new(Vec::new(), CodeOrder::LsbFirst, 13);
+}
+
+#[test]
+#[should_panic(expected = "code width must be in the range 1 to 12")]
+fn test_code_reader_invalid_width() {
+    let mut reader = CodeReader::new(&[][..], CodeOrder::MsbFirst, 9);
+    reader.set_width(0);
+}
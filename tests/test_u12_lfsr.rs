@@ -0,0 +1,92 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::lfsr::*;
+use twelve_bit::u12::*;
+
+/// Returns the number of steps until `lfsr` returns to its initial state.
+fn period(mut lfsr: Lfsr12) -> usize {
+    let initial = lfsr.state();
+    let mut steps = 1;
+    while lfsr.step() != initial {
+        steps += 1;
+    }
+    steps
+}
+
+// MARK: - Tests - Construction
+
+#[test]
+fn test_zero_seed_rejected() {
+    assert_eq!(Lfsr12::new(u12![0]), None);
+    assert_eq!(
+        Lfsr12::with_taps(u12![0], u12![0x80F], LfsrKind::Galois),
+        None
+    );
+    assert_eq!(Lfsr12::with_taps(u12![1], u12![0], LfsrKind::Galois), None);
+}
+
+#[test]
+fn test_default() {
+    let lfsr = Lfsr12::default();
+    assert_eq!(lfsr, Lfsr12::new(u12![1]).unwrap());
+    assert_eq!(lfsr.taps(), Lfsr12::DEFAULT_TAPS);
+    assert_eq!(lfsr.kind(), LfsrKind::Fibonacci);
+}
+
+// MARK: - Tests - Sequence
+
+#[test]
+fn test_maximal_length() {
+    for &kind in &[LfsrKind::Fibonacci, LfsrKind::Galois] {
+        let lfsr = Lfsr12::with_taps(u12![0xACE], Lfsr12::DEFAULT_TAPS, kind).unwrap();
+        assert_eq!(period(lfsr.clone()), 4095);
+        let mut seen = vec![false; 4096];
+        for value in lfsr.take(4095) {
+            seen[u16::from(value) as usize] = true;
+        }
+        assert!(!seen[0]);
+        assert!(seen[1..].iter().all(|&seen| seen));
+    }
+}
+
+#[test]
+fn test_fibonacci_steps() {
+    let mut lfsr = Lfsr12::new(u12![1]).unwrap();
+    assert_eq!(lfsr.step(), u12![0x002]);
+    assert_eq!(lfsr.step(), u12![0x004]);
+    let mut lfsr = Lfsr12::new(u12![0x800]).unwrap();
+    assert_eq!(lfsr.step(), u12![0x001]);
+    assert_eq!(lfsr.step(), u12![0x002]);
+}
+
+#[test]
+fn test_galois_steps() {
+    let mut lfsr = Lfsr12::with_taps(u12![1], Lfsr12::DEFAULT_TAPS, LfsrKind::Galois).unwrap();
+    assert_eq!(lfsr.step(), u12![0xE08]);
+    assert_eq!(lfsr.step(), u12![0x704]);
+}
+
+#[test]
+fn test_reseed_repeats_sequence() {
+    let mut lfsr = Lfsr12::new(u12![0x123]).unwrap();
+    let first: Vec<U12> = lfsr.by_ref().take(10).collect();
+    lfsr.reseed(u12![0x123]);
+    let second: Vec<U12> = lfsr.take(10).collect();
+    assert_eq!(first, second);
+}
+
+#[test]
+#[should_panic]
+fn test_reseed_with_zero() {
+    Lfsr12::default().reseed(u12![0]);
+}
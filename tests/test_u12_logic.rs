@@ -76,7 +76,7 @@ fn test_checked_shl() {
     );
     assert_eq!(u12![0b000000000001].checked_shl(12), None);
     assert_eq!(u12![0b000000000001].checked_shl(13), None);
-    assert_eq!(u12![0b000000000001].checked_shl(u32::max_value()), None);
+    assert_eq!(u12![0b000000000001].checked_shl(u32::MAX), None);
 }
 
 #[test]
@@ -233,7 +233,7 @@ fn test_checked_shr() {
     );
     assert_eq!(u12![0b100000000000].checked_shr(12), None);
     assert_eq!(u12![0b100000000000].checked_shr(13), None);
-    assert_eq!(u12![0b100000000000].checked_shr(u32::max_value()), None);
+    assert_eq!(u12![0b100000000000].checked_shr(u32::MAX), None);
 }
 
 #[test]
@@ -336,6 +336,56 @@ fn test_shr_operator_panics_on_wrapping() {
     let _ = u12![0b000000000001] >> 12;
 }
 
+// MARK: - Tests - Rotate Through Carry
+
+#[test]
+fn test_rotate_left_through_carry() {
+    assert_eq!(
+        U12::min_value().rotate_left_through_carry(false),
+        (U12::min_value(), false)
+    );
+    assert_eq!(
+        U12::min_value().rotate_left_through_carry(true),
+        (u12![1], false)
+    );
+    assert_eq!(
+        U12::max_value().rotate_left_through_carry(false),
+        (u12![0xFFE], true)
+    );
+    assert_eq!(
+        u12![0x800].rotate_left_through_carry(false),
+        (U12::min_value(), true)
+    );
+}
+
+#[test]
+fn test_rotate_right_through_carry() {
+    assert_eq!(
+        U12::min_value().rotate_right_through_carry(true),
+        (u12![0x800], false)
+    );
+    assert_eq!(
+        U12::max_value().rotate_right_through_carry(false),
+        (u12![0x7FF], true)
+    );
+    assert_eq!(
+        u12![1].rotate_right_through_carry(false),
+        (U12::min_value(), true)
+    );
+}
+
+#[test]
+fn test_rotate_through_carry_has_period_13() {
+    let (mut value, mut carry) = (u12![0xA5C], true);
+    for _ in 0..13 {
+        let (v, c) = value.rotate_left_through_carry(carry);
+        assert_eq!(v.rotate_right_through_carry(c), (value, carry));
+        value = v;
+        carry = c;
+    }
+    assert_eq!((value, carry), (u12![0xA5C], true));
+}
+
 // MARK: - Tests - And
 
 #[test]
@@ -401,3 +451,281 @@ fn test_xor_operator() {
         u12![0b010101010000]
     );
 }
+
+// MARK: - Tests - Assignment
+
+#[test]
+fn test_bitwise_assign_operators() {
+    let mut and = u12![0b010101010101];
+    and &= u12![0b000011110000];
+    assert_eq!(and, u12![0b000001010000]);
+
+    let mut or = u12![0b010101010101];
+    or |= u12![0b000011110000];
+    assert_eq!(or, u12![0b010111110101]);
+
+    let mut xor = u12![0b010101010101];
+    xor ^= u12![0b000011110000];
+    assert_eq!(xor, u12![0b010110100101]);
+}
+
+// MARK: - Tests - Primitive Operands
+
+#[test]
+fn test_bitwise_operators_with_primitive_operand() {
+    assert_eq!(u12![0xABC] & 0x0F0u16, u12![0x0B0]);
+    assert_eq!(u12![0xABC] | 0x00Fu16, u12![0xABF]);
+    assert_eq!(u12![0xABC] ^ 0xFFFu16, u12![0x543]);
+    let (lhs, rhs) = (&u12![0xABC], &0x03u16);
+    assert_eq!(lhs | rhs, u12![0xABF]);
+
+    let mut value = u12![0xABC];
+    value &= 0xFF0u16;
+    value |= 0x1u16;
+    value ^= 0x100u16;
+    assert_eq!(value, u12![0xBB1]);
+}
+
+#[test]
+fn test_bitwise_operators_with_unsuffixed_literal_operand() {
+    let addr = u12![0xABC];
+    assert_eq!(addr & 0x0F0, u12![0x0B0]);
+    assert_eq!(addr | 0x00F, u12![0xABF]);
+    assert_eq!(addr ^ 0xFFF, u12![0x543]);
+    assert_eq!(&addr & 0x0F0, u12![0x0B0]);
+
+    let mut value = addr;
+    value &= 0xFF0;
+    value |= 0x1;
+    value ^= 0x100;
+    assert_eq!(value, u12![0xBB1]);
+}
+
+#[test]
+fn test_bitwise_operators_mask_primitive_operand() {
+    assert_eq!(u12![0xABC] & 0xF000u16, u12![0]);
+    assert_eq!(u12![0xABC] & 0xF0F0u16, u12![0x0B0]);
+    assert_eq!(u12![0xABC] | 0xF00Fu16, u12![0xABF]);
+    assert_eq!(u12![0xABC] ^ 0xFFFFu16, u12![0x543]);
+
+    let mut value = u12![0xABC];
+    value |= 0xFFFFu16;
+    assert_eq!(value, U12::max_value());
+}
+
+// MARK: - Tests - Bits
+
+#[test]
+fn test_bits() {
+    assert_eq!(u12![0].bits().len(), 12);
+    assert!(u12![0].bits().all(|bit| !bit));
+    assert!(U12::max_value().bits().all(|bit| bit));
+    assert_eq!(
+        u12![0b110000000011].bits().collect::<Vec<_>>(),
+        vec![true, true, false, false, false, false, false, false, false, false, true, true]
+    );
+}
+
+#[test]
+fn test_bits_round_trip() {
+    for value in 0u16..4096 {
+        let value: U12 = value.unchecked_into();
+        assert_eq!(value.bits().collect::<U12>(), value);
+        assert_eq!(U12::try_from_bits(value.bits()), Some(value));
+    }
+}
+
+#[test]
+fn test_from_fewer_bits() {
+    assert_eq!(Vec::<bool>::new().into_iter().collect::<U12>(), u12![0]);
+    assert_eq!(vec![false, true].into_iter().collect::<U12>(), u12![0b10]);
+    assert_eq!(U12::try_from_bits(vec![true; 12]), Some(U12::max_value()));
+}
+
+#[test]
+fn test_try_from_too_many_bits() {
+    assert_eq!(U12::try_from_bits(vec![true; 13]), None);
+}
+
+#[test]
+#[should_panic]
+fn test_collect_too_many_bits() {
+    let _: U12 = vec![false; 13].into_iter().collect();
+}
+
+#[test]
+fn test_iter_ones_and_zeros() {
+    assert_eq!(U12::min_value().iter_ones().next(), None);
+    assert_eq!(U12::max_value().iter_zeros().next(), None);
+    assert_eq!(
+        U12::max_value().iter_ones().collect::<Vec<_>>(),
+        (0..12).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        U12::min_value().iter_zeros().collect::<Vec<_>>(),
+        (0..12).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        u12![0x801].iter_ones().rev().collect::<Vec<_>>(),
+        vec![11, 0]
+    );
+}
+
+#[test]
+fn test_iter_ones_and_zeros_match_bits() {
+    for value in 0u16..4096 {
+        let value: U12 = value.unchecked_into();
+        let ones: Vec<u32> = (0..12)
+            .filter(|&i| value.bits().nth(i as usize) == Some(true))
+            .collect();
+        let zeros: Vec<u32> = (0..12)
+            .filter(|&i| value.bits().nth(i as usize) == Some(false))
+            .collect();
+        assert_eq!(value.iter_ones().collect::<Vec<_>>(), ones);
+        assert_eq!(value.iter_zeros().collect::<Vec<_>>(), zeros);
+        assert_eq!(value.iter_ones().len(), value.count_ones() as usize);
+        assert_eq!(value.iter_zeros().len(), value.count_zeros() as usize);
+        assert_eq!(
+            value.iter_ones().rev().collect::<Vec<_>>(),
+            ones.iter().rev().cloned().collect::<Vec<_>>()
+        );
+    }
+}
+
+// MARK: - Tests - Parity
+
+#[test]
+fn test_is_even_and_is_odd() {
+    for value in 0u16..4096 {
+        let u12_value: U12 = value.unchecked_into();
+        assert_eq!(u12_value.is_even(), value % 2 == 0);
+        assert_eq!(u12_value.is_odd(), value % 2 == 1);
+    }
+}
+
+#[test]
+fn test_parity() {
+    assert!(!u12![0b000000000000].parity());
+    assert!(u12![0b000000000001].parity());
+    assert!(u12![0b100000000000].parity());
+    assert!(!u12![0b100000000001].parity());
+    assert!(u12![0b010101010111].parity());
+    assert!(!U12::max_value().parity());
+}
+
+// MARK: - Tests - Hamming Distance
+
+#[test]
+fn test_hamming_distance() {
+    assert_eq!(u12![0].hamming_distance(u12![0]), 0);
+    assert_eq!(u12![0].hamming_distance(U12::max_value()), 12);
+    assert_eq!(U12::max_value().hamming_distance(u12![0]), 12);
+    assert_eq!(
+        u12![0b111100001111].hamming_distance(u12![0b000011110000]),
+        12
+    );
+    assert_eq!(
+        u12![0b111100001111].hamming_distance(u12![0b111100000000]),
+        4
+    );
+    assert_eq!(
+        u12![0b100000000001].hamming_distance(u12![0b000000000001]),
+        1
+    );
+}
+
+// MARK: - Tests - Morton Codes
+
+#[test]
+fn test_from_interleaved() {
+    assert_eq!(U12::from_interleaved(0, 0), Some(U12::min_value()));
+    assert_eq!(U12::from_interleaved(1, 0), Some(u12![0b01]));
+    assert_eq!(U12::from_interleaved(0, 1), Some(u12![0b10]));
+    assert_eq!(U12::from_interleaved(0b100000, 0), Some(u12![0x400]));
+    assert_eq!(U12::from_interleaved(0, 0b100000), Some(u12![0x800]));
+    assert_eq!(U12::from_interleaved(63, 63), Some(U12::max_value()));
+    assert_eq!(U12::from_interleaved(64, 0), None);
+    assert_eq!(U12::from_interleaved(0, 64), None);
+}
+
+#[test]
+fn test_interleaved_round_trip() {
+    for x in 0..64u8 {
+        for y in 0..64u8 {
+            let code = U12::from_interleaved(x, y).unwrap();
+            assert_eq!(code.to_interleaved(), (x, y));
+        }
+    }
+    for value in 0..4096u16 {
+        let (x, y) = U12::from_u16(value).to_interleaved();
+        assert_eq!(U12::from_interleaved(x, y), Some(U12::from_u16(value)));
+    }
+}
+
+#[test]
+fn test_interleaved_z_order() {
+    // Each 2×2 quad is visited in the order (0, 0), (1, 0), (0, 1), (1, 1).
+    let codes: Vec<U12> = [(0, 0), (1, 0), (0, 1), (1, 1), (2, 0)]
+        .iter()
+        .map(|&(x, y)| U12::from_interleaved(x, y).unwrap())
+        .collect();
+    assert_eq!(codes, [u12![0], u12![1], u12![2], u12![3], u12![4]]);
+}
+
+// MARK: - Tests - Field Access
+
+#[test]
+fn test_extract_from() {
+    assert_eq!(U12::extract_from(0xABCu16, 0), u12![0xABC]);
+    assert_eq!(U12::extract_from(0xABC0u16, 4), u12![0xABC]);
+    assert_eq!(U12::extract_from(0xFFF0_0000u32, 20), u12![0xFFF]);
+    assert_eq!(U12::extract_from(0x1230_0000_0000_0000u64, 52), u12![0x123]);
+    assert_eq!(U12::extract_from(u64::MAX, 17), U12::max_value());
+}
+
+#[test]
+fn test_checked_extract_from_bounds() {
+    assert_eq!(U12::checked_extract_from(0u16, 4), Some(U12::min_value()));
+    assert_eq!(U12::checked_extract_from(0u16, 5), None);
+    assert_eq!(U12::checked_extract_from(0u32, 20), Some(U12::min_value()));
+    assert_eq!(U12::checked_extract_from(0u32, 21), None);
+    assert_eq!(U12::checked_extract_from(0u64, 52), Some(U12::min_value()));
+    assert_eq!(U12::checked_extract_from(0u64, 53), None);
+    assert_eq!(U12::checked_extract_from(0u64, u32::MAX), None);
+}
+
+#[test]
+#[should_panic(expected = "field out of bounds")]
+fn test_extract_from_out_of_bounds() {
+    let _ = U12::extract_from(0u32, 21);
+}
+
+#[test]
+fn test_insert_into_preserves_other_bits() {
+    let mut word = u64::MAX;
+    u12![0].insert_into(&mut word, 30);
+    assert_eq!(word, !(0xFFFu64 << 30));
+
+    let mut word = 0u16;
+    u12![0xABC].insert_into(&mut word, 4);
+    assert_eq!(word, 0xABC0);
+
+    let mut word = 0x8000_0001u32;
+    u12![0x5A5].insert_into(&mut word, 8);
+    assert_eq!(word, 0x8005_A501);
+    assert_eq!(U12::extract_from(word, 8), u12![0x5A5]);
+}
+
+#[test]
+fn test_checked_insert_u12_bounds() {
+    assert_eq!(0u16.checked_insert_u12(4, U12::max_value()), Some(0xFFF0));
+    assert_eq!(0u16.checked_insert_u12(5, U12::max_value()), None);
+    assert_eq!(0u32.checked_insert_u12(21, U12::max_value()), None);
+}
+
+#[test]
+#[should_panic(expected = "field out of bounds")]
+fn test_insert_into_out_of_bounds() {
+    let mut word = 0u16;
+    u12![1].insert_into(&mut word, 5);
+}
@@ -0,0 +1,96 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::lut::Lut12;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Construction
+
+#[test]
+fn test_lut_identity() {
+    let lut = Lut12::identity();
+    assert_eq!(lut.as_slice().len(), 4096);
+    for value in 0..4096u16 {
+        let value = U12::from_u16(value);
+        assert_eq!(lut.apply(value), value);
+        assert_eq!(lut[value], value);
+    }
+    assert_eq!(lut, Lut12::default());
+}
+
+#[test]
+fn test_lut_from_fn() {
+    let lut = Lut12::from_fn(|value| value.saturating_mul(u12![2]));
+    assert_eq!(lut.apply(u12![100]), u12![200]);
+    assert_eq!(lut.apply(u12![3000]), u12![4095]);
+}
+
+#[test]
+fn test_lut_from_table() {
+    let table = vec![u12![7]; 4096];
+    let lut = Lut12::from_table(&table).unwrap();
+    assert_eq!(lut.apply(u12![1234]), u12![7]);
+    assert_eq!(Lut12::from_table(&table[1..]), None);
+    assert_eq!(Lut12::from_table(&[]), None);
+}
+
+// MARK: - Tests - Gamma
+
+#[test]
+fn test_lut_gamma_endpoints_and_monotonicity() {
+    for &gamma in &[0.45, 1.0, 2.2] {
+        let lut = Lut12::gamma(gamma);
+        assert_eq!(lut.apply(U12::min_value()), U12::min_value());
+        assert_eq!(lut.apply(U12::max_value()), U12::max_value());
+        assert!(lut.as_slice().windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+    assert_eq!(Lut12::gamma(1.0), Lut12::identity());
+}
+
+#[test]
+fn test_lut_gamma_values() {
+    let lut = Lut12::gamma(2.0);
+    assert_eq!(lut.apply(u12![2048]), u12![1024]);
+    let lut = Lut12::gamma(0.5);
+    assert_eq!(lut.apply(u12![1024]), u12![2048]);
+}
+
+#[test]
+#[should_panic(expected = "gamma must be positive and finite")]
+fn test_lut_gamma_zero() {
+    let _ = Lut12::gamma(0.0);
+}
+
+#[test]
+#[should_panic(expected = "gamma must be positive and finite")]
+fn test_lut_gamma_nan() {
+    let _ = Lut12::gamma(f64::NAN);
+}
+
+// MARK: - Tests - Application
+
+#[test]
+fn test_lut_apply_slice() {
+    let lut = Lut12::from_fn(|value| value.wrapping_add(u12![1]));
+    let mut values = [u12![0], u12![1], u12![4095]];
+    lut.apply_slice(&mut values);
+    assert_eq!(values, [u12![1], u12![2], u12![0]]);
+}
+
+#[test]
+fn test_lut_then() {
+    let double = Lut12::from_fn(|value| value.wrapping_mul(u12![2]));
+    let increment = Lut12::from_fn(|value| value.wrapping_add(u12![1]));
+    let combined = double.then(&increment);
+    assert_eq!(combined.apply(u12![10]), u12![21]);
+    assert_eq!(increment.then(&double).apply(u12![10]), u12![22]);
+}
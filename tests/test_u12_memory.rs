@@ -0,0 +1,95 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::memory::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Bytes
+
+#[test]
+fn test_new_is_zeroed() {
+    let memory = Memory4K::new();
+    assert_eq!(memory.as_slice().len(), MEMORY_SIZE);
+    assert!(memory.as_slice().iter().all(|&byte| byte == 0));
+    assert_eq!(memory, Memory4K::default());
+}
+
+#[test]
+fn test_read_write() {
+    let mut memory = Memory4K::new();
+    memory.write(U12::min_value(), 0x12);
+    memory.write(U12::max_value(), 0x34);
+    assert_eq!(memory.read(U12::min_value()), 0x12);
+    assert_eq!(memory.read(U12::max_value()), 0x34);
+    assert_eq!(memory.as_slice()[4095], 0x34);
+
+    memory[u12![0x100]] = 0x56;
+    assert_eq!(memory[u12![0x100]], 0x56);
+    assert_eq!(memory.read(u12![0x100]), 0x56);
+}
+
+// MARK: - Tests - Words
+
+#[test]
+fn test_read_write_u16() {
+    let mut memory = Memory4K::new();
+    memory.write_u16(u12![0x200], 0xA2F0);
+    assert_eq!(memory.read(u12![0x200]), 0xA2);
+    assert_eq!(memory.read(u12![0x201]), 0xF0);
+    assert_eq!(memory.read_u16(u12![0x200]), 0xA2F0);
+}
+
+#[test]
+fn test_u16_wraps_around() {
+    let mut memory = Memory4K::new();
+    memory.write_u16(U12::max_value(), 0xBEEF);
+    assert_eq!(memory.read(U12::max_value()), 0xBE);
+    assert_eq!(memory.read(U12::min_value()), 0xEF);
+    assert_eq!(memory.read_u16(U12::max_value()), 0xBEEF);
+}
+
+// MARK: - Tests - Loading
+
+#[test]
+fn test_load_at() {
+    let mut memory = Memory4K::new();
+    memory.load_at(u12![0xFFE], &[1, 2]);
+    assert_eq!(&memory[u12![0xFFE]..], &[1, 2]);
+    memory.load_at(U12::min_value(), &[0xFF; MEMORY_SIZE]);
+    assert!(memory.as_slice().iter().all(|&byte| byte == 0xFF));
+}
+
+#[test]
+#[should_panic]
+fn test_load_at_past_end() {
+    Memory4K::new().load_at(u12![0xFFE], &[1, 2, 3]);
+}
+
+// MARK: - Tests - Range Indexing
+
+#[test]
+fn test_range_indexing() {
+    let mut bytes = [0u8; MEMORY_SIZE];
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = index as u8;
+    }
+    let mut memory = Memory4K::from_bytes(bytes);
+    assert_eq!(&memory[u12![2]..u12![5]], &[2, 3, 4]);
+    assert_eq!(&memory[u12![2]..=u12![5]], &[2, 3, 4, 5]);
+    assert_eq!(&memory[..u12![3]], &[0, 1, 2]);
+    assert_eq!(&memory[..=u12![3]], &[0, 1, 2, 3]);
+    assert_eq!(memory[u12![0xFF0]..].len(), 16);
+    assert_eq!(memory[..=U12::max_value()].len(), MEMORY_SIZE);
+
+    memory[u12![0x10]..u12![0x12]].copy_from_slice(&[0xAA, 0xBB]);
+    assert_eq!(memory.read_u16(u12![0x10]), 0xAABB);
+}
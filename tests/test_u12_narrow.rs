@@ -0,0 +1,68 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::*;
+
+/// Sums `values` with wrapping, written only in terms of `NarrowUInt`.
+fn wrapping_total<T: NarrowUInt>(values: &[T]) -> T {
+    values
+        .iter()
+        .fold(T::MIN, |sum, &value| sum.wrapping_add(value))
+}
+
+/// Returns the largest value which `T` can represent, as its storage type.
+fn max_wide<T: NarrowUInt>() -> T::Wide {
+    T::MAX.widen()
+}
+
+// MARK: - Tests - Constants
+
+#[test]
+fn test_narrow_constants() {
+    assert_eq!(<U12 as NarrowUInt>::BITS, 12);
+    assert_eq!(<U12 as NarrowUInt>::MIN, U12::min_value());
+    assert_eq!(<U12 as NarrowUInt>::MAX, U12::max_value());
+    assert_eq!(max_wide::<U12>(), 0xFFF);
+}
+
+// MARK: - Tests - Arithmetic
+
+#[test]
+fn test_narrow_arithmetic_matches_inherent() {
+    let values = [u12![0], u12![1], u12![2048], u12![4095]];
+    for &a in values.iter() {
+        for &b in values.iter() {
+            assert_eq!(NarrowUInt::checked_add(a, b), a.checked_add(b));
+            assert_eq!(NarrowUInt::wrapping_add(a, b), a.wrapping_add(b));
+            assert_eq!(NarrowUInt::checked_sub(a, b), a.checked_sub(b));
+            assert_eq!(NarrowUInt::wrapping_sub(a, b), a.wrapping_sub(b));
+            assert_eq!(NarrowUInt::checked_mul(a, b), a.checked_mul(b));
+            assert_eq!(NarrowUInt::wrapping_mul(a, b), a.wrapping_mul(b));
+        }
+    }
+}
+
+#[test]
+fn test_narrow_generic_code() {
+    assert_eq!(wrapping_total(&[u12![4095], u12![2]]), u12![1]);
+    assert_eq!(wrapping_total::<U12>(&[]), u12![0]);
+}
+
+// MARK: - Tests - Conversions
+
+#[test]
+fn test_narrow_conversions() {
+    assert_eq!(u12![0xABC].widen(), 0xABC);
+    assert_eq!(<U12 as NarrowUInt>::narrow(0xFFF), Some(u12![0xFFF]));
+    assert_eq!(<U12 as NarrowUInt>::narrow(0x1000), None);
+    assert_eq!(<U12 as NarrowUInt>::narrow_truncated(0x1ABC), u12![0xABC]);
+}
@@ -0,0 +1,94 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "num")]
+
+extern crate num_integer;
+extern crate num_traits;
+#[macro_use]
+extern crate twelve_bit;
+
+use num_integer::Integer;
+use num_traits::{Num, One, Zero};
+use twelve_bit::u12::*;
+
+/// Reduces the fraction `numerator / denominator` generically.
+fn reduce<T: Integer + Copy>(numerator: T, denominator: T) -> (T, T) {
+    let gcd = numerator.gcd(&denominator);
+    (numerator / gcd, denominator / gcd)
+}
+
+// MARK: - Tests - Num Traits
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(U12::zero(), U12::min_value());
+    assert!(U12::zero().is_zero());
+    assert!(!U12::one().is_zero());
+    assert_eq!(U12::one(), u12![1]);
+}
+
+#[test]
+fn test_num_from_str_radix() {
+    assert_eq!(
+        <U12 as Num>::from_str_radix("FFF", 16),
+        Ok(U12::max_value())
+    );
+    assert!(<U12 as Num>::from_str_radix("1000", 16).is_err());
+}
+
+// MARK: - Tests - Integer
+
+#[test]
+fn test_gcd_and_lcm() {
+    assert_eq!(u12![12].gcd(&u12![18]), u12![6]);
+    assert_eq!(u12![0].gcd(&u12![18]), u12![18]);
+    assert_eq!(u12![0].gcd(&u12![0]), u12![0]);
+    assert_eq!(u12![12].lcm(&u12![18]), u12![36]);
+    assert_eq!(u12![0].lcm(&u12![18]), u12![0]);
+    assert_eq!(u12![4095].lcm(&u12![5]), u12![4095]);
+    for a in 0..64u16 {
+        for b in 0..64u16 {
+            let (a, b) = (u12![a], u12![b]);
+            assert_eq!(a.gcd(&b), b.gcd(&a));
+            assert_eq!(a.gcd(&b) * a.lcm(&b), a * b);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_lcm_overflow() {
+    u12![4095].lcm(&u12![4094]);
+}
+
+#[test]
+fn test_floor_division() {
+    assert_eq!(u12![7].div_floor(&u12![2]), u12![3]);
+    assert_eq!(u12![7].mod_floor(&u12![2]), u12![1]);
+    assert_eq!(Integer::div_rem(&u12![7], &u12![2]), (u12![3], u12![1]));
+    assert_eq!(u12![7].div_mod_floor(&u12![2]), (u12![3], u12![1]));
+    assert_eq!(u12![7].div_ceil(&u12![2]), u12![4]);
+}
+
+#[test]
+fn test_multiples_and_parity() {
+    assert!(Integer::is_multiple_of(&u12![12], &u12![4]));
+    assert!(!Integer::is_multiple_of(&u12![12], &u12![5]));
+    assert!(Integer::is_multiple_of(&u12![0], &u12![0]));
+    assert!(!Integer::is_multiple_of(&u12![1], &u12![0]));
+    assert!(Integer::is_even(&u12![4]));
+    assert!(Integer::is_odd(&u12![5]));
+}
+
+#[test]
+fn test_generic_reduction() {
+    assert_eq!(reduce(u12![1024], u12![4064]), (u12![32], u12![127]));
+    assert_eq!(reduce(u12![3], u12![9]), (u12![1], u12![3]));
+}
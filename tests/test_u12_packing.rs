@@ -0,0 +1,231 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::packing::*;
+use twelve_bit::u12::*;
+
+/// Packs `values` one pair at a time, as a reference for the block kernel.
+fn reference_pack(values: &[U12]) -> Vec<u8> {
+    let mut packed = Vec::new();
+    for pair in values.chunks(2) {
+        let a = u16::from(pair[0]);
+        match pair.get(1) {
+            Some(b) => {
+                let b = u16::from(*b);
+                packed.push((a >> 4) as u8);
+                packed.push((b >> 4) as u8);
+                packed.push((((b & 0xF) << 4) | (a & 0xF)) as u8);
+            }
+            None => {
+                packed.push((a >> 4) as u8);
+                packed.push((a & 0xF) as u8);
+            }
+        }
+    }
+    packed
+}
+
+// MARK: - Tests - Packed Length
+
+#[test]
+fn test_packed_len() {
+    assert_eq!(packed_len(0), 0);
+    assert_eq!(packed_len(1), 2);
+    assert_eq!(packed_len(2), 3);
+    assert_eq!(packed_len(3), 5);
+    assert_eq!(packed_len(16), 24);
+    assert_eq!(packed_len(17), 26);
+}
+
+// MARK: - Tests - Pack
+
+#[test]
+fn test_pack_slice_matches_reference() {
+    for count in 0..70 {
+        let values: Vec<U12> = (0..count)
+            .map(|i| ((i as u16 * 0x3A7) & 0xFFF).unchecked_into())
+            .collect();
+        let mut packed = vec![0u8; packed_len(count)];
+        pack_slice(&values, &mut packed);
+        assert_eq!(packed, reference_pack(&values), "count {}", count);
+    }
+}
+
+#[test]
+fn test_pack_slice_block_kernel_all_values() {
+    let values: Vec<U12> = (0..4096u16).map(|i| i.unchecked_into()).collect();
+    let mut packed = vec![0u8; packed_len(values.len())];
+    pack_slice(&values, &mut packed);
+    assert_eq!(packed, reference_pack(&values));
+}
+
+#[test]
+#[should_panic]
+fn test_pack_slice_wrong_length() {
+    let mut packed = [0u8; 4];
+    pack_slice(&[u12![1], u12![2]], &mut packed);
+}
+
+// MARK: - Tests - Unpack
+
+#[test]
+fn test_unpack_slice_round_trip() {
+    for count in 0..70 {
+        let values: Vec<U12> = (0..count)
+            .map(|i| ((i as u16 * 0x3A7) & 0xFFF).unchecked_into())
+            .collect();
+        let packed = reference_pack(&values);
+        let mut unpacked = vec![U12::min_value(); count];
+        unpack_slice(&packed, &mut unpacked);
+        assert_eq!(unpacked, values, "count {}", count);
+    }
+}
+
+#[test]
+fn test_unpack_slice_block_kernel_all_values() {
+    let values: Vec<U12> = (0..4096u16).rev().map(|i| i.unchecked_into()).collect();
+    let packed = reference_pack(&values);
+    let mut unpacked = vec![U12::min_value(); values.len()];
+    unpack_slice(&packed, &mut unpacked);
+    assert_eq!(unpacked, values);
+}
+
+#[test]
+fn test_unpack_slice_ignores_padding() {
+    let mut unpacked = [U12::min_value()];
+    unpack_slice(&[0xAB, 0xFC], &mut unpacked);
+    assert_eq!(unpacked, [u12![0xABC]]);
+}
+
+#[test]
+#[should_panic]
+fn test_unpack_slice_wrong_length() {
+    let mut unpacked = [U12::min_value(); 2];
+    unpack_slice(&[0u8; 2], &mut unpacked);
+}
+
+// MARK: - Tests - Word Packing
+
+#[test]
+fn test_pack5_into_u64() {
+    assert_eq!(pack5_into_u64([U12::min_value(); 5]), 0);
+    assert_eq!(pack5_into_u64([U12::max_value(); 5]), 0x0FFF_FFFF_FFFF_FFFF);
+    assert_eq!(
+        pack5_into_u64([u12![0xABC], u12![0], u12![0], u12![0], u12![0x123]]),
+        0x0123_0000_0000_0ABC
+    );
+}
+
+#[test]
+fn test_unpack5_from_u64_ignores_top_bits() {
+    assert_eq!(unpack5_from_u64(u64::MAX), [U12::max_value(); 5]);
+    assert_eq!(
+        unpack5_from_u64(0xF000_0000_0000_0000),
+        [U12::min_value(); 5]
+    );
+}
+
+#[test]
+fn test_pack10_into_u128() {
+    let values: Vec<U12> = (0..10).map(|i| U12::from_u16(0x111 * i + 1)).collect();
+    let mut array = [U12::min_value(); 10];
+    array.copy_from_slice(&values);
+    let word = pack10_into_u128(array);
+    assert_eq!(word >> 120, 0);
+    for (index, value) in values.iter().enumerate() {
+        assert_eq!((word >> (12 * index)) as u16 & 0xFFF, u16::from(*value));
+    }
+    assert_eq!(unpack10_from_u128(word), array);
+    assert_eq!(unpack10_from_u128(u128::MAX), [U12::max_value(); 10]);
+}
+
+#[test]
+fn test_word_packing_round_trip() {
+    for seed in 0..4096u16 {
+        let value = |offset: u16| U12::from_u16(seed.wrapping_mul(offset * 2 + 1) & 0xFFF);
+        let five = [value(0), value(1), value(2), value(3), value(4)];
+        assert_eq!(unpack5_from_u64(pack5_into_u64(five)), five);
+    }
+}
+
+// MARK: - Tests - Packed Arrays
+
+#[test]
+fn test_packed_array_new() {
+    let array = PackedU12Array::<4, { packed_len(4) }>::new();
+    assert_eq!(array.len(), 4);
+    assert!(!array.is_empty());
+    assert_eq!(array.as_bytes(), &[0; 6]);
+    assert_eq!(array, PackedU12Array::default());
+    assert!(PackedU12Array::<0, 0>::new().is_empty());
+}
+
+#[test]
+fn test_packed_array_get_set() {
+    for &count in &[1usize, 2, 3, 4, 5] {
+        let mut array = PackedU12Array::<5, { packed_len(5) }>::new();
+        let values: Vec<U12> = (0..5u16)
+            .map(|i| U12::from_u16(0x111 * (i + 1) + 0xA))
+            .collect();
+        for (index, &value) in values.iter().enumerate().take(count) {
+            array.set(index, value);
+        }
+        for (index, &value) in values.iter().enumerate() {
+            let expected = if index < count { value } else { u12![0] };
+            assert_eq!(array.get(index), Some(expected));
+        }
+        assert_eq!(array.get(5), None);
+    }
+}
+
+#[test]
+fn test_packed_array_set_preserves_neighbors() {
+    let mut array = PackedU12Array::<3, 5>::from_values([U12::max_value(); 3]);
+    array.set(1, u12![0]);
+    assert_eq!(
+        array.to_values(),
+        [U12::max_value(), u12![0], U12::max_value()]
+    );
+    array.set(0, u12![0]);
+    array.set(1, U12::max_value());
+    assert_eq!(
+        array.to_values(),
+        [u12![0], U12::max_value(), U12::max_value()]
+    );
+}
+
+#[test]
+fn test_packed_array_matches_pack_slice() {
+    let values = [u12![0xABC], u12![0x123], u12![0xFED]];
+    let array = PackedU12Array::<3, { packed_len(3) }>::from_values(values);
+    let mut packed = [0; 5];
+    pack_slice(&values, &mut packed);
+    assert_eq!(array.as_bytes(), &packed);
+    assert_eq!(PackedU12Array::<3, 5>::from_bytes(packed), array);
+    assert_eq!(array.iter().collect::<Vec<_>>(), values);
+    assert_eq!((&array).into_iter().len(), 3);
+}
+
+#[test]
+fn test_packed_array_from_bytes_clears_unused_bits() {
+    let array = PackedU12Array::<1, 2>::from_bytes([0xAB, 0xFC]);
+    assert_eq!(array.get(0), Some(u12![0xABC]));
+    assert_eq!(array.as_bytes(), &[0xAB, 0x0C]);
+    assert_eq!(array, PackedU12Array::from_values([u12![0xABC]]));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_packed_array_set_out_of_bounds() {
+    let mut array = PackedU12Array::<2, 3>::new();
+    array.set(2, u12![1]);
+}
@@ -0,0 +1,87 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::permutation::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Bijection
+
+#[test]
+fn test_apply_is_bijective() {
+    for &key in &[0, 1, 0x5EED, u64::MAX] {
+        let permutation = Permutation12::new(key);
+        let mut seen = vec![false; 4096];
+        for value in 0..4096 {
+            let image = permutation.apply(U12::from_u16(value));
+            assert!(!seen[u16::from(image) as usize]);
+            seen[u16::from(image) as usize] = true;
+        }
+    }
+}
+
+#[test]
+fn test_invert_round_trip() {
+    let permutation = Permutation12::new(0xDEAD_BEEF);
+    for value in 0..4096 {
+        let value = U12::from_u16(value);
+        assert_eq!(permutation.invert(permutation.apply(value)), value);
+        assert_eq!(permutation.apply(permutation.invert(value)), value);
+    }
+}
+
+// MARK: - Tests - Keys
+
+#[test]
+fn test_same_key_is_deterministic() {
+    assert_eq!(Permutation12::new(7), Permutation12::new(7));
+    assert_eq!(
+        Permutation12::new(7).iter().collect::<Vec<_>>(),
+        Permutation12::new(7).iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_different_keys_differ() {
+    let a: Vec<U12> = Permutation12::new(1).iter().collect();
+    let b: Vec<U12> = Permutation12::new(2).iter().collect();
+    let same = a.iter().zip(&b).filter(|(x, y)| x == y).count();
+    assert!(same < 32, "{} fixed points in common", same);
+}
+
+#[test]
+fn test_scrambles() {
+    let permutation = Permutation12::new(0);
+    let fixed_points = (0..4096)
+        .map(U12::from_u16)
+        .filter(|&v| permutation.apply(v) == v)
+        .count();
+    assert!(fixed_points < 16);
+    let image: Vec<U12> = permutation.iter().take(8).collect();
+    assert_ne!(image, (0..8).map(U12::from_u16).collect::<Vec<_>>());
+}
+
+// MARK: - Tests - Iteration
+
+#[test]
+fn test_iter_visits_every_value_once() {
+    let permutation = Permutation12::new(42);
+    let iter = permutation.iter();
+    assert_eq!(iter.len(), 4096);
+    let mut values: Vec<U12> = iter.collect();
+    values.sort();
+    values.dedup();
+    assert_eq!(values.len(), 4096);
+    assert_eq!(
+        permutation.iter().nth(10),
+        Some(permutation.apply(u12![10]))
+    );
+}
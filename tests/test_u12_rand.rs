@@ -0,0 +1,54 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "rand")]
+
+extern crate rand;
+extern crate twelve_bit;
+
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Sampling
+
+#[test]
+fn test_random_covers_range() {
+    let mut rng = StdRng::seed_from_u64(12);
+    let mut seen = vec![false; 4096];
+    for _ in 0..100_000 {
+        let value: U12 = rng.random();
+        seen[usize::from(value)] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+// MARK: - Tests - Fill
+
+#[test]
+fn test_fill_covers_range() {
+    let mut rng = StdRng::seed_from_u64(12);
+    let mut values = vec![U12::min_value(); 100_003];
+    rng.fill(&mut values[..]);
+    let mut seen = vec![false; 4096];
+    for value in values {
+        seen[usize::from(value)] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+fn test_fill_is_deterministic() {
+    let mut first = [U12::min_value(); 17];
+    let mut second = [U12::min_value(); 17];
+    StdRng::seed_from_u64(7).fill(&mut first[..]);
+    StdRng::seed_from_u64(7).fill(&mut second[..]);
+    assert_eq!(first, second);
+    assert!(first.iter().any(|&value| value != U12::min_value()));
+}
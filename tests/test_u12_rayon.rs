@@ -0,0 +1,88 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "rayon")]
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::dither::{ordered, par_ordered};
+use twelve_bit::u12::histogram::Histogram12;
+use twelve_bit::u12::lut::Lut12;
+use twelve_bit::u12::packing::*;
+use twelve_bit::u12::*;
+
+/// Returns `count` values covering the whole 12-bit range in a scrambled order.
+fn sample_values(count: usize) -> Vec<U12> {
+    (0..count)
+        .map(|index| U12::truncate_from(index.wrapping_mul(2_654_435_761) >> 7))
+        .collect()
+}
+
+// MARK: - Tests - Packing
+
+#[test]
+fn test_par_pack_matches_sequential() {
+    for &count in &[0, 1, 2, 65_535, 65_536, 65_537, 300_001] {
+        let values = sample_values(count);
+        let mut expected = vec![0; packed_len(count)];
+        pack_slice(&values, &mut expected);
+        let mut packed = vec![0; packed_len(count)];
+        par_pack_slice(&values, &mut packed);
+        assert_eq!(packed, expected);
+
+        let mut unpacked = vec![u12![0]; count];
+        par_unpack_slice(&packed, &mut unpacked);
+        assert_eq!(unpacked, values);
+    }
+}
+
+#[test]
+#[should_panic(expected = "packed length mismatch")]
+fn test_par_pack_length_mismatch() {
+    par_pack_slice(&[u12![1]; 3], &mut [0; 4]);
+}
+
+// MARK: - Tests - Lookup Tables
+
+#[test]
+fn test_par_apply_slice_matches_sequential() {
+    let lut = Lut12::gamma(0.45);
+    let mut expected = sample_values(200_000);
+    let mut values = expected.clone();
+    lut.apply_slice(&mut expected);
+    lut.par_apply_slice(&mut values);
+    assert_eq!(values, expected);
+}
+
+// MARK: - Tests - Histograms
+
+#[test]
+fn test_par_record_slice_matches_sequential() {
+    let values = sample_values(300_000);
+    let mut expected = Histogram12::new();
+    expected.record(u12![7]);
+    expected.record_slice(&values);
+    let mut histogram = Histogram12::new();
+    histogram.record(u12![7]);
+    histogram.par_record_slice(&values);
+    assert_eq!(histogram, expected);
+}
+
+// MARK: - Tests - Dithering
+
+#[test]
+fn test_par_ordered_matches_sequential() {
+    let values = sample_values(640 * 48);
+    let mut expected = vec![0; values.len()];
+    ordered(&values, &mut expected, 640);
+    let mut dithered = vec![0; values.len()];
+    par_ordered(&values, &mut dithered, 640);
+    assert_eq!(dithered, expected);
+}
@@ -0,0 +1,54 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "rkyv")]
+
+#[macro_use]
+extern crate twelve_bit;
+extern crate rkyv;
+
+use rkyv::rancor::Error;
+use rkyv::vec::ArchivedVec;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Round Trip
+
+#[test]
+fn test_rkyv_round_trip() {
+    for &value in &[U12::min_value(), u12![0xABC], U12::max_value()] {
+        let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+        assert_eq!(bytes.len(), 2);
+        assert_eq!(rkyv::from_bytes::<U12, Error>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_rkyv_zero_copy_access() {
+    let values: Vec<U12> = (0..4096u16).map(U12::from_u16).collect();
+    let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+    let archived = rkyv::access::<ArchivedVec<ArchivedU12>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 4096);
+    assert_eq!(archived[0xABC].to_native(), u12![0xABC]);
+    assert!(archived
+        .iter()
+        .zip(values.iter())
+        .all(|(archived, &value)| archived.to_native() == value));
+}
+
+// MARK: - Tests - Validation
+
+#[test]
+fn test_rkyv_rejects_out_of_range_values() {
+    let bytes = rkyv::to_bytes::<Error>(&0x0FFFu16).unwrap();
+    assert!(rkyv::access::<ArchivedU12, Error>(&bytes).is_ok());
+
+    let bytes = rkyv::to_bytes::<Error>(&0x1000u16).unwrap();
+    assert!(rkyv::access::<ArchivedU12, Error>(&bytes).is_err());
+    assert!(rkyv::from_bytes::<U12, Error>(&bytes).is_err());
+}
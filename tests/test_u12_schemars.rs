@@ -0,0 +1,41 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "schemars")]
+
+extern crate schemars;
+extern crate twelve_bit;
+
+use schemars::{json_schema, schema_for, JsonSchema};
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Schema
+
+#[test]
+fn test_schema_for_u12() {
+    let schema = schema_for!(U12);
+    assert_eq!(schema.get("type").unwrap(), "integer");
+    assert_eq!(schema.get("minimum").unwrap(), 0);
+    assert_eq!(schema.get("maximum").unwrap(), 4095);
+}
+
+#[test]
+fn test_schema_is_inlined() {
+    assert!(U12::inline_schema());
+    let schema = schema_for!(Vec<U12>);
+    assert_eq!(
+        schema.get("items").unwrap(),
+        json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 4095
+        })
+        .as_value()
+    );
+}
@@ -0,0 +1,150 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate twelve_bit;
+extern crate serde;
+extern crate serde_test;
+
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+use twelve_bit::u12::*;
+
+/// A wrapper serializing its value through `twelve_bit::serde::hex`.
+#[derive(Debug, PartialEq)]
+struct Hex(U12);
+
+impl serde::Serialize for Hex {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        twelve_bit::serde::hex::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Hex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        twelve_bit::serde::hex::deserialize(deserializer).map(Hex)
+    }
+}
+
+// MARK: - Tests - Integer Representation
+
+#[test]
+fn test_integer_representation() {
+    assert_tokens(&u12![0], &[Token::U16(0)]);
+    assert_tokens(&u12![4095], &[Token::U16(4095)]);
+}
+
+#[test]
+fn test_integer_out_of_range() {
+    assert_de_tokens_error::<U12>(&[Token::U16(4096)], "U12 out of range: 4096");
+}
+
+// MARK: - Tests - Hex Representation
+
+#[test]
+fn test_hex_readable() {
+    assert_tokens(&Hex(u12![0]).readable(), &[Token::Str("000")]);
+    assert_tokens(&Hex(u12![0x0A5]).readable(), &[Token::Str("0A5")]);
+    assert_tokens(&Hex(u12![0xFFF]).readable(), &[Token::Str("FFF")]);
+}
+
+#[test]
+fn test_hex_readable_lenient_input() {
+    serde_test::assert_de_tokens(&Hex(u12![0xA]).readable(), &[Token::Str("a")]);
+    serde_test::assert_de_tokens(&Hex(u12![0xABC]).readable(), &[Token::Str("0xabc")]);
+    serde_test::assert_de_tokens(&Hex(u12![0xABC]).readable(), &[Token::Str("0XABC")]);
+}
+
+#[test]
+fn test_hex_readable_invalid_input() {
+    for input in ["", "0x", "1000", "0FFF", "+FF", "XYZ"].iter() {
+        assert_de_tokens_error::<serde_test::Readable<Hex>>(
+            &[Token::Str(input)],
+            &format!(
+                "invalid value: string \"{}\", expected a hexadecimal string between 000 and FFF",
+                input
+            ),
+        );
+    }
+}
+
+#[test]
+fn test_hex_compact() {
+    assert_tokens(&Hex(u12![0x0A5]).compact(), &[Token::U16(0x0A5)]);
+}
+
+// MARK: - Tests - Packed Representation
+
+/// A wrapper serializing its values through `twelve_bit::serde::packed`.
+#[derive(Debug, PartialEq)]
+struct Packed(Vec<U12>);
+
+impl serde::Serialize for Packed {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        twelve_bit::serde::packed::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Packed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        twelve_bit::serde::packed::deserialize(deserializer).map(Packed)
+    }
+}
+
+#[test]
+fn test_packed() {
+    assert_tokens(&Packed(vec![]), &[Token::Bytes(&[])]);
+    assert_tokens(&Packed(vec![u12![0xABC]]), &[Token::Bytes(&[0xAB, 0x0C])]);
+    assert_tokens(
+        &Packed(vec![u12![0xABC], u12![0x123]]),
+        &[Token::Bytes(&[0xAB, 0x12, 0x3C])],
+    );
+    assert_tokens(
+        &Packed(vec![u12![0xABC], u12![0x123], u12![0xFED]]),
+        &[Token::Bytes(&[0xAB, 0x12, 0x3C, 0xFE, 0x0D])],
+    );
+}
+
+#[test]
+fn test_packed_from_seq() {
+    serde_test::assert_de_tokens(
+        &Packed(vec![u12![0xABC], u12![0x123]]),
+        &[
+            Token::Seq { len: Some(3) },
+            Token::U8(0xAB),
+            Token::U8(0x12),
+            Token::U8(0x3C),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_packed_from_seq_ignores_oversized_hint() {
+    serde_test::assert_de_tokens(
+        &Packed(vec![u12![0xABC]]),
+        &[
+            Token::Seq {
+                len: Some(usize::MAX),
+            },
+            Token::U8(0xAB),
+            Token::U8(0x0C),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_packed_invalid_length() {
+    assert_de_tokens_error::<Packed>(
+        &[Token::Bytes(&[0xAB, 0x12, 0x3C, 0xFE])],
+        "invalid length 4, expected a byte array of packed 12-bit values",
+    );
+}
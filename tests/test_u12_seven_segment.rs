@@ -0,0 +1,72 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::seven_segment::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Hexadecimal
+
+#[test]
+fn test_hex_digits_are_distinct() {
+    for (i, a) in HEX_DIGITS.iter().enumerate() {
+        assert_eq!(a & 0x80, 0);
+        for b in &HEX_DIGITS[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn test_to_seven_segment() {
+    assert_eq!(U12::min_value().to_seven_segment(), [0x3F; 3]);
+    assert_eq!(U12::max_value().to_seven_segment(), [0x71; 3]);
+    assert_eq!(u12![0x123].to_seven_segment(), [0x06, 0x5B, 0x4F]);
+    assert_eq!(u12![0xBCD].to_seven_segment(), [0x7C, 0x39, 0x5E]);
+}
+
+#[test]
+fn test_to_seven_segment_matches_hex_digits() {
+    for value in 0..4096 {
+        let digits = U12::from_u16(value).to_seven_segment();
+        let expected = [value >> 8, value >> 4 & 0xF, value & 0xF].map(|d| HEX_DIGITS[d as usize]);
+        assert_eq!(digits, expected);
+    }
+}
+
+// MARK: - Tests - Decimal
+
+#[test]
+fn test_to_seven_segment_decimal() {
+    assert_eq!(U12::min_value().to_seven_segment_decimal(), [0x3F; 4]);
+    assert_eq!(
+        U12::max_value().to_seven_segment_decimal(),
+        [0x66, 0x3F, 0x6F, 0x6D]
+    );
+    assert_eq!(
+        u12![58].to_seven_segment_decimal(),
+        [0x3F, 0x3F, 0x6D, 0x7F]
+    );
+}
+
+// MARK: - Tests - Polarity
+
+#[test]
+fn test_polarity() {
+    let digits = u12![0x800].to_seven_segment();
+    assert_eq!(Polarity::CommonCathode.apply(digits), digits);
+    assert_eq!(Polarity::CommonAnode.apply(digits), [0x80, 0xC0, 0xC0]);
+    assert_eq!(
+        Polarity::CommonAnode.apply(Polarity::CommonAnode.apply(digits)),
+        digits
+    );
+    assert_eq!(Polarity::CommonAnode.apply([0x06; 4]), [0xF9; 4]);
+}
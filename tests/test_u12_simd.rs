@@ -0,0 +1,131 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::simd::*;
+use twelve_bit::u12::*;
+
+fn sample() -> [U12; 8] {
+    [
+        u12![0],
+        u12![1],
+        u12![100],
+        u12![2047],
+        u12![2048],
+        u12![4000],
+        u12![4094],
+        u12![4095],
+    ]
+}
+
+// MARK: - Tests - Construction
+
+#[test]
+fn test_splat_and_lanes() {
+    let vector = U12x8::splat(u12![42]);
+    assert_eq!(vector.to_array(), [u12![42]; 8]);
+    assert_eq!(U12x8::LANES, 8);
+    assert_eq!(U12x4::LANES, 4);
+    assert_eq!(U12x4::default().to_array(), [u12![0]; 4]);
+}
+
+#[test]
+fn test_array_round_trip() {
+    let vector = U12x8::from_array(sample());
+    assert_eq!(vector.to_array(), sample());
+    assert_eq!(<[U12; 8]>::from(U12x8::from(sample())), sample());
+    assert_eq!(vector.lane(3), u12![2047]);
+}
+
+#[test]
+fn test_slice_round_trip() {
+    let values = sample();
+    let vector = U12x4::from_slice(&values[4..]);
+    assert_eq!(
+        vector.to_array(),
+        [u12![2048], u12![4000], u12![4094], u12![4095]]
+    );
+
+    let mut out = [u12![7]; 6];
+    vector.write_to_slice(&mut out);
+    assert_eq!(&out[..4], &values[4..]);
+    assert_eq!(&out[4..], &[u12![7]; 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_from_slice_too_short() {
+    let _ = U12x8::from_slice(&[u12![1]; 7]);
+}
+
+#[test]
+#[should_panic]
+fn test_write_to_slice_too_short() {
+    U12x4::splat(u12![1]).write_to_slice(&mut [u12![0]; 3]);
+}
+
+// MARK: - Tests - Arithmetic
+
+#[test]
+fn test_lane_arithmetic_matches_scalar() {
+    let a = sample();
+    let mut b = sample();
+    b.reverse();
+    let (va, vb) = (U12x8::from_array(a), U12x8::from_array(b));
+    for index in 0..8 {
+        let (x, y) = (a[index], b[index]);
+        assert_eq!(va.wrapping_add(vb).lane(index), x.wrapping_add(y));
+        assert_eq!(va.wrapping_sub(vb).lane(index), x.wrapping_sub(y));
+        assert_eq!(va.wrapping_mul(vb).lane(index), x.wrapping_mul(y));
+        assert_eq!(va.saturating_add(vb).lane(index), x.saturating_add(y));
+        assert_eq!(va.saturating_sub(vb).lane(index), x.saturating_sub(y));
+        assert_eq!(va.min(vb).lane(index), x.min(y));
+        assert_eq!(va.max(vb).lane(index), x.max(y));
+        assert_eq!((va & vb).lane(index), x & y);
+        assert_eq!((va | vb).lane(index), x | y);
+        assert_eq!((va ^ vb).lane(index), x ^ y);
+    }
+}
+
+// MARK: - Tests - Comparisons
+
+#[test]
+fn test_compare_masks() {
+    let a = U12x4::from_array([u12![1], u12![2], u12![3], u12![4]]);
+    let b = U12x4::splat(u12![2]);
+    assert_eq!(a.simd_eq(b), [false, true, false, false]);
+    assert_eq!(a.simd_ne(b), [true, false, true, true]);
+    assert_eq!(a.simd_lt(b), [true, false, false, false]);
+    assert_eq!(a.simd_le(b), [true, true, false, false]);
+    assert_eq!(a.simd_gt(b), [false, false, true, true]);
+    assert_eq!(a.simd_ge(b), [false, true, true, true]);
+}
+
+#[test]
+fn test_select() {
+    let a = U12x4::from_array([u12![1], u12![2], u12![3], u12![4]]);
+    let b = U12x4::splat(u12![9]);
+    let blended = U12x4::select(a.simd_gt(U12x4::splat(u12![2])), b, a);
+    assert_eq!(blended.to_array(), [u12![1], u12![2], u12![9], u12![9]]);
+}
+
+// MARK: - Tests - Reductions
+
+#[test]
+fn test_reductions() {
+    let vector = U12x8::from_array(sample());
+    let sum: u32 = sample().iter().map(|&value| u16::from(value) as u32).sum();
+    assert_eq!(vector.reduce_sum(), sum);
+    assert_eq!(U12x8::splat(U12::max_value()).reduce_sum(), 8 * 4095);
+    assert_eq!(vector.reduce_min(), u12![0]);
+    assert_eq!(vector.reduce_max(), u12![4095]);
+    assert_eq!(U12x4::splat(u12![5]).reduce_min(), u12![5]);
+}
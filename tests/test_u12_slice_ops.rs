@@ -0,0 +1,145 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::slice_ops::*;
+use twelve_bit::u12::*;
+
+/// Returns a slice exercising every 12-bit value, in ascending order.
+fn all_values() -> Vec<U12> {
+    (0u16..4096).map(|x| x.unchecked_into()).collect()
+}
+
+// MARK: - Tests - Element-Wise Operations
+
+#[test]
+fn test_wrapping_add_slices_matches_scalar() {
+    let src = all_values();
+    let mut dst: Vec<U12> = src.iter().rev().cloned().collect();
+    let expected: Vec<U12> = dst
+        .iter()
+        .zip(&src)
+        .map(|(a, b)| a.wrapping_add(*b))
+        .collect();
+    wrapping_add_slices(&mut dst, &src);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn test_wrapping_sub_slices_matches_scalar() {
+    let src = all_values();
+    let mut dst: Vec<U12> = src.iter().rev().cloned().collect();
+    let expected: Vec<U12> = dst
+        .iter()
+        .zip(&src)
+        .map(|(a, b)| a.wrapping_sub(*b))
+        .collect();
+    wrapping_sub_slices(&mut dst, &src);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn test_saturating_add_slices_matches_scalar() {
+    let src = all_values();
+    let mut dst: Vec<U12> = src.iter().rev().cloned().collect();
+    let expected: Vec<U12> = dst
+        .iter()
+        .zip(&src)
+        .map(|(a, b)| a.saturating_add(*b))
+        .collect();
+    saturating_add_slices(&mut dst, &src);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn test_saturating_sub_slices_matches_scalar() {
+    let src = all_values();
+    let mut dst: Vec<U12> = src.iter().rev().cloned().collect();
+    let expected: Vec<U12> = dst
+        .iter()
+        .zip(&src)
+        .map(|(a, b)| a.saturating_sub(*b))
+        .collect();
+    saturating_sub_slices(&mut dst, &src);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn test_bitwise_slices() {
+    let mut and = [u12![0b111100001111], u12![0b000000000000]];
+    and_slices(&mut and, &[u12![0b111111110000], u12![0b111111111111]]);
+    assert_eq!(and, [u12![0b111100000000], u12![0b000000000000]]);
+
+    let mut or = [u12![0b111100001111], u12![0b000000000000]];
+    or_slices(&mut or, &[u12![0b111111110000], u12![0b111111111111]]);
+    assert_eq!(or, [u12![0b111111111111], u12![0b111111111111]]);
+
+    let mut xor = [u12![0b111100001111], u12![0b000000000000]];
+    xor_slices(&mut xor, &[u12![0b111111110000], u12![0b111111111111]]);
+    assert_eq!(xor, [u12![0b000011111111], u12![0b111111111111]]);
+}
+
+#[test]
+#[should_panic]
+fn test_slices_of_different_length() {
+    wrapping_add_slices(&mut [u12![0], u12![1]], &[u12![0]]);
+}
+
+// MARK: - Tests - Scalar Operations
+
+#[test]
+fn test_scalar_ops_match_scalar() {
+    let values = all_values();
+    for scalar in [u12![0], u12![1], u12![0x800], U12::max_value()].iter() {
+        let mut dst = values.clone();
+        wrapping_add_scalar(&mut dst, *scalar);
+        assert!(dst
+            .iter()
+            .zip(&values)
+            .all(|(d, v)| *d == v.wrapping_add(*scalar)));
+
+        let mut dst = values.clone();
+        wrapping_sub_scalar(&mut dst, *scalar);
+        assert!(dst
+            .iter()
+            .zip(&values)
+            .all(|(d, v)| *d == v.wrapping_sub(*scalar)));
+
+        let mut dst = values.clone();
+        saturating_add_scalar(&mut dst, *scalar);
+        assert!(dst
+            .iter()
+            .zip(&values)
+            .all(|(d, v)| *d == v.saturating_add(*scalar)));
+
+        let mut dst = values.clone();
+        saturating_sub_scalar(&mut dst, *scalar);
+        assert!(dst
+            .iter()
+            .zip(&values)
+            .all(|(d, v)| *d == v.saturating_sub(*scalar)));
+    }
+}
+
+#[test]
+fn test_mask_slices() {
+    let mut and = [u12![0xABC], u12![0x123]];
+    and_mask_slice(&mut and, u12![0x0F0]);
+    assert_eq!(and, [u12![0x0B0], u12![0x020]]);
+
+    let mut or = [u12![0xABC], u12![0x123]];
+    or_mask_slice(&mut or, u12![0x0F0]);
+    assert_eq!(or, [u12![0xAFC], u12![0x1F3]]);
+
+    let mut xor = [u12![0xABC], u12![0x123]];
+    xor_mask_slice(&mut xor, u12![0xFFF]);
+    assert_eq!(xor, [u12![0x543], u12![0xEDC]]);
+}
@@ -0,0 +1,64 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::stats::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Summaries
+
+#[test]
+fn test_summarize_empty() {
+    assert_eq!(summarize(&[]), None);
+    assert_eq!(summarize_with_variance(&[]), None);
+}
+
+#[test]
+fn test_summarize_single() {
+    let stats = summarize_with_variance(&[u12![123]]).unwrap();
+    assert_eq!(stats.count, 1);
+    assert_eq!((stats.min, stats.max), (u12![123], u12![123]));
+    assert_eq!(stats.sum, 123);
+    assert_eq!(stats.mean, 123.0);
+    assert_eq!(stats.variance, Some(0.0));
+}
+
+#[test]
+fn test_summarize_matches_reference() {
+    let values: Vec<U12> = (0..10_000u32)
+        .map(|i| U12::from_u16((i * 7919 % 4096) as u16))
+        .collect();
+    let stats = summarize_with_variance(&values).unwrap();
+    let wide: Vec<f64> = values.iter().map(|&v| u16::from(v) as f64).collect();
+    let mean = wide.iter().sum::<f64>() / wide.len() as f64;
+    let variance = wide.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / wide.len() as f64;
+
+    assert_eq!(stats.count, 10_000);
+    assert_eq!(stats.min, U12::min_value());
+    assert_eq!(stats.max, U12::max_value());
+    assert_eq!(
+        stats.sum,
+        values.iter().map(|&v| u16::from(v) as u64).sum::<u64>()
+    );
+    assert!((stats.mean - mean).abs() < 1e-9);
+    assert!((stats.variance.unwrap() - variance).abs() < 1e-6);
+    assert!((stats.std_dev().unwrap() - variance.sqrt()).abs() < 1e-9);
+    assert_eq!(summarize(&values).unwrap().variance, None);
+}
+
+#[test]
+fn test_summarize_saturated_buffer_does_not_overflow() {
+    let values = vec![U12::max_value(); 1 << 20];
+    let stats = summarize_with_variance(&values).unwrap();
+    assert_eq!(stats.sum, 4095 << 20);
+    assert_eq!(stats.mean, 4095.0);
+    assert_eq!(stats.variance, Some(0.0));
+}
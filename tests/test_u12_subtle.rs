@@ -0,0 +1,58 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "subtle")]
+
+extern crate subtle;
+#[macro_use]
+extern crate twelve_bit;
+
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Comparison
+
+#[test]
+fn test_ct_eq() {
+    assert!(bool::from(u12![0xABC].ct_eq(&u12![0xABC])));
+    assert!(!bool::from(u12![0xABC].ct_eq(&u12![0xABD])));
+    assert!(bool::from(u12![0xABC].ct_ne(&u12![0xABD])));
+}
+
+#[test]
+fn test_ct_ordering_matches_ord() {
+    for a in (0..4096u16).step_by(37) {
+        for b in (0..4096u16).step_by(41) {
+            let (a, b) = (U12::from_u16(a), U12::from_u16(b));
+            assert_eq!(bool::from(a.ct_gt(&b)), a > b);
+            assert_eq!(bool::from(a.ct_lt(&b)), a < b);
+        }
+    }
+}
+
+// MARK: - Tests - Selection
+
+#[test]
+fn test_conditional_select() {
+    let (a, b) = (u12![0x123], u12![0xFED]);
+    assert_eq!(U12::conditional_select(&a, &b, Choice::from(0)), a);
+    assert_eq!(U12::conditional_select(&a, &b, Choice::from(1)), b);
+
+    let mut value = a;
+    value.conditional_assign(&b, Choice::from(0));
+    assert_eq!(value, a);
+    value.conditional_assign(&b, Choice::from(1));
+    assert_eq!(value, b);
+
+    let (mut x, mut y) = (a, b);
+    U12::conditional_swap(&mut x, &mut y, Choice::from(1));
+    assert_eq!((x, y), (b, a));
+}
@@ -0,0 +1,67 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::vlan::*;
+use twelve_bit::u12::*;
+
+// MARK: - Tests - Fields
+
+#[test]
+fn test_new() {
+    let tci = VlanTci::new(7, true, VID_RESERVED);
+    assert_eq!(tci.to_bits(), 0xFFFF);
+    assert_eq!(VlanTci::new(0, false, VID_NONE), VlanTci::default());
+    assert_eq!(VlanTci::new(3, false, VID_DEFAULT).to_bits(), 0x6001);
+}
+
+#[test]
+fn test_accessors() {
+    let tci = VlanTci::from_bits(0xB0FF);
+    assert_eq!(tci.pcp(), 0b101);
+    assert!(tci.dei());
+    assert_eq!(tci.vid(), u12![0xFF]);
+}
+
+#[test]
+fn test_setters_leave_other_fields_untouched() {
+    let mut tci = VlanTci::new(6, true, u12![0xABC]);
+    tci.set_vid(u12![0x123]);
+    assert_eq!((tci.pcp(), tci.dei(), tci.vid()), (6, true, u12![0x123]));
+    tci.set_pcp(1);
+    assert_eq!((tci.pcp(), tci.dei(), tci.vid()), (1, true, u12![0x123]));
+    tci.set_dei(false);
+    assert_eq!((tci.pcp(), tci.dei(), tci.vid()), (1, false, u12![0x123]));
+}
+
+#[test]
+#[should_panic(expected = "priority code point out of range")]
+fn test_new_invalid_pcp() {
+    let _ = VlanTci::new(8, false, VID_DEFAULT);
+}
+
+#[test]
+#[should_panic(expected = "priority code point out of range")]
+fn test_set_pcp_invalid() {
+    VlanTci::default().set_pcp(8);
+}
+
+// MARK: - Tests - Conversions
+
+#[test]
+fn test_be_bytes_round_trip() {
+    for bits in (0..=u16::MAX).step_by(7) {
+        let tci = VlanTci::from_bits(bits);
+        assert_eq!(VlanTci::from_be_bytes(tci.to_be_bytes()), tci);
+        assert_eq!(tci.to_be_bytes(), bits.to_be_bytes());
+        assert_eq!(u16::from(VlanTci::from(bits)), bits);
+    }
+}
@@ -0,0 +1,99 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#[macro_use]
+extern crate twelve_bit;
+
+use twelve_bit::u12::waveform::*;
+use twelve_bit::u12::*;
+
+const SINE: [U12; 256] = sine_table();
+
+// MARK: - Tests - Full-Range Tables
+
+#[test]
+fn test_sine_table_matches_std() {
+    for (index, &sample) in SINE.iter().enumerate() {
+        let phase = 2.0 * std::f64::consts::PI * index as f64 / 256.0;
+        let expected = ((1.0 + phase.sin()) / 2.0 * 4095.0).round() as u16;
+        assert_eq!(u16::from(sample), expected, "index {}", index);
+    }
+    assert_eq!(SINE[64], U12::max_value());
+    assert_eq!(SINE[192], U12::min_value());
+}
+
+#[test]
+fn test_triangle_table() {
+    let table: [U12; 8] = triangle_table();
+    let expected = [0, 1024, 2048, 3071, 4095, 3071, 2048, 1024];
+    assert_eq!(table, expected.map(U12::from_u16));
+}
+
+#[test]
+fn test_sawtooth_table() {
+    let table: [U12; 4] = sawtooth_table();
+    assert_eq!(table, [0, 1024, 2048, 3071].map(U12::from_u16));
+}
+
+#[test]
+fn test_ramp_table() {
+    let table: [U12; 4] = ramp_table();
+    assert_eq!(table, [0, 1365, 2730, 4095].map(U12::from_u16));
+    let single: [U12; 1] = ramp_table();
+    assert_eq!(single, [U12::min_value()]);
+}
+
+// MARK: - Tests - Scaled Tables
+
+#[test]
+fn test_scaled_tables_stay_in_range() {
+    for &waveform in &[
+        Waveform::Sine,
+        Waveform::Triangle,
+        Waveform::Sawtooth,
+        Waveform::Ramp,
+    ] {
+        let table: [U12; 100] = waveform.table(u12![500], u12![1000]);
+        assert!(table.iter().all(|&s| s >= u12![500] && s <= u12![1500]));
+        assert_eq!(table.iter().min(), Some(&u12![500]));
+    }
+}
+
+#[test]
+fn test_fill_matches_table() {
+    for &waveform in &[
+        Waveform::Sine,
+        Waveform::Triangle,
+        Waveform::Sawtooth,
+        Waveform::Ramp,
+    ] {
+        let table: [U12; 37] = waveform.table(u12![100], u12![3000]);
+        let mut filled = vec![U12::min_value(); 37];
+        waveform.fill(&mut filled, u12![100], u12![3000]);
+        assert_eq!(&filled[..], &table[..]);
+    }
+}
+
+#[test]
+fn test_zero_amplitude_is_constant() {
+    let table: [U12; 16] = Waveform::Sine.table(u12![1234], U12::min_value());
+    assert_eq!(table, [u12![1234]; 16]);
+}
+
+#[test]
+#[should_panic(expected = "waveform exceeds the 12-bit range")]
+fn test_amplitude_overflow() {
+    let _: [U12; 4] = Waveform::Ramp.table(u12![1], U12::max_value());
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_sample_index_out_of_bounds() {
+    let _ = Waveform::Sine.sample(4, 4, U12::min_value(), U12::max_value());
+}
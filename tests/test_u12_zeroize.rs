@@ -0,0 +1,56 @@
+//
+// Copyright 2016 The u12 Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the MIT license <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// All files in the project carrying such notice may not be copied, modified, or
+// distributed except according to those terms.
+//
+
+#![cfg(feature = "zeroize")]
+
+#[macro_use]
+extern crate twelve_bit;
+extern crate zeroize;
+
+use twelve_bit::u12::image::Image12;
+use twelve_bit::u12::memory::Memory4K;
+use twelve_bit::u12::*;
+use zeroize::Zeroize;
+
+// MARK: - Tests - Values
+
+#[test]
+fn test_zeroize_value() {
+    let mut value = u12![0xABC];
+    value.zeroize();
+    assert_eq!(value, U12::min_value());
+}
+
+#[test]
+fn test_zeroize_collections() {
+    let mut array = [u12![1], u12![2], u12![3]];
+    array.zeroize();
+    assert_eq!(array, [U12::min_value(); 3]);
+
+    let mut vector = vec![u12![1], u12![2]];
+    vector.zeroize();
+    assert!(vector.is_empty());
+}
+
+// MARK: - Tests - Containers
+
+#[test]
+fn test_zeroize_image() {
+    let mut image = Image12::from_u16_samples(3, 2, &[1, 2, 3, 4, 5, 0xFFF]).unwrap();
+    image.zeroize();
+    assert_eq!((image.width(), image.height()), (3, 2));
+    assert!(image.as_packed().iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn test_zeroize_memory() {
+    let mut memory = Memory4K::from_bytes([0xA5; 4096]);
+    memory.zeroize();
+    assert_eq!(memory, Memory4K::new());
+}